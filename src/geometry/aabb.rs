@@ -0,0 +1,364 @@
+use crate::vector::{Vec2, Vec3};
+use num::Num;
+use std::borrow::Borrow;
+
+/// A 2D axis-aligned bounding box, stored as its minimum and maximum corners.
+///
+/// The bounds are closed, so boxes that only touch along an edge still
+/// intersect, and a box may have zero size along any axis.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Aabb2<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    pub min: Vec2<T>,
+    pub max: Vec2<T>,
+}
+
+impl<T> Aabb2<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Create a 2D box from its minimum and maximum corners
+    pub fn new(min: Vec2<T>, max: Vec2<T>) -> Self {
+        Self { min, max }
+    }
+
+    /// Create the smallest 2D box containing every point, or `None` if there are no points
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Vec2<T>>,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?.borrow();
+        Some(points.fold(Self::new(first, first), |aabb, p| {
+            Self::new(aabb.min.min(*p.borrow()), aabb.max.max(*p.borrow()))
+        }))
+    }
+
+    /// Create a 2D box from its center and half of its size
+    pub fn from_center_half_extents(center: Vec2<T>, half_extents: Vec2<T>) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Return whether the point lies inside or on the edge of the box
+    pub fn contains_point(&self, point: &Vec2<T>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    /// Return whether the point lies inside or on the edge of the box, the same as
+    /// `contains_point`
+    pub fn contains(&self, point: &Vec2<T>) -> bool {
+        self.contains_point(point)
+    }
+
+    /// Return whether two boxes overlap or touch
+    pub fn intersects(&self, other: &Aabb2<T>) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+
+    /// Return the smallest box containing both boxes
+    pub fn union(&self, other: &Aabb2<T>) -> Aabb2<T> {
+        Self::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// Return the smallest box containing both boxes, the same as `union`
+    pub fn merge(&self, other: &Aabb2<T>) -> Aabb2<T> {
+        self.union(other)
+    }
+
+    /// Return the box shared by both boxes, or `None` if they do not intersect
+    ///
+    /// Touching boxes give a box of zero size along the touching axis.
+    pub fn intersection(&self, other: &Aabb2<T>) -> Option<Aabb2<T>> {
+        if self.intersects(other) {
+            Some(Self::new(self.min.max(other.min), self.max.min(other.max)))
+        } else {
+            None
+        }
+    }
+
+    /// Return the center of the box
+    pub fn center(&self) -> Vec2<T> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+
+    /// Return the size of the box along each axis
+    pub fn size(&self) -> Vec2<T> {
+        self.max - self.min
+    }
+
+    /// Return half the size of the box along each axis
+    ///
+    /// The inverse of `from_center_half_extents`: `center() - extents()` is `min`.
+    pub fn extents(&self) -> Vec2<T> {
+        self.size() / (T::one() + T::one())
+    }
+
+    /// Return the box grown by `margin` on every side
+    pub fn expand(&self, margin: T) -> Aabb2<T> {
+        Self::new(self.min - margin, self.max + margin)
+    }
+}
+
+/// A 3D axis-aligned bounding box, stored as its minimum and maximum corners.
+///
+/// The bounds are closed, so boxes that only touch along a face still
+/// intersect, and a box may have zero size along any axis.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Aabb3<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+impl<T> Aabb3<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Create a 3D box from its minimum and maximum corners
+    pub fn new(min: Vec3<T>, max: Vec3<T>) -> Self {
+        Self { min, max }
+    }
+
+    /// Create the smallest 3D box containing every point, or `None` if there are no points
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Vec3<T>>,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?.borrow();
+        Some(points.fold(Self::new(first, first), |aabb, p| {
+            Self::new(aabb.min.min(*p.borrow()), aabb.max.max(*p.borrow()))
+        }))
+    }
+
+    /// Create a 3D box from its center and half of its size
+    pub fn from_center_half_extents(center: Vec3<T>, half_extents: Vec3<T>) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Return whether the point lies inside or on the surface of the box
+    pub fn contains_point(&self, point: &Vec3<T>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    /// Return whether the point lies inside or on the surface of the box, the same
+    /// as `contains_point`
+    pub fn contains(&self, point: &Vec3<T>) -> bool {
+        self.contains_point(point)
+    }
+
+    /// Return whether two boxes overlap or touch
+    pub fn intersects(&self, other: &Aabb3<T>) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    /// Return the smallest box containing both boxes
+    pub fn union(&self, other: &Aabb3<T>) -> Aabb3<T> {
+        Self::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// Return the smallest box containing both boxes, the same as `union`
+    pub fn merge(&self, other: &Aabb3<T>) -> Aabb3<T> {
+        self.union(other)
+    }
+
+    /// Return the box shared by both boxes, or `None` if they do not intersect
+    ///
+    /// Touching boxes give a box of zero size along the touching axis.
+    pub fn intersection(&self, other: &Aabb3<T>) -> Option<Aabb3<T>> {
+        if self.intersects(other) {
+            Some(Self::new(self.min.max(other.min), self.max.min(other.max)))
+        } else {
+            None
+        }
+    }
+
+    /// Return the center of the box
+    pub fn center(&self) -> Vec3<T> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+
+    /// Return the size of the box along each axis
+    pub fn size(&self) -> Vec3<T> {
+        self.max - self.min
+    }
+
+    /// Return half the size of the box along each axis
+    ///
+    /// The inverse of `from_center_half_extents`: `center() - extents()` is `min`.
+    pub fn extents(&self) -> Vec3<T> {
+        self.size() / (T::one() + T::one())
+    }
+
+    /// Return the box grown by `margin` on every side
+    pub fn expand(&self, margin: T) -> Aabb3<T> {
+        Self::new(self.min - margin, self.max + margin)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::{Aabb2, Aabb3};
+    use crate::vector::{Vec2, Vec3};
+
+    fn aabb2(min: (i32, i32), max: (i32, i32)) -> Aabb2<i32> {
+        Aabb2::new(Vec2::new(min.0, min.1), Vec2::new(max.0, max.1))
+    }
+
+    fn aabb3(min: (i32, i32, i32), max: (i32, i32, i32)) -> Aabb3<i32> {
+        Aabb3::new(
+            Vec3::new(min.0, min.1, min.2),
+            Vec3::new(max.0, max.1, max.2),
+        )
+    }
+
+    #[test]
+    fn from_points_test() {
+        let points = [Vec3::new(1, 5, -2), Vec3::new(-3, 2, 0), Vec3::new(0, 0, 4)];
+
+        assert_eq!(
+            Aabb3::from_points(points.iter()),
+            Some(aabb3((-3, 0, -2), (1, 5, 4)))
+        );
+        assert_eq!(
+            Aabb3::from_points(points.to_vec()),
+            Some(aabb3((-3, 0, -2), (1, 5, 4)))
+        );
+        assert_eq!(Aabb3::<i32>::from_points(Vec::<Vec3<i32>>::new()), None);
+        assert_eq!(Aabb2::<f32>::from_points([].iter()), None);
+
+        // a slice of points
+        let outline = [
+            Vec2::new(2.0, -1.0),
+            Vec2::new(-0.5, 3.0),
+            Vec2::new(1.0, 0.0),
+        ];
+        let a = Aabb2::from_points(&outline[..]).unwrap();
+        assert_eq!(a, Aabb2::new(Vec2::new(-0.5, -1.0), Vec2::new(2.0, 3.0)));
+        assert!(outline.iter().all(|p| a.contains(p)));
+    }
+
+    #[test]
+    fn from_center_half_extents_test() {
+        let a = Aabb2::from_center_half_extents(Vec2::new(1.0, 2.0), Vec2::new(0.5, 1.0));
+
+        assert_eq!(a.min, Vec2::new(0.5, 1.0));
+        assert_eq!(a.max, Vec2::new(1.5, 3.0));
+        assert_eq!(a.center(), Vec2::new(1.0, 2.0));
+        assert_eq!(a.size(), Vec2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn extents_test() {
+        let a = Aabb2::from_center_half_extents(Vec2::new(1.0, 2.0), Vec2::new(0.5, 1.0));
+        let b = aabb3((-2, 0, 4), (2, 6, 4));
+
+        assert_eq!(a.extents(), Vec2::new(0.5, 1.0));
+        assert_eq!(a.center() - a.extents(), a.min);
+        assert_eq!(b.extents(), Vec3::new(2, 3, 0));
+        assert_eq!(b.extents() * 2, b.size());
+    }
+
+    #[test]
+    fn contains_point_test() {
+        let a = aabb3((0, 0, 0), (2, 2, 2));
+
+        assert!(a.contains_point(&Vec3::new(1, 1, 1)));
+        assert!(a.contains_point(&Vec3::new(2, 0, 1)));
+        assert!(!a.contains_point(&Vec3::new(3, 1, 1)));
+
+        assert!(a.contains(&Vec3::new(0, 2, 2)));
+        assert!(!a.contains(&Vec3::new(1, -1, 1)));
+        let b = aabb2((-1, -1), (1, 3));
+        assert!(b.contains(&Vec2::new(-1, 3)));
+        assert!(b.contains(&Vec2::new(0, 0)));
+        assert!(!b.contains(&Vec2::new(2, 0)));
+        assert!(!b.contains(&Vec2::new(0, 4)));
+    }
+
+    #[test]
+    fn intersects_test() {
+        let a = aabb2((0, 0), (2, 2));
+
+        assert!(a.intersects(&aabb2((1, 1), (3, 3))));
+        assert!(!a.intersects(&aabb2((3, 0), (4, 2))));
+        assert!(!aabb3((0, 0, 0), (1, 1, 1)).intersects(&aabb3((0, 0, 2), (1, 1, 3))));
+    }
+
+    #[test]
+    fn touching_boxes_test() {
+        let a = aabb3((0, 0, 0), (2, 2, 2));
+        let b = aabb3((2, 0, 0), (4, 2, 2));
+
+        assert!(a.intersects(&b));
+        assert_eq!(a.intersection(&b), Some(aabb3((2, 0, 0), (2, 2, 2))));
+        assert_eq!(a.intersection(&b).unwrap().size(), Vec3::new(0, 2, 2));
+    }
+
+    #[test]
+    fn union_intersection_test() {
+        let a = aabb2((0, 0), (2, 2));
+        let b = aabb2((1, -1), (3, 1));
+
+        assert_eq!(a.union(&b), aabb2((0, -1), (3, 2)));
+        assert_eq!(a.intersection(&b), Some(aabb2((1, 0), (2, 1))));
+        assert_eq!(a.intersection(&aabb2((5, 5), (6, 6))), None);
+    }
+
+    #[test]
+    fn merge_test() {
+        let a = aabb3((0, 0, 0), (2, 2, 2));
+        let b = aabb3((5, -1, 1), (6, 1, 3));
+        let merged = a.merge(&b);
+
+        assert_eq!(merged, aabb3((0, -1, 0), (6, 2, 3)));
+        assert_eq!(merged, b.merge(&a));
+        assert_eq!(a.merge(&a), a);
+        // a box inside another merges to the outer one
+        assert_eq!(merged.merge(&a), merged);
+        assert_eq!(
+            aabb2((0, 0), (1, 1)).merge(&aabb2((3, 3), (4, 5))),
+            aabb2((0, 0), (4, 5))
+        );
+    }
+
+    #[test]
+    fn degenerate_box_test() {
+        let p = Vec3::new(1.0, 2.0, 3.0);
+        let a = Aabb3::from_points([p]).unwrap();
+
+        assert_eq!(a.size(), Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(a.center(), p);
+        assert!(a.contains_point(&p));
+        assert!(a.intersects(&a));
+        assert_eq!(a.intersection(&a), Some(a));
+        assert_eq!(a.expand(1.0).size(), Vec3::new(2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn expand_test() {
+        assert_eq!(aabb2((0, 0), (2, 2)).expand(1), aabb2((-1, -1), (3, 3)));
+    }
+}
@@ -0,0 +1,279 @@
+use crate::geometry::Plane;
+use crate::matrix::Mat3;
+use crate::vector::{Vec2, Vec3};
+use num::Float;
+use std::cmp::Ordering;
+
+/// Return the covariance matrix of a set of 3D points
+///
+/// Entry `(i, j)` is the mean of `(p[i] - mean[i]) * (p[j] - mean[j])`, so the
+/// diagonal holds the variance along each axis. The matrix is symmetric, and
+/// is all zeros when `points` is empty.
+///
+/// ```rust
+/// # use lamar::geometry::covariance_matrix;
+/// # use lamar::vector::Vec3;
+/// let points = [Vec3::new(-1.0, 0.0, 5.0), Vec3::new(1.0, 0.0, 5.0)];
+/// let cov = covariance_matrix(&points);
+///
+/// assert_eq!(cov.cols[0], Vec3::new(1.0, 0.0, 0.0));
+/// assert_eq!(cov.cols[2], Vec3::new(0.0, 0.0, 0.0));
+/// ```
+pub fn covariance_matrix<T: Float>(points: &[Vec3<T>]) -> Mat3<T> {
+    let zero = T::zero();
+    let mut cols = [Vec3::new(zero, zero, zero); 3];
+    let mean = match Vec3::centroid(points) {
+        Some(mean) => mean,
+        None => return Mat3 { cols },
+    };
+    for p in points {
+        let d = *p - mean;
+        cols[0] = cols[0] + d * d.x;
+        cols[1] = cols[1] + d * d.y;
+        cols[2] = cols[2] + d * d.z;
+    }
+    let n = T::from(points.len()).unwrap();
+    Mat3 {
+        cols: cols.map(|c| c / n),
+    }
+}
+
+/// Return the eigenvalues of the symmetric Matrix `m`, with the eigenvectors as the
+/// columns of the returned Matrix
+///
+/// Uses cyclic Jacobi rotations, which stay accurate for the repeated and zero
+/// eigenvalues that flat and thin point sets give.
+fn symmetric_eigen<T: Float>(m: &Mat3<T>) -> ([T; 3], Mat3<T>) {
+    let mut a: [[T; 3]; 3] = m.cols.map(|c| c.into());
+    let mut v: [[T; 3]; 3] = Mat3::identity().cols.map(|c| c.into());
+    let two = T::one() + T::one();
+
+    for _ in 0..32 {
+        let off = a[0][1].abs() + a[0][2].abs() + a[1][2].abs();
+        let diagonal = a[0][0].abs() + a[1][1].abs() + a[2][2].abs();
+        if off <= diagonal * T::epsilon() {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == T::zero() {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+            let c = T::one() / (t * t + T::one()).sqrt();
+            let s = t * c;
+            for row in a.iter_mut().chain(v.iter_mut()) {
+                let (kp, kq) = (row[p], row[q]);
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+            let (rp, rq) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| c * rp[k] - s * rq[k]);
+            a[q] = std::array::from_fn(|k| s * rp[k] + c * rq[k]);
+        }
+    }
+
+    let vectors = Mat3::from_rows(v[0].into(), v[1].into(), v[2].into());
+    ([a[0][0], a[1][1], a[2][2]], vectors)
+}
+
+/// Return the least squares plane through a set of 3D points
+///
+/// The plane passes through the centroid, and its normal is the direction the
+/// points vary least along (the smallest eigenvector of `covariance_matrix`),
+/// which minimizes the sum of squared distances to the plane. The normal's sign is
+/// chosen so its largest component is positive.
+///
+/// Returns `None` for fewer than 3 points, or when the points are (nearly)
+/// collinear and so don't pick out a single plane.
+///
+/// ```rust
+/// # use lamar::geometry::best_fit_plane;
+/// # use lamar::vector::Vec3;
+/// let ground = [
+///     Vec3::new(0.0, 2.0, 0.0),
+///     Vec3::new(4.0, 2.0, 1.0),
+///     Vec3::new(-3.0, 2.0, 5.0),
+///     Vec3::new(1.0, 2.0, -2.0),
+/// ];
+/// let plane = best_fit_plane(&ground).unwrap();
+///
+/// assert!((plane.normal - Vec3::new(0.0, 1.0, 0.0)).length() < 1e-12);
+/// assert!((plane.distance - 2.0_f64).abs() < 1e-12);
+/// ```
+pub fn best_fit_plane<T: Float>(points: &[Vec3<T>]) -> Option<Plane<T>> {
+    if points.len() < 3 {
+        return None;
+    }
+    let (values, vectors) = symmetric_eigen(&covariance_matrix(points));
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap_or(Ordering::Equal));
+    let [_, middle, largest] = order.map(|i| values[i]);
+    if middle <= largest * T::epsilon().sqrt() {
+        return None;
+    }
+
+    let normal = vectors.cols[order[0]];
+    let largest_component =
+        normal.iter().fold(
+            T::zero(),
+            |best, &c| if c.abs() > best.abs() { c } else { best },
+        );
+    let normal = if largest_component < T::zero() {
+        normal * -T::one()
+    } else {
+        normal
+    };
+    Some(Plane::from_point_normal(Vec3::centroid(points)?, normal))
+}
+
+/// Return the least squares line through a set of 2D points, as `(point, direction)`
+///
+/// The point is the centroid and the direction is the unit vector the points
+/// vary most along, which minimizes the sum of squared perpendicular distances.
+/// The direction's sign is chosen so its largest component is positive.
+///
+/// Returns `None` for fewer than 2 points, or when all the points coincide.
+///
+/// ```rust
+/// # use lamar::geometry::best_fit_line_2d;
+/// # use lamar::vector::Vec2;
+/// let points = [Vec2::new(0.0, 1.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 3.0)];
+/// let (point, direction) = best_fit_line_2d(&points).unwrap();
+///
+/// assert_eq!(point, Vec2::new(1.0, 2.0));
+/// assert!((direction - Vec2::new(1.0, 1.0).normalize()).length() < 1e-12_f64);
+/// ```
+pub fn best_fit_line_2d<T: Float>(points: &[Vec2<T>]) -> Option<(Vec2<T>, Vec2<T>)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mean = Vec2::centroid(points)?;
+    let (mut xx, mut xy, mut yy) = (T::zero(), T::zero(), T::zero());
+    for p in points {
+        let d = *p - mean;
+        xx = xx + d.x * d.x;
+        xy = xy + d.x * d.y;
+        yy = yy + d.y * d.y;
+    }
+    if xx + yy <= T::zero() {
+        return None;
+    }
+
+    // the major axis of the 2x2 covariance is at half the angle of (xx - yy, 2xy)
+    let angle = (xy + xy).atan2(xx - yy) / (T::one() + T::one());
+    let (sin, cos) = angle.sin_cos();
+    // the angle is in (-pi/2, pi/2], so only a steep downward direction needs flipping
+    let direction = if sin < -cos {
+        Vec2::new(-cos, -sin)
+    } else {
+        Vec2::new(cos, sin)
+    };
+    Some((mean, direction))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::{best_fit_line_2d, best_fit_plane, covariance_matrix, Plane};
+    use crate::vector::{Vec2, Vec3};
+
+    // deterministic noise in [-0.5, 0.5)
+    fn noise(i: usize) -> f64 {
+        ((i as f64 * 12.9898).sin() * 43758.5453).fract().abs() - 0.5
+    }
+
+    #[test]
+    fn covariance_matrix_test() {
+        let points = [
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(-1.0, -2.0, 0.0),
+            Vec3::new(1.0, -2.0, 2.0),
+            Vec3::new(-1.0, 2.0, -2.0),
+        ];
+        let cov = covariance_matrix(&points);
+
+        assert_eq!(cov.cols[0], Vec3::new(1.0, 0.0, 1.0));
+        assert_eq!(cov.cols[1], Vec3::new(0.0, 4.0, -2.0));
+        assert_eq!(cov.cols[2], Vec3::new(1.0, -2.0, 2.0));
+        assert_eq!(cov, cov.transpose());
+        assert_eq!(covariance_matrix::<f32>(&[]).cols[1], Vec3::zero());
+    }
+
+    #[test]
+    fn best_fit_plane_exact_test() {
+        let target = Plane::from_point_normal(Vec3::new(1.0, -2.0, 3.0), Vec3::new(2.0, 3.0, -1.0));
+        let (u, v) = target.normal.any_orthonormal_pair();
+        let points: Vec<Vec3<f64>> = (0..20)
+            .map(|i| {
+                let (a, b) = (i as f64 * 0.7 - 5.0, (i * i % 7) as f64 - 3.0);
+                target.project_point(Vec3::new(0.0, 0.0, 0.0)) + u * a + v * b
+            })
+            .collect();
+
+        let plane = best_fit_plane(&points).unwrap();
+
+        assert!(
+            (plane.normal - target.normal).length() < 1e-9,
+            "{:?}",
+            plane
+        );
+        assert!((plane.distance - target.distance).abs() < 1e-9);
+        for p in &points {
+            assert!(plane.signed_distance(*p).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn best_fit_plane_noisy_test() {
+        // a tilted scanned floor with up to 1cm of noise over 10m
+        let normal = Vec3::new(0.05, 1.0, -0.02).normalize();
+        let (u, v) = normal.any_orthonormal_pair();
+        let points: Vec<Vec3<f64>> = (0..500)
+            .map(|i| {
+                let (a, b) = (noise(3 * i) * 10.0, noise(3 * i + 1) * 10.0);
+                u * a + v * b + normal * (0.3 + noise(3 * i + 2) * 0.02)
+            })
+            .collect();
+
+        let plane = best_fit_plane(&points).unwrap();
+
+        assert!(plane.normal.dot(&normal) > 0.9999, "{:?}", plane);
+        assert!((plane.distance - 0.3).abs() < 0.01);
+    }
+
+    #[test]
+    fn best_fit_plane_degenerate_test() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(1.0, 1.0, 1.0);
+
+        assert_eq!(best_fit_plane::<f64>(&[]), None);
+        assert_eq!(best_fit_plane(&[a, b]), None);
+        // collinear and coincident points don't define a plane
+        assert_eq!(best_fit_plane(&[a, b, b * 2.0, b * -3.0]), None);
+        assert_eq!(best_fit_plane(&[b, b, b]), None);
+        assert!(best_fit_plane(&[a, b, Vec3::new(1.0, 0.0, 0.0)]).is_some());
+    }
+
+    #[test]
+    fn best_fit_line_2d_test() {
+        let direction = Vec2::new(3.0, -1.0).normalize();
+        let points: Vec<Vec2<f64>> = (0..200)
+            .map(|i| {
+                let along = noise(2 * i) * 20.0;
+                let across = noise(2 * i + 1) * 0.1;
+                Vec2::new(5.0, 1.0) + direction * along + direction.perp() * across
+            })
+            .collect();
+
+        let (_, fitted) = best_fit_line_2d(&points).unwrap();
+        assert!(fitted.dot(&direction) > 0.9999, "{}", fitted);
+
+        // vertical lines point up
+        let vertical = [Vec2::new(2.0, 3.0), Vec2::new(2.0, -1.0)];
+        let (point, up) = best_fit_line_2d(&vertical).unwrap();
+        assert_eq!(point, Vec2::new(2.0, 1.0));
+        assert!((up - Vec2::new(0.0, 1.0)).length() < 1e-12);
+        assert_eq!(best_fit_line_2d(&vertical[..1]), None);
+        assert_eq!(best_fit_line_2d(&[vertical[0], vertical[0]]), None);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::geometry::{Aabb3, Plane};
+use crate::matrix::Mat4;
+use crate::vector::{Vec3, Vec4};
+use num::Float;
+
+/// A view frustum, made of six planes whose normals face inwards.
+///
+/// The planes are in the order left, right, bottom, top, near, far. The culling
+/// tests are conservative: they never reject something that is (even partly)
+/// inside, but may accept something just outside near a corner of the frustum.
+///
+/// ```rust
+/// # use lamar::geometry::Frustum;
+/// # use lamar::matrix::Mat4;
+/// # use lamar::vector::Vec3;
+/// let proj = Mat4::perspective(std::f64::consts::FRAC_PI_2, 1.0, 0.1, 100.0);
+/// let frustum = Frustum::from_matrix(&proj);
+///
+/// assert!(frustum.contains_point(&Vec3::new(0.0, 0.0, -10.0)));
+/// assert!(!frustum.contains_point(&Vec3::new(0.0, 0.0, 10.0)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Frustum<T>
+where
+    T: Float,
+{
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T> Frustum<T>
+where
+    T: Float,
+{
+    /// Extract the frustum of a view-projection Matrix (Gribb-Hartmann)
+    ///
+    /// The planes are in the space `view_proj` transforms from, so a projection
+    /// alone gives view space and `proj * view` gives world space. Like
+    /// `Mat4::perspective`, clip space depth is expected in `[-1, 1]`.
+    pub fn from_matrix(view_proj: &Mat4<T>) -> Self {
+        let w = view_proj.row(3);
+        let plane = |v: Vec4<T>| {
+            let normal = v.xyz();
+            let length = normal.length();
+            Plane {
+                normal: normal / length,
+                distance: -v.w / length,
+            }
+        };
+        let (x, y, z) = (view_proj.row(0), view_proj.row(1), view_proj.row(2));
+        Self {
+            planes: [
+                plane(w + x),
+                plane(w - x),
+                plane(w + y),
+                plane(w - y),
+                plane(w + z),
+                plane(w - z),
+            ],
+        }
+    }
+
+    /// Return whether the point lies inside or on the frustum
+    pub fn contains_point(&self, point: &Vec3<T>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(*point) >= T::zero())
+    }
+
+    /// Return whether a sphere is at least partly inside the frustum
+    ///
+    /// Only rejects spheres entirely behind one of the planes.
+    pub fn intersects_sphere(&self, center: Vec3<T>, radius: T) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(center) >= -radius)
+    }
+
+    /// Return whether a box is at least partly inside the frustum
+    ///
+    /// For each plane only the corner furthest along its normal (the p-vertex) is
+    /// tested, and the box is rejected when even that corner is outside.
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        self.planes.iter().all(|plane| {
+            let n = plane.normal;
+            let pick = |positive: bool, min: T, max: T| if positive { max } else { min };
+            let p_vertex = Vec3::new(
+                pick(n.x >= T::zero(), aabb.min.x, aabb.max.x),
+                pick(n.y >= T::zero(), aabb.min.y, aabb.max.y),
+                pick(n.z >= T::zero(), aabb.min.z, aabb.max.z),
+            );
+            plane.signed_distance(p_vertex) >= T::zero()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::{Aabb3, Frustum};
+    use crate::matrix::Mat4;
+    use crate::vector::Vec3;
+    use std::f64::consts::FRAC_PI_2;
+
+    // 90 degrees each way, so the side planes are at 45 degrees
+    fn frustum() -> Frustum<f64> {
+        Frustum::from_matrix(&Mat4::perspective(FRAC_PI_2, 1.0, 1.0, 100.0))
+    }
+
+    // a point on each plane, in the same order as the planes
+    fn on_planes() -> [Vec3<f64>; 6] {
+        [
+            Vec3::new(-10.0, 0.0, -10.0),
+            Vec3::new(10.0, 0.0, -10.0),
+            Vec3::new(0.0, -10.0, -10.0),
+            Vec3::new(0.0, 10.0, -10.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(0.0, 0.0, -100.0),
+        ]
+    }
+
+    #[test]
+    fn from_matrix_test() {
+        let f = frustum();
+        let h = 0.5_f64.sqrt();
+        let normals = [
+            Vec3::new(h, 0.0, -h),
+            Vec3::new(-h, 0.0, -h),
+            Vec3::new(0.0, h, -h),
+            Vec3::new(0.0, -h, -h),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        ];
+
+        for ((plane, normal), point) in f.planes.iter().zip(normals).zip(on_planes()) {
+            assert!((plane.normal - normal).length() < 1e-12, "{:?}", plane);
+            assert!(plane.signed_distance(point).abs() < 1e-9, "{:?}", plane);
+        }
+    }
+
+    #[test]
+    fn contains_point_test() {
+        let f = frustum();
+
+        assert!(f.contains_point(&Vec3::new(0.0, 0.0, -50.0)));
+        assert!(f.contains_point(&Vec3::new(9.0, -9.0, -10.0)));
+        assert!(!f.contains_point(&Vec3::new(11.0, 0.0, -10.0)));
+        assert!(!f.contains_point(&Vec3::new(0.0, 0.0, -0.5)));
+        assert!(!f.contains_point(&Vec3::new(0.0, 0.0, -101.0)));
+        assert!(!f.contains_point(&Vec3::new(0.0, 0.0, 10.0)));
+    }
+
+    #[test]
+    fn intersects_sphere_test() {
+        let f = frustum();
+        let radius = 0.5;
+
+        for (plane, point) in f.planes.iter().zip(on_planes()) {
+            let just_inside = point - plane.normal * (radius * 0.99);
+            let just_outside = point - plane.normal * (radius * 1.01);
+
+            assert!(f.intersects_sphere(just_inside, radius), "{:?}", plane);
+            assert!(!f.intersects_sphere(just_outside, radius), "{:?}", plane);
+        }
+        assert!(f.intersects_sphere(Vec3::new(0.0, 0.0, -10.0), 1.0));
+        // the camera is inside a big enough sphere behind it
+        assert!(f.intersects_sphere(Vec3::new(0.0, 0.0, 5.0), 10.0));
+    }
+
+    #[test]
+    fn intersects_aabb_test() {
+        let f = frustum();
+        let cube =
+            |center: Vec3<f64>| Aabb3::from_center_half_extents(center, Vec3::new(1.0, 1.0, 1.0));
+
+        assert!(f.intersects_aabb(&cube(Vec3::new(0.0, 0.0, -10.0))));
+        assert!(!f.intersects_aabb(&cube(Vec3::new(0.0, 0.0, 10.0))));
+        assert!(!f.intersects_aabb(&cube(Vec3::new(-20.0, 0.0, -10.0))));
+        assert!(!f.intersects_aabb(&cube(Vec3::new(0.0, 0.0, -102.0))));
+        // a box surrounding the whole frustum has no corners inside it
+        let everything = Aabb3::new(
+            Vec3::new(-500.0, -500.0, -500.0),
+            Vec3::new(500.0, 500.0, 500.0),
+        );
+        assert!(f.intersects_aabb(&everything));
+    }
+
+    #[test]
+    fn intersects_aabb_straddling_test() {
+        let f = frustum();
+
+        // straddling each plane, with the center (and most of the box) outside it
+        for (plane, point) in f.planes.iter().zip(on_planes()) {
+            let center = point - plane.normal * 0.9;
+            let aabb = Aabb3::from_center_half_extents(center, Vec3::new(1.0, 1.0, 1.0));
+
+            assert!(!f.contains_point(&center));
+            assert!(f.intersects_aabb(&aabb), "{:?}", plane);
+        }
+    }
+
+    #[test]
+    fn world_space_test() {
+        // a camera at (5, 0, 0) looking down +X
+        let view = Mat4::look_at(
+            Vec3::new(5.0, 0.0, 0.0),
+            Vec3::new(10.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        let proj = Mat4::perspective(FRAC_PI_2, 1.0, 1.0, 100.0);
+        let f = Frustum::from_matrix(&(proj * view));
+
+        assert!(f.contains_point(&Vec3::new(20.0, 3.0, -3.0)));
+        assert!(!f.contains_point(&Vec3::new(0.0, 0.0, 0.0)));
+        assert!(!f.contains_point(&Vec3::new(20.0, 0.0, 16.0)));
+    }
+}
@@ -0,0 +1,174 @@
+use crate::vector::Vec3;
+
+const MAX_ITERATIONS: usize = 64;
+const EPSILON: f32 = 1e-12;
+
+/// Return the vector triple product `(a x b) x c`
+fn triple(a: Vec3<f32>, b: Vec3<f32>, c: Vec3<f32>) -> Vec3<f32> {
+    a.cross(&b).cross(&c)
+}
+
+/// Reduce `simplex` to the feature closest to the origin and return the next search
+/// direction towards it, or `None` if the simplex contains the origin.
+///
+/// The newest point is always last in `simplex`.
+fn update_simplex(simplex: &mut Vec<Vec3<f32>>) -> Option<Vec3<f32>> {
+    let dir = match simplex.len() {
+        2 => line(simplex),
+        3 => triangle(simplex),
+        _ => return tetrahedron(simplex),
+    };
+    if dir.length_squared() < EPSILON {
+        // the origin lies exactly on the current simplex
+        None
+    } else {
+        Some(dir)
+    }
+}
+
+fn line(simplex: &mut Vec<Vec3<f32>>) -> Vec3<f32> {
+    let (b, a) = (simplex[0], simplex[1]);
+    let (ab, ao) = (b - a, a * -1.0);
+    if ab.dot(&ao) > 0.0 {
+        triple(ab, ao, ab)
+    } else {
+        *simplex = vec![a];
+        ao
+    }
+}
+
+fn triangle(simplex: &mut Vec<Vec3<f32>>) -> Vec3<f32> {
+    let (c, b, a) = (simplex[0], simplex[1], simplex[2]);
+    let (ab, ac, ao) = (b - a, c - a, a * -1.0);
+    let abc = ab.cross(&ac);
+
+    if abc.cross(&ac).dot(&ao) > 0.0 {
+        if ac.dot(&ao) > 0.0 {
+            *simplex = vec![c, a];
+            triple(ac, ao, ac)
+        } else {
+            *simplex = vec![b, a];
+            line(simplex)
+        }
+    } else if ab.cross(&abc).dot(&ao) > 0.0 {
+        *simplex = vec![b, a];
+        line(simplex)
+    } else if abc.dot(&ao) >= 0.0 {
+        abc
+    } else {
+        // keep the winding so that `abc` faces the origin
+        *simplex = vec![b, c, a];
+        abc * -1.0
+    }
+}
+
+fn tetrahedron(simplex: &mut Vec<Vec3<f32>>) -> Option<Vec3<f32>> {
+    let (d, c, b, a) = (simplex[0], simplex[1], simplex[2], simplex[3]);
+    let ao = a * -1.0;
+    // the triangle case leaves `d, c, b` wound so that `abc` faces away from `d`
+    for [p, q] in [[c, b], [d, c], [b, d]] {
+        let normal = (q - a).cross(&(p - a));
+        if normal.dot(&ao) > 0.0 {
+            *simplex = vec![p, q, a];
+            let dir = triangle(simplex);
+            return if dir.length_squared() < EPSILON {
+                None
+            } else {
+                Some(dir)
+            };
+        }
+    }
+    None
+}
+
+/// Return whether the convex hulls of two point sets overlap (GJK)
+///
+/// Hulls that only touch count as overlapping, and an empty point set never
+/// overlaps anything. Degenerate configurations that fail to converge are
+/// treated as overlapping.
+pub fn gjk_intersects(a: &[Vec3<f32>], b: &[Vec3<f32>]) -> bool {
+    if a.is_empty() || b.is_empty() {
+        return false;
+    }
+    let support = |d: Vec3<f32>| Vec3::support(a, &d) - Vec3::support(b, &(d * -1.0));
+
+    let first = support(Vec3::new(1.0, 0.0, 0.0));
+    let mut simplex = vec![first];
+    let mut dir = first * -1.0;
+    for _ in 0..MAX_ITERATIONS {
+        if dir.length_squared() < EPSILON {
+            return true;
+        }
+        let p = support(dir);
+        if p.dot(&dir) < 0.0 {
+            // the farthest point towards the origin does not pass it
+            return false;
+        }
+        simplex.push(p);
+        match update_simplex(&mut simplex) {
+            Some(d) => dir = d,
+            None => return true,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::gjk_intersects;
+    use crate::vector::Vec3;
+
+    fn cube(min: Vec3<f32>, size: f32) -> Vec<Vec3<f32>> {
+        (0..8)
+            .map(|i| {
+                min + Vec3::new(
+                    (i & 1) as f32 * size,
+                    ((i >> 1) & 1) as f32 * size,
+                    ((i >> 2) & 1) as f32 * size,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn overlapping_test() {
+        let a = cube(Vec3::new(0.0, 0.0, 0.0), 2.0);
+
+        assert!(gjk_intersects(&a, &cube(Vec3::new(1.0, 1.0, 1.0), 2.0)));
+        assert!(gjk_intersects(&a, &cube(Vec3::new(0.5, 0.5, 0.5), 0.5)));
+        assert!(gjk_intersects(&a, &cube(Vec3::new(-1.5, 0.5, -0.5), 2.0)));
+    }
+
+    #[test]
+    fn separated_test() {
+        let a = cube(Vec3::new(0.0, 0.0, 0.0), 1.0);
+
+        assert!(!gjk_intersects(&a, &cube(Vec3::new(3.0, 0.0, 0.0), 1.0)));
+        assert!(!gjk_intersects(&a, &cube(Vec3::new(1.1, 1.1, 1.1), 1.0)));
+        assert!(!gjk_intersects(&a, &cube(Vec3::new(-0.5, -0.5, -2.0), 1.0)));
+        assert!(!gjk_intersects(&a, &[]));
+    }
+
+    #[test]
+    fn touching_test() {
+        let a = cube(Vec3::new(0.0, 0.0, 0.0), 1.0);
+
+        // sharing a face
+        assert!(gjk_intersects(&a, &cube(Vec3::new(1.0, 0.0, 0.0), 1.0)));
+        // sharing a single corner
+        assert!(gjk_intersects(&a, &cube(Vec3::new(1.0, 1.0, 1.0), 1.0)));
+    }
+
+    #[test]
+    fn tetrahedron_test() {
+        let tetra = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(0.0, 4.0, 0.0),
+            Vec3::new(0.0, 0.0, 4.0),
+        ];
+
+        assert!(gjk_intersects(&tetra, &[Vec3::new(1.0, 1.0, 1.0)]));
+        assert!(!gjk_intersects(&tetra, &[Vec3::new(2.0, 2.0, 2.0)]));
+    }
+}
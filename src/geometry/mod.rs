@@ -0,0 +1,20 @@
+mod aabb;
+mod fit;
+mod frustum;
+mod gjk;
+mod plane;
+mod ray;
+mod segment;
+mod sphere;
+mod triangle;
+
+// rexports
+pub use crate::geometry::aabb::*;
+pub use crate::geometry::fit::*;
+pub use crate::geometry::frustum::*;
+pub use crate::geometry::gjk::*;
+pub use crate::geometry::plane::*;
+pub use crate::geometry::ray::*;
+pub use crate::geometry::segment::*;
+pub use crate::geometry::sphere::*;
+pub use crate::geometry::triangle::*;
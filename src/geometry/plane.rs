@@ -0,0 +1,167 @@
+use crate::vector::Vec3;
+use num::Float;
+use std::cmp::Ordering;
+
+/// A plane in 3D space, made of the points `p` where `normal.dot(&p) == distance`.
+///
+/// The normal is kept normalized, so `distance` is the signed distance of the
+/// plane from the origin along it. This matches [`Ray3::intersect_plane`](crate::geometry::Ray3::intersect_plane).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Plane<T>
+where
+    T: Float,
+{
+    pub normal: Vec3<T>,
+    pub distance: T,
+}
+
+impl<T> Plane<T>
+where
+    T: Float,
+{
+    /// Create a plane through `point` facing along `normal`
+    ///
+    /// `normal` is normalized, so it must not be zero.
+    pub fn from_point_normal(point: Vec3<T>, normal: Vec3<T>) -> Self {
+        let normal = normal.normalize();
+        Self {
+            normal,
+            distance: normal.dot(&point),
+        }
+    }
+
+    /// Create the plane through three points, or `None` if they are collinear
+    ///
+    /// The normal is `(b - a).cross(&(c - a))`, so it faces the side from which
+    /// `a, b, c` appear counter-clockwise.
+    pub fn try_from_points(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Option<Self> {
+        let normal = (b - a).cross(&(c - a));
+        if normal.length_squared() <= T::epsilon() * T::epsilon() {
+            None
+        } else {
+            Some(Self::from_point_normal(a, normal))
+        }
+    }
+
+    /// Create the plane through three points
+    ///
+    /// # Panics
+    ///
+    /// Panics if the points are collinear, use `try_from_points` to handle that case.
+    pub fn from_points(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Self {
+        Self::try_from_points(a, b, c).expect("cannot build a Plane from collinear points")
+    }
+
+    /// Return the signed distance from the plane to `p`, positive on the side the normal faces
+    pub fn signed_distance(&self, p: Vec3<T>) -> T {
+        self.normal.dot(&p) - self.distance
+    }
+
+    /// Return the closest point on the plane to `p`
+    pub fn project_point(&self, p: Vec3<T>) -> Vec3<T> {
+        p - self.normal * self.signed_distance(p)
+    }
+
+    /// Return `p` mirrored through the plane
+    pub fn reflect_point(&self, p: Vec3<T>) -> Vec3<T> {
+        let two = T::one() + T::one();
+        p - self.normal * (self.signed_distance(p) * two)
+    }
+
+    /// Return which side of the plane `p` is on
+    ///
+    /// `Greater` is the side the normal faces, `Less` the opposite side, and
+    /// `Equal` means exactly on the plane (no tolerance is applied).
+    pub fn side(&self, p: Vec3<T>) -> Ordering {
+        self.signed_distance(p)
+            .partial_cmp(&T::zero())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::Plane;
+    use crate::vector::Vec3;
+    use std::cmp::Ordering;
+
+    fn ground() -> Plane<f64> {
+        Plane::from_point_normal(Vec3::new(0.0, 2.0, 0.0), Vec3::new(0.0, 10.0, 0.0))
+    }
+
+    #[test]
+    fn from_point_normal_test() {
+        let p = ground();
+
+        assert_eq!(p.normal, Vec3::new(0.0, 1.0, 0.0));
+        assert_eq!(p.distance, 2.0);
+    }
+
+    #[test]
+    fn from_points_test() {
+        let p = Plane::from_points(
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(0.0, 2.0, 1.0),
+            Vec3::new(1.0, 2.0, 0.0),
+        );
+
+        assert_eq!(p, ground());
+        assert_eq!(
+            Plane::try_from_points(
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(1.0, 1.0, 1.0),
+                Vec3::new(3.0, 3.0, 3.0)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_collinear_points_test() {
+        let a = Vec3::new(1.0f32, 2.0, 3.0);
+        Plane::from_points(a, a, a * 2.0);
+    }
+
+    #[test]
+    fn signed_distance_test() {
+        let p = ground();
+
+        assert_eq!(p.signed_distance(Vec3::new(5.0, 7.0, -1.0)), 5.0);
+        assert_eq!(p.signed_distance(Vec3::new(5.0, -1.0, -1.0)), -3.0);
+        assert_eq!(p.signed_distance(Vec3::new(5.0, 2.0, -1.0)), 0.0);
+    }
+
+    #[test]
+    fn side_test() {
+        let p = ground();
+
+        assert_eq!(p.side(Vec3::new(0.0, 3.0, 0.0)), Ordering::Greater);
+        assert_eq!(p.side(Vec3::new(0.0, 1.0, 0.0)), Ordering::Less);
+        assert_eq!(p.side(Vec3::new(4.0, 2.0, 4.0)), Ordering::Equal);
+    }
+
+    #[test]
+    fn project_point_test() {
+        let p = Plane::from_point_normal(Vec3::new(1.0f64, 1.0, 1.0), Vec3::new(1.0, 2.0, -2.0));
+        let q = Vec3::new(4.0, -3.0, 7.0);
+
+        let projected = p.project_point(q);
+        assert!(p.signed_distance(projected).abs() < 1e-12);
+        assert!((p.project_point(projected) - projected).length() < 1e-12);
+    }
+
+    #[test]
+    fn reflect_point_test() {
+        let p = ground();
+
+        assert_eq!(
+            p.reflect_point(Vec3::new(1.0, 5.0, 1.0)),
+            Vec3::new(1.0, -1.0, 1.0)
+        );
+        assert_eq!(
+            p.reflect_point(p.reflect_point(Vec3::new(1.0, 5.0, 1.0))),
+            Vec3::new(1.0, 5.0, 1.0)
+        );
+    }
+}
@@ -0,0 +1,459 @@
+use crate::matrix::Mat4;
+use crate::vector::{unproject, Vec2, Vec3};
+use num::Float;
+
+/// Clip the parametric range `(t_min, t_max)` against the slab `min..=max` along one axis.
+///
+/// A direction of exactly zero is parallel to the slab, which either misses it entirely
+/// or leaves the range untouched depending on where the origin is.
+fn clip_slab<T>(origin: T, direction: T, min: T, max: T, range: (T, T)) -> Option<(T, T)>
+where
+    T: Float,
+{
+    if direction == T::zero() {
+        return if origin < min || origin > max {
+            None
+        } else {
+            Some(range)
+        };
+    }
+
+    let t1 = (min - origin) / direction;
+    let t2 = (max - origin) / direction;
+    let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+    let range = (range.0.max(near), range.1.min(far));
+    if range.0 > range.1 {
+        None
+    } else {
+        Some(range)
+    }
+}
+
+/// Return the nearest non-negative root of `a t^2 + 2 b t + c = 0`, used for spheres and circles.
+fn nearest_root<T>(a: T, b: T, c: T) -> Option<T>
+where
+    T: Float,
+{
+    if a == T::zero() {
+        return None;
+    }
+    let discriminant = b * b - a * c;
+    if discriminant < T::zero() {
+        return None;
+    }
+    let s = discriminant.sqrt();
+    let (t0, t1) = ((-b - s) / a, (-b + s) / a);
+    if t1 < T::zero() {
+        // both hits are behind the origin
+        None
+    } else if t0 >= T::zero() {
+        Some(t0)
+    } else {
+        // the origin is inside, so the only forward hit is the exit
+        Some(t1)
+    }
+}
+
+/// A 3D ray, made of an origin and a direction.
+///
+/// The direction does not need to be normalized, but the distances returned by
+/// the intersection methods are then in units of its length.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ray3<T>
+where
+    T: Float,
+{
+    pub origin: Vec3<T>,
+    pub direction: Vec3<T>,
+}
+
+impl<T> Ray3<T>
+where
+    T: Float,
+{
+    /// Create a 3D ray from an origin and direction
+    pub fn new(origin: Vec3<T>, direction: Vec3<T>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Create a 3D ray from an origin and direction, normalizing the direction
+    ///
+    /// Distances along the ray are then in world units.
+    pub fn new_normalized(origin: Vec3<T>, direction: Vec3<T>) -> Self {
+        Self::new(origin, direction.normalize())
+    }
+
+    /// Create a picking ray through a point on the screen (e.g. the mouse cursor)
+    ///
+    /// `screen` and `viewport` are in window coordinates as for `project`, with `y`
+    /// pointing up. The ray starts on the near plane and points (normalized) towards
+    /// the far plane. Returns `None` when `view_proj` isn't invertible.
+    pub fn from_screen_point(
+        screen: Vec2<T>,
+        view_proj: &Mat4<T>,
+        viewport: (T, T, T, T),
+    ) -> Option<Self> {
+        let inverse = view_proj.inverse()?;
+        let near = unproject(Vec3::new(screen.x, screen.y, T::zero()), &inverse, viewport)?;
+        let far = unproject(Vec3::new(screen.x, screen.y, T::one()), &inverse, viewport)?;
+        Some(Self::new_normalized(near, far - near))
+    }
+
+    /// Return the point `t` along the ray
+    ///
+    /// `origin + direction * t`
+    pub fn point_at(&self, t: T) -> Vec3<T> {
+        self.origin + self.direction * t
+    }
+
+    /// Return the point on the ray nearest to `p`
+    ///
+    /// `p` is projected onto the direction, and points behind the origin snap to it.
+    pub fn closest_point(&self, p: &Vec3<T>) -> Vec3<T> {
+        let length_squared = self.direction.length_squared();
+        if length_squared == T::zero() {
+            return self.origin;
+        }
+        let t = (*p - self.origin).dot(&self.direction) / length_squared;
+        self.point_at(t.max(T::zero()))
+    }
+
+    /// Return the distance to the first hit with a sphere, if any
+    ///
+    /// Hits behind the origin are ignored, so a ray starting inside the sphere
+    /// returns the distance to where it exits.
+    pub fn intersect_sphere(&self, center: Vec3<T>, radius: T) -> Option<T> {
+        let oc = self.origin - center;
+        nearest_root(
+            self.direction.length_squared(),
+            oc.dot(&self.direction),
+            oc.length_squared() - radius * radius,
+        )
+    }
+
+    /// Return the distance to the hit with the plane `normal.dot(p) == d`, if any
+    ///
+    /// Returns `None` when the ray is parallel to the plane or the plane is behind the origin.
+    /// Whether it is parallel is judged relative to the lengths of `normal` and the
+    /// direction, so neither needs to be normalized.
+    pub fn intersect_plane(&self, normal: Vec3<T>, d: T) -> Option<T> {
+        let denom = normal.dot(&self.direction);
+        // relative, as neither the normal nor the direction has to be unit length
+        if denom.abs() <= T::epsilon() * normal.length() * self.direction.length() {
+            return None;
+        }
+        let t = (d - normal.dot(&self.origin)) / denom;
+        if t < T::zero() {
+            None
+        } else {
+            Some(t)
+        }
+    }
+
+    /// Return the `(entry, exit)` distances through an axis-aligned box, if it is hit
+    ///
+    /// A ray starting inside the box has an entry distance of zero. Boxes entirely
+    /// behind the origin are not hit.
+    pub fn intersect_aabb(&self, min: Vec3<T>, max: Vec3<T>) -> Option<(T, T)> {
+        let (o, d) = (self.origin, self.direction);
+        let range = (T::zero(), T::infinity());
+        let range = clip_slab(o.x, d.x, min.x, max.x, range)?;
+        let range = clip_slab(o.y, d.y, min.y, max.y, range)?;
+        clip_slab(o.z, d.z, min.z, max.z, range)
+    }
+}
+
+/// A 2D ray, made of an origin and a direction.
+///
+/// The 2D counterpart of [`Ray3`], useful for picking in 2D scenes.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ray2<T>
+where
+    T: Float,
+{
+    pub origin: Vec2<T>,
+    pub direction: Vec2<T>,
+}
+
+impl<T> Ray2<T>
+where
+    T: Float,
+{
+    /// Create a 2D ray from an origin and direction
+    pub fn new(origin: Vec2<T>, direction: Vec2<T>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Create a 2D ray from an origin and direction, normalizing the direction
+    ///
+    /// Distances along the ray are then in world units.
+    pub fn new_normalized(origin: Vec2<T>, direction: Vec2<T>) -> Self {
+        Self::new(origin, direction.normalize())
+    }
+
+    /// Return the point `t` along the ray
+    ///
+    /// `origin + direction * t`
+    pub fn point_at(&self, t: T) -> Vec2<T> {
+        self.origin + self.direction * t
+    }
+
+    /// Return the point on the ray nearest to `p`
+    ///
+    /// `p` is projected onto the direction, and points behind the origin snap to it.
+    pub fn closest_point(&self, p: &Vec2<T>) -> Vec2<T> {
+        let length_squared = self.direction.length_squared();
+        if length_squared == T::zero() {
+            return self.origin;
+        }
+        let t = (*p - self.origin).dot(&self.direction) / length_squared;
+        self.point_at(t.max(T::zero()))
+    }
+
+    /// Return the distance to the first hit with a circle, if any
+    ///
+    /// Behaves like [`Ray3::intersect_sphere`].
+    pub fn intersect_circle(&self, center: Vec2<T>, radius: T) -> Option<T> {
+        let oc = self.origin - center;
+        nearest_root(
+            self.direction.length_squared(),
+            oc.dot(&self.direction),
+            oc.length_squared() - radius * radius,
+        )
+    }
+
+    /// Return the distance to the hit with the line `normal.dot(p) == d`, if any
+    ///
+    /// Behaves like [`Ray3::intersect_plane`].
+    pub fn intersect_line(&self, normal: Vec2<T>, d: T) -> Option<T> {
+        let denom = normal.dot(&self.direction);
+        // relative, as neither the normal nor the direction has to be unit length
+        if denom.abs() <= T::epsilon() * normal.length() * self.direction.length() {
+            return None;
+        }
+        let t = (d - normal.dot(&self.origin)) / denom;
+        if t < T::zero() {
+            None
+        } else {
+            Some(t)
+        }
+    }
+
+    /// Return the `(entry, exit)` distances through an axis-aligned rectangle, if it is hit
+    ///
+    /// Behaves like [`Ray3::intersect_aabb`].
+    pub fn intersect_aabb(&self, min: Vec2<T>, max: Vec2<T>) -> Option<(T, T)> {
+        let (o, d) = (self.origin, self.direction);
+        let range = (T::zero(), T::infinity());
+        let range = clip_slab(o.x, d.x, min.x, max.x, range)?;
+        clip_slab(o.y, d.y, min.y, max.y, range)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::{Ray2, Ray3};
+    use crate::matrix::Mat4;
+    use crate::vector::{project, Vec2, Vec3};
+
+    fn ray(origin: (f32, f32, f32), direction: (f32, f32, f32)) -> Ray3<f32> {
+        Ray3::new(
+            Vec3::new(origin.0, origin.1, origin.2),
+            Vec3::new(direction.0, direction.1, direction.2),
+        )
+    }
+
+    #[test]
+    fn point_at_test() {
+        let r = ray((1.0, 2.0, 3.0), (0.0, 0.0, -2.0));
+
+        assert_eq!(r.point_at(0.0), r.origin);
+        assert_eq!(r.point_at(1.5), Vec3::new(1.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn new_normalized_test() {
+        let r = Ray3::new_normalized(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 3.0, 4.0));
+
+        assert_eq!(r.direction, Vec3::new(0.0, 0.6, 0.8));
+        assert_eq!(r.point_at(0.0), r.origin);
+        assert_eq!(r.point_at(5.0), Vec3::new(1.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn closest_point_test() {
+        let r = ray((1.0, 0.0, 0.0), (2.0, 0.0, 0.0));
+
+        assert_eq!(
+            r.closest_point(&Vec3::new(4.0, 3.0, -1.0)),
+            Vec3::new(4.0, 0.0, 0.0)
+        );
+        // points behind the origin snap to it
+        assert_eq!(r.closest_point(&Vec3::new(-3.0, 1.0, 0.0)), r.origin);
+        assert_eq!(
+            Ray2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)).closest_point(&Vec2::new(2.0, 0.0)),
+            Vec2::new(1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn from_screen_point_test() {
+        let eye = Vec3::new(2.0, 1.0, 5.0);
+        let target = Vec3::new(-1.0, 0.0, -3.0);
+        let view_proj = Mat4::perspective(1.0, 16.0 / 9.0, 0.1, 100.0)
+            * Mat4::look_at(eye, target, Vec3::new(0.0, 1.0, 0.0));
+        let viewport = (0.0, 0.0, 1920.0, 1080.0);
+
+        // the centre of the viewport looks straight down the camera's forward axis
+        let center =
+            Ray3::from_screen_point(Vec2::new(960.0, 540.0), &view_proj, viewport).unwrap();
+        let forward = (target - eye).normalize();
+        assert!((center.direction - forward).length() < 1e-4);
+        assert!((center.origin - (eye + forward * 0.1)).length() < 1e-4);
+
+        // any ray passes back through the pixel it was picked from
+        let cursor = Vec2::new(300.0, 800.0);
+        let ray = Ray3::from_screen_point(cursor, &view_proj, viewport).unwrap();
+        let hit = project(ray.point_at(20.0), &view_proj, viewport).unwrap();
+        assert!((Vec2::new(hit.x, hit.y) - cursor).length() < 1e-2);
+
+        let singular = Mat4::scale(Vec3::new(1.0, 1.0, 0.0));
+        assert_eq!(Ray3::from_screen_point(cursor, &singular, viewport), None);
+    }
+
+    #[test]
+    fn intersect_sphere_test() {
+        let center = Vec3::new(0.0, 0.0, -5.0);
+
+        assert_eq!(
+            ray((0.0, 0.0, 0.0), (0.0, 0.0, -1.0)).intersect_sphere(center, 1.0),
+            Some(4.0)
+        );
+        // unnormalized directions scale the distance
+        assert_eq!(
+            ray((0.0, 0.0, 0.0), (0.0, 0.0, -2.0)).intersect_sphere(center, 1.0),
+            Some(2.0)
+        );
+        assert_eq!(
+            ray((0.0, 2.0, 0.0), (0.0, 0.0, -1.0)).intersect_sphere(center, 1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn intersect_sphere_inside_and_behind_test() {
+        let center = Vec3::new(0.0, 0.0, -5.0);
+
+        // from the center the only forward hit is the exit
+        assert_eq!(
+            ray((0.0, 0.0, -5.0), (0.0, 0.0, -1.0)).intersect_sphere(center, 1.0),
+            Some(1.0)
+        );
+        // the sphere is entirely behind the ray
+        assert_eq!(
+            ray((0.0, 0.0, 0.0), (0.0, 0.0, 1.0)).intersect_sphere(center, 1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn intersect_plane_test() {
+        let up = Vec3::new(0.0, 1.0, 0.0);
+
+        assert_eq!(
+            ray((0.0, 5.0, 0.0), (0.0, -1.0, 0.0)).intersect_plane(up, 1.0),
+            Some(4.0)
+        );
+        // parallel to the plane
+        assert_eq!(
+            ray((0.0, 5.0, 0.0), (1.0, 0.0, 0.0)).intersect_plane(up, 1.0),
+            None
+        );
+        // the plane is behind the origin
+        assert_eq!(
+            ray((0.0, 5.0, 0.0), (0.0, 1.0, 0.0)).intersect_plane(up, 1.0),
+            None
+        );
+        // short directions and normals are not parallel
+        let short = ray((0.0, 5.0, 0.0), (0.0, -1e-9, 0.0));
+        assert_eq!(short.intersect_plane(up, 1.0), Some(4e9));
+        assert_eq!(short.intersect_plane(up * 1e-9, 1e-9), Some(4e9));
+        assert_eq!(
+            ray((0.0, 5.0, 0.0), (1e-9, 0.0, 0.0)).intersect_plane(up * 1e-9, 1e-9),
+            None
+        );
+    }
+
+    #[test]
+    fn intersect_aabb_test() {
+        let (min, max) = (Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+
+        assert_eq!(
+            ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)).intersect_aabb(min, max),
+            Some((4.0, 6.0))
+        );
+        assert_eq!(
+            ray((-5.0, 3.0, 0.0), (1.0, 0.0, 0.0)).intersect_aabb(min, max),
+            None
+        );
+        // the box is behind the origin
+        assert_eq!(
+            ray((5.0, 0.0, 0.0), (1.0, 0.0, 0.0)).intersect_aabb(min, max),
+            None
+        );
+    }
+
+    #[test]
+    fn intersect_aabb_inside_test() {
+        let (min, max) = (Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+
+        assert_eq!(
+            ray((0.0, 0.0, 0.0), (0.0, 0.0, 1.0)).intersect_aabb(min, max),
+            Some((0.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn intersect_aabb_parallel_slab_test() {
+        let (min, max) = (Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+
+        // parallel to the y and z slabs and inside them
+        assert_eq!(
+            ray((-5.0, 0.5, -0.5), (2.0, 0.0, 0.0)).intersect_aabb(min, max),
+            Some((2.0, 3.0))
+        );
+        // exactly on a slab boundary
+        assert_eq!(
+            ray((-5.0, 1.0, 0.0), (1.0, 0.0, 0.0)).intersect_aabb(min, max),
+            Some((4.0, 6.0))
+        );
+        // parallel and outside a slab
+        assert_eq!(
+            ray((-5.0, 1.5, 0.0), (1.0, 0.0, 0.0)).intersect_aabb(min, max),
+            None
+        );
+    }
+
+    #[test]
+    fn ray2_test() {
+        let r = Ray2::new(Vec2::new(-5.0, 0.0), Vec2::new(1.0, 0.0));
+
+        assert_eq!(r.point_at(2.0), Vec2::new(-3.0, 0.0));
+        assert_eq!(r.intersect_circle(Vec2::new(0.0, 0.0), 1.0), Some(4.0));
+        assert_eq!(r.intersect_circle(Vec2::new(0.0, 2.0), 1.0), None);
+        assert_eq!(r.intersect_line(Vec2::new(1.0, 0.0), 2.0), Some(7.0));
+        assert_eq!(r.intersect_line(Vec2::new(0.0, 1.0), 2.0), None);
+        let short = Ray2::new(r.origin, r.direction * 1e-9);
+        let t = short.intersect_line(Vec2::new(1e-9, 0.0), 2e-9).unwrap();
+        assert!((short.point_at(t) - Vec2::new(2.0, 0.0)).length() < 1e-6);
+        assert_eq!(
+            r.intersect_aabb(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0)),
+            Some((4.0, 6.0))
+        );
+        assert_eq!(
+            Ray2::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, -1.0))
+                .intersect_aabb(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0)),
+            Some((0.0, 1.0))
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use crate::vector::{Vec2, Vec3};
+use num::Float;
+
+/// Clamp `t` into `[0, 1]`
+fn clamp01<T>(t: T) -> T
+where
+    T: Float,
+{
+    t.max(T::zero()).min(T::one())
+}
+
+/// Return the parameters `(s, t)` of the closest points between two segments
+///
+/// Takes the dot products of the directions `d1`, `d2` and the offset `r = start1 - start2`
+/// (Ericson, Real-Time Collision Detection, 5.1.9). A segment is treated as a point
+/// when it is zero length, or negligible next to the other one, and parallel segments
+/// pick `s = 0` so the answer is deterministic. The tolerances are relative, so the
+/// result doesn't depend on the scale of the input.
+fn closest_params<T>(d1_d1: T, d2_d2: T, d1_d2: T, d1_r: T, d2_r: T) -> (T, T)
+where
+    T: Float,
+{
+    let (a, e, b, c, f) = (d1_d1, d2_d2, d1_d2, d1_r, d2_r);
+    let epsilon = T::epsilon();
+
+    if a <= T::zero() && e <= T::zero() {
+        return (T::zero(), T::zero());
+    }
+    if a <= epsilon * e {
+        return (T::zero(), clamp01(f / e));
+    }
+    if e <= epsilon * a {
+        return (clamp01(-c / a), T::zero());
+    }
+
+    let denom = a * e - b * b;
+    let s = if denom <= epsilon * a * e {
+        T::zero()
+    } else {
+        clamp01((b * f - c * e) / denom)
+    };
+    let t = (b * s + f) / e;
+    if t < T::zero() {
+        (clamp01(-c / a), T::zero())
+    } else if t > T::one() {
+        (clamp01((b - c) / a), T::one())
+    } else {
+        (s, t)
+    }
+}
+
+/// A line segment in 2D space, between `start` and `end`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Segment2<T>
+where
+    T: Float,
+{
+    pub start: Vec2<T>,
+    pub end: Vec2<T>,
+}
+
+impl<T> Segment2<T>
+where
+    T: Float,
+{
+    /// Create a segment between two points
+    pub fn new(start: Vec2<T>, end: Vec2<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// Return the length of the segment
+    pub fn length(&self) -> T {
+        (self.end - self.start).length()
+    }
+
+    /// Return the closest point on the segment to `p`
+    ///
+    /// Points beyond either end clamp to that endpoint, and a zero length segment
+    /// always returns `start`.
+    pub fn closest_point_to(&self, p: Vec2<T>) -> Vec2<T> {
+        let d = self.end - self.start;
+        let len_sq = d.length_squared();
+        if len_sq <= T::zero() {
+            return self.start;
+        }
+        self.start + d * clamp01((p - self.start).dot(&d) / len_sq)
+    }
+
+    /// Return the distance from `p` to the closest point on the segment
+    pub fn distance_to_point(&self, p: Vec2<T>) -> T {
+        (p - self.closest_point_to(p)).length()
+    }
+
+    /// Return the closest pair of points `(on self, on other)` between two segments
+    ///
+    /// When many pairs are equally close (parallel segments), the pair starting
+    /// from the point nearest `self.start` is returned.
+    pub fn closest_points_between(&self, other: &Segment2<T>) -> (Vec2<T>, Vec2<T>) {
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let r = self.start - other.start;
+        let (s, t) = closest_params(
+            d1.length_squared(),
+            d2.length_squared(),
+            d1.dot(&d2),
+            d1.dot(&r),
+            d2.dot(&r),
+        );
+        (self.start + d1 * s, other.start + d2 * t)
+    }
+
+    /// Return the point where two segments cross, if they do
+    ///
+    /// Touching at an endpoint counts as crossing. Collinear overlapping segments
+    /// return the point of the overlap closest to `self.start`, and zero length
+    /// segments behave as single points. The parallel and collinear tests are
+    /// relative to the lengths involved, so tiny segments work as well as large ones.
+    pub fn intersect(&self, other: &Segment2<T>) -> Option<Vec2<T>> {
+        let epsilon = T::epsilon();
+        let r = self.end - self.start;
+        let s = other.end - other.start;
+        let qp = other.start - self.start;
+        let (r_len, s_len, qp_len) = (r.length(), s.length(), qp.length());
+
+        let denom = r.cross(&s);
+        if denom.abs() > epsilon * r_len * s_len {
+            let t = qp.cross(&s) / denom;
+            let u = qp.cross(&r) / denom;
+            let range = T::zero()..=T::one();
+            return if range.contains(&t) && range.contains(&u) {
+                Some(self.start + r * t)
+            } else {
+                None
+            };
+        }
+
+        // parallel: only collinear segments can meet, so work along their shared line
+        if qp.cross(&r).abs() > epsilon * qp_len * r_len
+            || qp.cross(&s).abs() > epsilon * qp_len * s_len
+        {
+            return None;
+        }
+        let axis = if r_len >= s_len { r } else { s };
+        let axis_sq = axis.length_squared();
+        if axis_sq <= T::zero() {
+            // both are points
+            return if qp_len <= T::zero() {
+                Some(self.start)
+            } else {
+                None
+            };
+        }
+
+        let param = |p: Vec2<T>| (p - self.start).dot(&axis) / axis_sq;
+        let (a0, a1) = (T::zero(), param(self.end));
+        let (b0, b1) = (param(other.start), param(other.end));
+        let lo = a0.min(a1).max(b0.min(b1));
+        let hi = a0.max(a1).min(b0.max(b1));
+        if lo > hi {
+            None
+        } else {
+            Some(self.start + axis * lo)
+        }
+    }
+}
+
+/// A line segment in 3D space, between `start` and `end`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Segment3<T>
+where
+    T: Float,
+{
+    pub start: Vec3<T>,
+    pub end: Vec3<T>,
+}
+
+impl<T> Segment3<T>
+where
+    T: Float,
+{
+    /// Create a segment between two points
+    pub fn new(start: Vec3<T>, end: Vec3<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// Return the length of the segment
+    pub fn length(&self) -> T {
+        (self.end - self.start).length()
+    }
+
+    /// Return the closest point on the segment to `p`
+    ///
+    /// Points beyond either end clamp to that endpoint, and a zero length segment
+    /// always returns `start`.
+    pub fn closest_point_to(&self, p: Vec3<T>) -> Vec3<T> {
+        let d = self.end - self.start;
+        let len_sq = d.length_squared();
+        if len_sq <= T::zero() {
+            return self.start;
+        }
+        self.start + d * clamp01((p - self.start).dot(&d) / len_sq)
+    }
+
+    /// Return the distance from `p` to the closest point on the segment
+    pub fn distance_to_point(&self, p: Vec3<T>) -> T {
+        (p - self.closest_point_to(p)).length()
+    }
+
+    /// Return the closest pair of points `(on self, on other)` between two segments
+    ///
+    /// Behaves like [`Segment2::closest_points_between`].
+    pub fn closest_points_between(&self, other: &Segment3<T>) -> (Vec3<T>, Vec3<T>) {
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let r = self.start - other.start;
+        let (s, t) = closest_params(
+            d1.length_squared(),
+            d2.length_squared(),
+            d1.dot(&d2),
+            d1.dot(&r),
+            d2.dot(&r),
+        );
+        (self.start + d1 * s, other.start + d2 * t)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::{Segment2, Segment3};
+    use crate::vector::{Vec2, Vec3};
+
+    fn seg2(a: (f64, f64), b: (f64, f64)) -> Segment2<f64> {
+        Segment2::new(Vec2::new(a.0, a.1), Vec2::new(b.0, b.1))
+    }
+
+    #[test]
+    fn closest_point_to_test() {
+        let s = Segment3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0));
+
+        assert_eq!(
+            s.closest_point_to(Vec3::new(1.0, 3.0, 0.0)),
+            Vec3::new(1.0, 0.0, 0.0)
+        );
+        // beyond each endpoint clamps
+        assert_eq!(s.closest_point_to(Vec3::new(-2.0, 1.0, 0.0)), s.start);
+        assert_eq!(s.closest_point_to(Vec3::new(9.0, 0.0, 1.0)), s.end);
+        assert_eq!(s.distance_to_point(Vec3::new(7.0, 4.0, 0.0)), 5.0);
+
+        let point = Segment3::new(Vec3::new(1.0, 1.0, 1.0), Vec3::new(1.0, 1.0, 1.0));
+        assert_eq!(
+            point.closest_point_to(Vec3::new(5.0, 5.0, 5.0)),
+            point.start
+        );
+    }
+
+    #[test]
+    fn closest_points_between_test() {
+        let a = Segment3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0));
+        let b = Segment3::new(Vec3::new(1.0, -1.0, 2.0), Vec3::new(1.0, 1.0, 2.0));
+        assert_eq!(
+            a.closest_points_between(&b),
+            (Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 2.0))
+        );
+
+        // clamped to the end of `a`
+        let c = Segment3::new(Vec3::new(6.0, -1.0, 0.0), Vec3::new(6.0, 1.0, 0.0));
+        assert_eq!(
+            a.closest_points_between(&c),
+            (Vec3::new(4.0, 0.0, 0.0), Vec3::new(6.0, 0.0, 0.0))
+        );
+
+        // parallel picks the pair nearest `self.start`
+        let d = seg2((1.0, 1.0), (3.0, 1.0));
+        let e = seg2((0.0, 0.0), (4.0, 0.0));
+        assert_eq!(
+            e.closest_points_between(&d),
+            (Vec2::new(1.0, 0.0), Vec2::new(1.0, 1.0))
+        );
+
+        // zero length segments
+        let p = seg2((2.0, 5.0), (2.0, 5.0));
+        assert_eq!(e.closest_points_between(&p), (Vec2::new(2.0, 0.0), p.start));
+        assert_eq!(p.closest_points_between(&p), (p.start, p.start));
+    }
+
+    #[test]
+    fn intersect_crossing_test() {
+        let a = seg2((0.0, 0.0), (4.0, 4.0));
+
+        assert_eq!(
+            a.intersect(&seg2((0.0, 4.0), (4.0, 0.0))),
+            Some(Vec2::new(2.0, 2.0))
+        );
+        // touching at an endpoint
+        assert_eq!(
+            a.intersect(&seg2((4.0, 4.0), (6.0, 0.0))),
+            Some(Vec2::new(4.0, 4.0))
+        );
+        // the lines cross, but past the end of the segments
+        assert_eq!(a.intersect(&seg2((5.0, 0.0), (6.0, -1.0))), None);
+    }
+
+    #[test]
+    fn intersect_parallel_test() {
+        let a = seg2((0.0, 0.0), (4.0, 0.0));
+
+        assert_eq!(a.intersect(&seg2((0.0, 1.0), (4.0, 1.0))), None);
+        // collinear, overlapping and disjoint
+        assert_eq!(
+            a.intersect(&seg2((6.0, 0.0), (2.0, 0.0))),
+            Some(Vec2::new(2.0, 0.0))
+        );
+        assert_eq!(
+            a.intersect(&seg2((-3.0, 0.0), (1.0, 0.0))),
+            Some(Vec2::new(0.0, 0.0))
+        );
+        assert_eq!(a.intersect(&seg2((5.0, 0.0), (6.0, 0.0))), None);
+    }
+
+    #[test]
+    fn intersect_zero_length_test() {
+        let a = seg2((0.0, 0.0), (4.0, 0.0));
+        let on = seg2((3.0, 0.0), (3.0, 0.0));
+        let off = seg2((3.0, 1.0), (3.0, 1.0));
+
+        assert_eq!(a.intersect(&on), Some(on.start));
+        assert_eq!(on.intersect(&a), Some(on.start));
+        assert_eq!(a.intersect(&off), None);
+        assert_eq!(on.intersect(&on), Some(on.start));
+        assert_eq!(on.intersect(&off), None);
+    }
+
+    #[test]
+    fn intersect_small_scale_test() {
+        let a = Segment2::new(Vec2::new(0.0_f32, 0.0), Vec2::new(1e-4, 1e-4));
+        let b = Segment2::new(Vec2::new(0.0_f32, 1e-4), Vec2::new(1e-4, 0.0));
+        let crossing = a.intersect(&b).unwrap();
+        assert!(
+            (crossing - Vec2::new(5e-5, 5e-5)).length() < 1e-9,
+            "{}",
+            crossing
+        );
+
+        // parallel segments 1e-5 apart don't meet
+        let c = Segment2::new(Vec2::new(0.0_f32, 0.0), Vec2::new(1e-3, 0.0));
+        let d = Segment2::new(Vec2::new(0.0_f32, 1e-5), Vec2::new(1e-3, 1e-5));
+        assert_eq!(c.intersect(&d), None);
+        assert_eq!(
+            c.intersect(&Segment2::new(Vec2::new(5e-4, 0.0), Vec2::new(2e-3, 0.0))),
+            Some(Vec2::new(5e-4, 0.0))
+        );
+
+        // a short segment is still a segment, not a point
+        let e = Segment2::new(Vec2::new(0.0_f32, 1e-4), Vec2::new(2e-4, 1e-4));
+        let (on_e, on_a) = e.closest_points_between(&a);
+        assert!((on_e - Vec2::new(1e-4, 1e-4)).length() < 1e-9, "{}", on_e);
+        assert!((on_a - a.end).length() < 1e-9);
+        assert_eq!(
+            a.closest_point_to(Vec2::new(1e-4, 0.0)),
+            Vec2::new(5e-5, 5e-5)
+        );
+    }
+}
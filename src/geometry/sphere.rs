@@ -0,0 +1,278 @@
+use crate::geometry::{Aabb2, Aabb3};
+use crate::vector::{Vec2, Vec3};
+use num::Float;
+
+/// A bounding sphere, made of a center and a radius.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Sphere<T>
+where
+    T: Float,
+{
+    pub center: Vec3<T>,
+    pub radius: T,
+}
+
+impl<T> Sphere<T>
+where
+    T: Float,
+{
+    /// Create a sphere from its center and radius
+    pub fn new(center: Vec3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+
+    /// Create a sphere containing every point, or `None` if there are no points
+    ///
+    /// Uses Ritter's algorithm, which is fast but only approximately minimal
+    /// (typically within a few percent of the optimal radius).
+    pub fn from_points(points: &[Vec3<T>]) -> Option<Self> {
+        let first = *points.first()?;
+        let farthest_from = |from: Vec3<T>| {
+            points.iter().copied().fold(from, |best, p| {
+                if (p - from).length_squared() > (best - from).length_squared() {
+                    p
+                } else {
+                    best
+                }
+            })
+        };
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+
+        let two = T::one() + T::one();
+        let mut bounds = Self::new((a + b) / two, (b - a).length() / two);
+        for p in points {
+            let d = (*p - bounds.center).length();
+            if d > bounds.radius {
+                let radius = (bounds.radius + d) / two;
+                bounds.center =
+                    bounds.center + (*p - bounds.center) * ((radius - bounds.radius) / d);
+                bounds.radius = radius;
+            }
+        }
+        Some(bounds)
+    }
+
+    /// Return whether the point lies inside or on the sphere
+    pub fn contains_point(&self, point: &Vec3<T>) -> bool {
+        (*point - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    /// Return whether two spheres overlap or touch
+    pub fn intersects_sphere(&self, other: &Sphere<T>) -> bool {
+        let r = self.radius + other.radius;
+        (other.center - self.center).length_squared() <= r * r
+    }
+
+    /// Return whether the sphere overlaps or touches an axis-aligned box
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        let closest = self.center.max(aabb.min).min(aabb.max);
+        self.contains_point(&closest)
+    }
+
+    /// Return the smallest sphere containing both spheres
+    pub fn merged(&self, other: &Sphere<T>) -> Sphere<T> {
+        let offset = other.center - self.center;
+        let d = offset.length();
+        if d + other.radius <= self.radius {
+            return *self;
+        }
+        if d + self.radius <= other.radius {
+            return *other;
+        }
+        let radius = (d + self.radius + other.radius) / (T::one() + T::one());
+        Self::new(self.center + offset * ((radius - self.radius) / d), radius)
+    }
+}
+
+/// A bounding circle, the 2D counterpart of [`Sphere`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Circle<T>
+where
+    T: Float,
+{
+    pub center: Vec2<T>,
+    pub radius: T,
+}
+
+impl<T> Circle<T>
+where
+    T: Float,
+{
+    /// Create a circle from its center and radius
+    pub fn new(center: Vec2<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+
+    /// Create a circle containing every point, or `None` if there are no points
+    ///
+    /// Uses Ritter's algorithm, which is fast but only approximately minimal
+    /// (typically within a few percent of the optimal radius).
+    pub fn from_points(points: &[Vec2<T>]) -> Option<Self> {
+        let first = *points.first()?;
+        let farthest_from = |from: Vec2<T>| {
+            points.iter().copied().fold(from, |best, p| {
+                if (p - from).length_squared() > (best - from).length_squared() {
+                    p
+                } else {
+                    best
+                }
+            })
+        };
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+
+        let two = T::one() + T::one();
+        let mut bounds = Self::new((a + b) / two, (b - a).length() / two);
+        for p in points {
+            let d = (*p - bounds.center).length();
+            if d > bounds.radius {
+                let radius = (bounds.radius + d) / two;
+                bounds.center =
+                    bounds.center + (*p - bounds.center) * ((radius - bounds.radius) / d);
+                bounds.radius = radius;
+            }
+        }
+        Some(bounds)
+    }
+
+    /// Return whether the point lies inside or on the circle
+    pub fn contains_point(&self, point: &Vec2<T>) -> bool {
+        (*point - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    /// Return whether two circles overlap or touch
+    pub fn intersects_circle(&self, other: &Circle<T>) -> bool {
+        let r = self.radius + other.radius;
+        (other.center - self.center).length_squared() <= r * r
+    }
+
+    /// Return whether the circle overlaps or touches an axis-aligned box
+    pub fn intersects_aabb(&self, aabb: &Aabb2<T>) -> bool {
+        let closest = self.center.max(aabb.min).min(aabb.max);
+        self.contains_point(&closest)
+    }
+
+    /// Return the smallest circle containing both circles
+    pub fn merged(&self, other: &Circle<T>) -> Circle<T> {
+        let offset = other.center - self.center;
+        let d = offset.length();
+        if d + other.radius <= self.radius {
+            return *self;
+        }
+        if d + self.radius <= other.radius {
+            return *other;
+        }
+        let radius = (d + self.radius + other.radius) / (T::one() + T::one());
+        Self::new(self.center + offset * ((radius - self.radius) / d), radius)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::{Aabb2, Aabb3, Circle, Sphere};
+    use crate::vector::{Vec2, Vec3};
+
+    fn sphere(x: f32, y: f32, z: f32, r: f32) -> Sphere<f32> {
+        Sphere::new(Vec3::new(x, y, z), r)
+    }
+
+    #[test]
+    fn contains_point_test() {
+        let s = sphere(1.0, 0.0, 0.0, 2.0);
+
+        assert!(s.contains_point(&Vec3::new(2.0, 1.0, 0.0)));
+        assert!(s.contains_point(&Vec3::new(3.0, 0.0, 0.0)));
+        assert!(!s.contains_point(&Vec3::new(3.1, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn intersects_sphere_test() {
+        let s = sphere(0.0, 0.0, 0.0, 1.0);
+
+        // concentric
+        assert!(s.intersects_sphere(&sphere(0.0, 0.0, 0.0, 5.0)));
+        // tangent
+        assert!(s.intersects_sphere(&sphere(3.0, 0.0, 0.0, 2.0)));
+        assert!(!s.intersects_sphere(&sphere(3.0, 0.0, 0.0, 1.9)));
+    }
+
+    #[test]
+    fn intersects_aabb_test() {
+        let aabb = Aabb3::new(Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 2.0, 2.0));
+
+        assert!(sphere(0.0, 0.0, 0.0, 2.0).intersects_aabb(&aabb));
+        assert!(!sphere(0.0, 0.0, 0.0, 1.7).intersects_aabb(&aabb));
+        // the box touching the sphere's edge
+        assert!(sphere(0.0, 1.5, 1.5, 1.0).intersects_aabb(&aabb));
+        // the sphere entirely inside the box
+        assert!(sphere(1.5, 1.5, 1.5, 0.1).intersects_aabb(&aabb));
+    }
+
+    #[test]
+    fn from_points_test() {
+        let points: Vec<Vec3<f32>> = (0..50)
+            .map(|i| {
+                let a = i as f32 * 0.7;
+                Vec3::new(a.cos() * 3.0, a.sin() * 2.0, (a * 1.3).sin()) + Vec3::new(5.0, -1.0, 2.0)
+            })
+            .collect();
+        let s = Sphere::from_points(&points).unwrap();
+
+        for p in &points {
+            assert!((*p - s.center).length() <= s.radius + 1e-4);
+        }
+        // every point lies within 3.0 + 1.0 of (5, -1, 2), so the result should be close to that
+        assert!(s.radius >= 3.0 && s.radius < 3.5);
+        assert_eq!(Sphere::<f32>::from_points(&[]), None);
+    }
+
+    #[test]
+    fn from_single_point_test() {
+        let p = Vec3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(Sphere::from_points(&[p]), Some(Sphere::new(p, 0.0)));
+    }
+
+    #[test]
+    fn merged_test() {
+        let a = sphere(0.0, 0.0, 0.0, 1.0);
+        let b = sphere(4.0, 0.0, 0.0, 1.0);
+
+        assert_eq!(a.merged(&b), sphere(2.0, 0.0, 0.0, 3.0));
+        // one inside the other
+        let big = sphere(0.0, 0.0, 0.0, 10.0);
+        assert_eq!(a.merged(&big), big);
+        assert_eq!(big.merged(&a), big);
+        // concentric
+        assert_eq!(
+            a.merged(&sphere(0.0, 0.0, 0.0, 2.0)),
+            sphere(0.0, 0.0, 0.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn circle_test() {
+        let c = Circle::new(Vec2::new(0.0, 0.0), 1.0);
+
+        assert!(c.contains_point(&Vec2::new(0.6, 0.8)));
+        assert!(c.intersects_circle(&Circle::new(Vec2::new(2.0, 0.0), 1.0)));
+        assert!(!c.intersects_circle(&Circle::new(Vec2::new(2.0, 0.0), 0.5)));
+        assert!(c.intersects_aabb(&Aabb2::new(Vec2::new(0.5, 0.5), Vec2::new(2.0, 2.0))));
+        assert!(!c.intersects_aabb(&Aabb2::new(Vec2::new(0.8, 0.8), Vec2::new(2.0, 2.0))));
+        assert_eq!(
+            c.merged(&Circle::new(Vec2::new(0.0, 4.0), 1.0)),
+            Circle::new(Vec2::new(0.0, 2.0), 3.0)
+        );
+
+        let square = [
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+        ];
+        let bounds = Circle::from_points(&square).unwrap();
+        assert!((bounds.radius - 2.0f32.sqrt()).abs() < 1e-6);
+        assert!(bounds.center.length() < 1e-6);
+    }
+}
@@ -0,0 +1,349 @@
+use crate::geometry::{Ray3, Segment3};
+use crate::vector::Vec3;
+use num::Float;
+
+/// A triangle in 3D space, made of three vertices.
+///
+/// Counter-clockwise vertices (as seen from the front) give a normal facing the viewer.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Triangle<T>
+where
+    T: Float,
+{
+    pub a: Vec3<T>,
+    pub b: Vec3<T>,
+    pub c: Vec3<T>,
+}
+
+impl<T> Triangle<T>
+where
+    T: Float,
+{
+    /// Create a triangle from its three vertices
+    pub fn new(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Return the unnormalized normal, `(b - a).cross(&(c - a))`, whose length is twice the area
+    fn scaled_normal(&self) -> Vec3<T> {
+        (self.b - self.a).cross(&(self.c - self.a))
+    }
+
+    /// Return whether the triangle has (close to) zero area
+    ///
+    /// The area is compared against the lengths of the edges at `a`, so tiny
+    /// triangles are only degenerate when they are also flat.
+    pub fn is_degenerate(&self) -> bool {
+        let epsilon = T::epsilon();
+        let edges = (self.b - self.a).length_squared() * (self.c - self.a).length_squared();
+        self.scaled_normal().length_squared() <= epsilon * epsilon * edges
+    }
+
+    /// Return the unit normal of the triangle
+    ///
+    /// Degenerate (zero area) triangles have no normal, so the zero vector is returned.
+    pub fn normal(&self) -> Vec3<T> {
+        if self.is_degenerate() {
+            Vec3::new(T::zero(), T::zero(), T::zero())
+        } else {
+            self.scaled_normal().normalize()
+        }
+    }
+
+    /// Return the area of the triangle
+    pub fn area(&self) -> T {
+        self.scaled_normal().length() / (T::one() + T::one())
+    }
+
+    /// Return the barycentric coordinates `(u, v, w)` of `p`, weighting `a`, `b` and `c`
+    ///
+    /// `p` is projected onto the triangle's plane first, and `u + v + w == 1`.
+    /// Points outside the triangle have at least one negative weight. Returns
+    /// `None` for degenerate triangles, where the coordinates are undefined.
+    pub fn barycentric(&self, p: Vec3<T>) -> Option<Vec3<T>> {
+        if self.is_degenerate() {
+            return None;
+        }
+        let n = self.scaled_normal();
+        let denom = n.length_squared();
+        // each weight is the signed area of the sub-triangle opposite its vertex
+        let v = (p - self.a).cross(&(self.c - self.a)).dot(&n) / denom;
+        let w = (self.b - self.a).cross(&(p - self.a)).dot(&n) / denom;
+        Some(Vec3::new(T::one() - v - w, v, w))
+    }
+
+    /// Return whether `p` lies on the triangle, including its edges and vertices
+    ///
+    /// A small tolerance (relative to the triangle's size) is allowed both off the
+    /// plane and outside the edges. Degenerate triangles contain no points.
+    pub fn contains_point(&self, p: Vec3<T>) -> bool {
+        let tolerance = T::epsilon().sqrt();
+        let bary = match self.barycentric(p) {
+            Some(bary) => bary,
+            None => return false,
+        };
+        let scale = (self.b - self.a)
+            .length()
+            .max((self.c - self.a).length())
+            .max(T::one());
+        let off_plane = (p - self.a).dot(&self.normal()).abs();
+
+        off_plane <= tolerance * scale
+            && bary.x >= -tolerance
+            && bary.y >= -tolerance
+            && bary.z >= -tolerance
+    }
+
+    /// Return the closest point on the triangle (including its interior) to `p`
+    ///
+    /// Degenerate triangles are treated as the segments between their vertices.
+    pub fn closest_point(&self, p: Vec3<T>) -> Vec3<T> {
+        let (a, b, c) = (self.a, self.b, self.c);
+        if !self.is_degenerate() {
+            if let Some(bary) = self.barycentric(p) {
+                if bary.x >= T::zero() && bary.y >= T::zero() && bary.z >= T::zero() {
+                    return a * bary.x + b * bary.y + c * bary.z;
+                }
+            }
+        }
+        // outside the triangle (or no interior), so the answer is on an edge
+        [
+            Segment3::new(a, b).closest_point_to(p),
+            Segment3::new(b, c).closest_point_to(p),
+            Segment3::new(c, a).closest_point_to(p),
+        ]
+        .into_iter()
+        .fold(None, |best: Option<Vec3<T>>, q| match best {
+            Some(b) if (b - p).length_squared() <= (q - p).length_squared() => Some(b),
+            _ => Some(q),
+        })
+        .unwrap()
+    }
+
+    /// Return the `(t, u, v)` hit of a ray with the triangle (Möller–Trumbore)
+    ///
+    /// `t` is the distance along the ray, and `u`, `v` are the barycentric weights
+    /// of `b` and `c`. Hits behind the ray origin are ignored. When `cull_backfaces`
+    /// is set, rays hitting the back of the triangle (travelling along its normal)
+    /// miss. Rays parallel to the triangle and degenerate triangles never hit.
+    pub fn intersect_ray(&self, ray: &Ray3<T>, cull_backfaces: bool) -> Option<(T, T, T)> {
+        let e1 = self.b - self.a;
+        let e2 = self.c - self.a;
+        let pvec = ray.direction.cross(&e2);
+        let det = e1.dot(&pvec);
+
+        // relative to the sizes involved, as `det` scales with both edges and the direction
+        let tolerance = T::epsilon() * e1.length() * e2.length() * ray.direction.length();
+        if (cull_backfaces && det <= tolerance) || det.abs() <= tolerance {
+            return None;
+        }
+        let inv_det = T::one() / det;
+
+        let tvec = ray.origin - self.a;
+        let u = tvec.dot(&pvec) * inv_det;
+        if u < T::zero() || u > T::one() {
+            return None;
+        }
+        let qvec = tvec.cross(&e1);
+        let v = ray.direction.dot(&qvec) * inv_det;
+        if v < T::zero() || u + v > T::one() {
+            return None;
+        }
+        let t = e2.dot(&qvec) * inv_det;
+        if t < T::zero() {
+            None
+        } else {
+            Some((t, u, v))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::{Ray3, Triangle};
+    use crate::vector::Vec3;
+
+    fn triangle() -> Triangle<f64> {
+        Triangle::new(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(0.0, 4.0, 0.0),
+        )
+    }
+
+    fn degenerate() -> Triangle<f64> {
+        Triangle::new(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(2.0, 2.0, 2.0),
+        )
+    }
+
+    fn down_at(x: f64, y: f64) -> Ray3<f64> {
+        Ray3::new(Vec3::new(x, y, 5.0), Vec3::new(0.0, 0.0, -1.0))
+    }
+
+    #[test]
+    fn normal_and_area_test() {
+        assert_eq!(triangle().normal(), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(triangle().area(), 8.0);
+        assert_eq!(degenerate().normal(), Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(degenerate().area(), 0.0);
+    }
+
+    #[test]
+    fn small_triangle_test() {
+        let t = Triangle::new(
+            Vec3::new(0.0_f32, 0.0, 0.0),
+            Vec3::new(1e-4, 0.0, 0.0),
+            Vec3::new(0.0, 1e-4, 0.0),
+        );
+        let flat = Triangle::new(t.a, t.b, t.b * 2.0);
+
+        assert!(!t.is_degenerate());
+        assert_eq!(t.normal(), Vec3::new(0.0, 0.0, 1.0));
+        assert!(flat.is_degenerate());
+        assert!(Triangle::new(t.a, t.a, t.b).is_degenerate());
+    }
+
+    #[test]
+    fn small_triangle_intersect_ray_test() {
+        let t = Triangle::new(
+            Vec3::new(0.0_f32, 0.0, 0.0),
+            Vec3::new(1e-4, 0.0, 0.0),
+            Vec3::new(0.0, 1e-4, 0.0),
+        );
+        let centroid = (t.a + t.b + t.c) / 3.0;
+        let ray = Ray3::new(
+            centroid + Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+        );
+
+        assert!(t.contains_point(centroid));
+        let (distance, u, v) = t.intersect_ray(&ray, true).unwrap();
+        assert!((distance - 1.0).abs() < 1e-6);
+        assert!((u - 1.0 / 3.0).abs() < 1e-3 && (v - 1.0 / 3.0).abs() < 1e-3);
+        // a short direction vector still hits
+        let short = Ray3::new(ray.origin, ray.direction * 1e-4);
+        assert!(t.intersect_ray(&short, false).is_some());
+        // the back face is still culled
+        let below = Ray3::new(centroid + ray.direction, ray.direction * -1.0);
+        assert_eq!(t.intersect_ray(&below, true), None);
+        assert!(t.intersect_ray(&below, false).is_some());
+    }
+
+    #[test]
+    fn barycentric_test() {
+        let t = triangle();
+
+        assert_eq!(
+            t.barycentric(Vec3::new(1.0, 1.0, 0.0)),
+            Some(Vec3::new(0.5, 0.25, 0.25))
+        );
+        assert_eq!(t.barycentric(t.a), Some(Vec3::new(1.0, 0.0, 0.0)));
+        assert_eq!(t.barycentric(t.b), Some(Vec3::new(0.0, 1.0, 0.0)));
+        assert_eq!(t.barycentric(t.c), Some(Vec3::new(0.0, 0.0, 1.0)));
+        // off the plane projects onto it
+        assert_eq!(
+            t.barycentric(Vec3::new(1.0, 1.0, 3.0)),
+            Some(Vec3::new(0.5, 0.25, 0.25))
+        );
+        assert_eq!(degenerate().barycentric(Vec3::new(1.0, 1.0, 1.0)), None);
+    }
+
+    #[test]
+    fn contains_point_test() {
+        let t = triangle();
+
+        // interior
+        assert!(t.contains_point(Vec3::new(1.0, 1.0, 0.0)));
+        // each edge
+        assert!(t.contains_point(Vec3::new(2.0, 0.0, 0.0)));
+        assert!(t.contains_point(Vec3::new(2.0, 2.0, 0.0)));
+        assert!(t.contains_point(Vec3::new(0.0, 2.0, 0.0)));
+        // each vertex
+        assert!(t.contains_point(t.a) && t.contains_point(t.b) && t.contains_point(t.c));
+        // outside, and above the plane
+        assert!(!t.contains_point(Vec3::new(3.0, 3.0, 0.0)));
+        assert!(!t.contains_point(Vec3::new(1.0, 1.0, 0.1)));
+        assert!(!degenerate().contains_point(Vec3::new(1.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn closest_point_test() {
+        let t = triangle();
+
+        assert_eq!(
+            t.closest_point(Vec3::new(1.0, 1.0, 7.0)),
+            Vec3::new(1.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            t.closest_point(Vec3::new(2.0, -3.0, 1.0)),
+            Vec3::new(2.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            t.closest_point(Vec3::new(4.0, 4.0, 0.0)),
+            Vec3::new(2.0, 2.0, 0.0)
+        );
+        assert_eq!(t.closest_point(Vec3::new(-1.0, -1.0, 0.0)), t.a);
+        assert_eq!(t.closest_point(Vec3::new(9.0, -1.0, 0.0)), t.b);
+        assert_eq!(
+            degenerate().closest_point(Vec3::new(5.0, 5.0, 5.0)),
+            Vec3::new(2.0, 2.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn intersect_ray_test() {
+        let t = triangle();
+
+        assert_eq!(
+            t.intersect_ray(&down_at(1.0, 1.0), false),
+            Some((5.0, 0.25, 0.25))
+        );
+        // edges and vertices are hit
+        assert_eq!(
+            t.intersect_ray(&down_at(2.0, 0.0), false),
+            Some((5.0, 0.5, 0.0))
+        );
+        assert_eq!(
+            t.intersect_ray(&down_at(2.0, 2.0), false),
+            Some((5.0, 0.5, 0.5))
+        );
+        assert_eq!(
+            t.intersect_ray(&down_at(0.0, 4.0), false),
+            Some((5.0, 0.0, 1.0))
+        );
+        assert_eq!(
+            t.intersect_ray(&down_at(0.0, 0.0), false),
+            Some((5.0, 0.0, 0.0))
+        );
+        // misses
+        assert_eq!(t.intersect_ray(&down_at(3.0, 3.0), false), None);
+        assert_eq!(t.intersect_ray(&down_at(-0.1, 1.0), false), None);
+    }
+
+    #[test]
+    fn intersect_ray_culling_test() {
+        let t = triangle();
+        let from_below = Ray3::new(Vec3::new(1.0, 1.0, -5.0), Vec3::new(0.0, 0.0, 1.0));
+
+        assert_eq!(t.intersect_ray(&from_below, false), Some((5.0, 0.25, 0.25)));
+        assert_eq!(t.intersect_ray(&from_below, true), None);
+        assert_eq!(
+            t.intersect_ray(&down_at(1.0, 1.0), true),
+            Some((5.0, 0.25, 0.25))
+        );
+    }
+
+    #[test]
+    fn intersect_ray_parallel_and_behind_test() {
+        let t = triangle();
+        let parallel = Ray3::new(Vec3::new(-1.0, 1.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        let behind = Ray3::new(Vec3::new(1.0, 1.0, 5.0), Vec3::new(0.0, 0.0, 1.0));
+
+        assert_eq!(t.intersect_ray(&parallel, false), None);
+        assert_eq!(t.intersect_ray(&behind, false), None);
+        assert_eq!(degenerate().intersect_ray(&down_at(1.0, 1.0), false), None);
+    }
+}
@@ -1 +1,11 @@
+pub mod geometry;
+pub mod matrix;
+pub mod prelude;
+pub mod quaternion;
+pub mod scalar;
+mod simd;
+pub mod spatial;
+#[cfg(test)]
+mod test_rng;
+pub mod transform;
 pub mod vector;
@@ -0,0 +1,136 @@
+use crate::vector::Vec2;
+use num::Num;
+use std::ops::Mul;
+
+/// A generic 2x2 Matrix implementation.
+/// Stored as 2 column vectors (column-major, like GLM).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Mat2<T>
+where
+    T: Num + Copy,
+{
+    pub cols: [Vec2<T>; 2],
+}
+
+impl<T> Mat2<T>
+where
+    T: Num + Copy,
+{
+    /// Create a 2x2 Matrix from its 2 columns
+    pub fn new(x: Vec2<T>, y: Vec2<T>) -> Self {
+        Self { cols: [x, y] }
+    }
+
+    /// Create a 2x2 Matrix from its 2 rows
+    pub fn from_rows(x: Vec2<T>, y: Vec2<T>) -> Self {
+        Self::new(x, y).transpose()
+    }
+
+    /// Create the 2x2 identity Matrix
+    pub fn identity() -> Self {
+        let (o, z) = (T::one(), T::zero());
+        Self::new(Vec2::new(o, z), Vec2::new(z, o))
+    }
+
+    /// Return the given row of the Matrix
+    pub fn row(&self, i: usize) -> Vec2<T> {
+        let [x, y] = self.cols;
+        match i {
+            0 => Vec2::new(x.x, y.x),
+            1 => Vec2::new(x.y, y.y),
+            _ => panic!("Mat2 row index out of range: {}", i),
+        }
+    }
+
+    /// Return the transpose of the Matrix (rows swapped with columns)
+    pub fn transpose(&self) -> Self {
+        Self {
+            cols: [self.row(0), self.row(1)],
+        }
+    }
+
+    /// Return the determinant of the Matrix
+    ///
+    /// `x.x * y.y - y.x * x.y`
+    pub fn determinant(&self) -> T {
+        self.cols[0].cross(&self.cols[1])
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::matrix::Mat2;
+/// # use lamar::vector::Vec2;
+/// let a = Mat2::new(Vec2::new(0, 1), Vec2::new(-1, 0));
+/// let b = a * a;
+///
+/// assert_eq!(b, Mat2::new(Vec2::new(-1, 0), Vec2::new(0, -1)));
+/// ```
+impl<T> Mul for Mat2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Mat2<T>;
+
+    fn mul(self, other: Mat2<T>) -> Self::Output {
+        Self {
+            cols: [self * other.cols[0], self * other.cols[1]],
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::matrix::Mat2;
+/// # use lamar::vector::Vec2;
+/// let a = Mat2::new(Vec2::new(0, 1), Vec2::new(-1, 0));
+/// let b = Vec2::new(1, 0);
+///
+/// assert_eq!(a * b, Vec2::new(0, 1));
+/// ```
+impl<T> Mul<Vec2<T>> for Mat2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn mul(self, other: Vec2<T>) -> Self::Output {
+        self.cols[0] * other.x + self.cols[1] * other.y
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat2;
+    use crate::vector::Vec2;
+
+    #[test]
+    fn identity_test() {
+        let m = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+
+        assert_eq!(Mat2::identity() * m, m);
+        assert_eq!(m * Mat2::identity(), m);
+    }
+
+    #[test]
+    fn transpose_test() {
+        let m = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+
+        assert_eq!(m.transpose(), Mat2::new(Vec2::new(1, 3), Vec2::new(2, 4)));
+        assert_eq!(Mat2::from_rows(Vec2::new(1, 3), Vec2::new(2, 4)), m);
+    }
+
+    #[test]
+    fn determinant_test() {
+        let m = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+
+        assert_eq!(m.determinant(), -2);
+    }
+
+    #[test]
+    fn mul_vec2_test() {
+        let m = Mat2::new(Vec2::new(1, 2), Vec2::new(3, 4));
+
+        assert_eq!(m * Vec2::new(1, 1), Vec2::new(4, 6));
+    }
+}
@@ -0,0 +1,292 @@
+use crate::quaternion::Quat;
+use crate::vector::Vec3;
+use num::{Float, Num};
+use std::ops::Mul;
+
+/// A generic 3x3 Matrix implementation.
+/// Stored as 3 column vectors (column-major, like GLM).
+///
+/// Used for 3D linear transforms and 2D homogeneous (affine) transforms.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Mat3<T>
+where
+    T: Num + Copy,
+{
+    pub cols: [Vec3<T>; 3],
+}
+
+impl<T> Mat3<T>
+where
+    T: Num + Copy,
+{
+    /// Create a 3x3 Matrix from its 3 columns
+    pub fn new(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Self {
+        Self { cols: [x, y, z] }
+    }
+
+    /// Create a 3x3 Matrix from its 3 rows
+    pub fn from_rows(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Self {
+        Self::new(x, y, z).transpose()
+    }
+
+    /// Create the 3x3 identity Matrix
+    pub fn identity() -> Self {
+        let (o, z) = (T::one(), T::zero());
+        Self::new(Vec3::new(o, z, z), Vec3::new(z, o, z), Vec3::new(z, z, o))
+    }
+
+    /// Return the given row of the Matrix
+    pub fn row(&self, i: usize) -> Vec3<T> {
+        let [x, y, z] = self.cols;
+        match i {
+            0 => Vec3::new(x.x, y.x, z.x),
+            1 => Vec3::new(x.y, y.y, z.y),
+            2 => Vec3::new(x.z, y.z, z.z),
+            _ => panic!("Mat3 row index out of range: {}", i),
+        }
+    }
+
+    /// Return the transpose of the Matrix (rows swapped with columns)
+    pub fn transpose(&self) -> Self {
+        Self {
+            cols: [self.row(0), self.row(1), self.row(2)],
+        }
+    }
+
+    /// Return the determinant of the Matrix
+    ///
+    /// `x.dot(y.cross(z))`
+    pub fn determinant(&self) -> T {
+        self.cols[0].dot(&self.cols[1].cross(&self.cols[2]))
+    }
+}
+
+impl<T> Mat3<T>
+where
+    T: Float,
+{
+    /// Return the inverse transpose of the Matrix, or `None` if it is singular
+    ///
+    /// This is the Matrix that transforms normals: if `m` maps a surface's tangents,
+    /// `m.inverse_transpose()` maps its normals so they stay perpendicular, even under
+    /// non-uniform scale. Its columns are `(y.cross(z), z.cross(x), x.cross(y)) / det`.
+    pub fn inverse_transpose(&self) -> Option<Self> {
+        let [x, y, z] = self.cols;
+        let det = self.determinant();
+        if det == T::zero() || !det.is_finite() {
+            return None;
+        }
+        Some(Self::new(
+            y.cross(&z) / det,
+            z.cross(&x) / det,
+            x.cross(&y) / det,
+        ))
+    }
+
+    /// Return the quaternion equivalent to the rotation Matrix
+    ///
+    /// The Matrix must be a pure rotation (orthonormal, with a determinant of 1).
+    /// Uses Shepperd's method, building the quaternion from its largest component
+    /// so that it stays accurate for any angle.
+    pub fn to_quat(&self) -> Quat<T> {
+        let (o, two) = (T::one(), T::one() + T::one());
+        let [x, y, z] = self.cols;
+        let trace = x.x + y.y + z.z;
+
+        let q = if trace > T::zero() {
+            let s = (trace + o).sqrt() * two;
+            Quat::new(
+                (y.z - z.y) / s,
+                (z.x - x.z) / s,
+                (x.y - y.x) / s,
+                s / (two * two),
+            )
+        } else if x.x > y.y && x.x > z.z {
+            let s = (o + x.x - y.y - z.z).sqrt() * two;
+            Quat::new(
+                s / (two * two),
+                (y.x + x.y) / s,
+                (z.x + x.z) / s,
+                (y.z - z.y) / s,
+            )
+        } else if y.y > z.z {
+            let s = (o + y.y - x.x - z.z).sqrt() * two;
+            Quat::new(
+                (y.x + x.y) / s,
+                s / (two * two),
+                (z.y + y.z) / s,
+                (z.x - x.z) / s,
+            )
+        } else {
+            let s = (o + z.z - x.x - y.y).sqrt() * two;
+            Quat::new(
+                (z.x + x.z) / s,
+                (z.y + y.z) / s,
+                s / (two * two),
+                (x.y - y.x) / s,
+            )
+        };
+        q.normalize()
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::matrix::Mat3;
+/// # use lamar::vector::Vec3;
+/// let a = Mat3::identity();
+/// let b = Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+///
+/// assert_eq!(a * b, b);
+/// ```
+impl<T> Mul for Mat3<T>
+where
+    T: Num + Copy,
+{
+    type Output = Mat3<T>;
+
+    fn mul(self, other: Mat3<T>) -> Self::Output {
+        Self {
+            cols: [
+                self * other.cols[0],
+                self * other.cols[1],
+                self * other.cols[2],
+            ],
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::matrix::Mat3;
+/// # use lamar::vector::Vec3;
+/// let a = Mat3::new(Vec3::new(2, 0, 0), Vec3::new(0, 3, 0), Vec3::new(0, 0, 4));
+/// let b = Vec3::new(1, 1, 1);
+///
+/// assert_eq!(a * b, Vec3::new(2, 3, 4));
+/// ```
+impl<T> Mul<Vec3<T>> for Mat3<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn mul(self, other: Vec3<T>) -> Self::Output {
+        self.cols[0] * other.x + self.cols[1] * other.y + self.cols[2] * other.z
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat3;
+    use crate::quaternion::Quat;
+    use crate::vector::Vec3;
+
+    // 90 degree rotations about Z and X, exact in integers
+    fn rot_z() -> Mat3<i32> {
+        Mat3::from_rows(Vec3::new(0, -1, 0), Vec3::new(1, 0, 0), Vec3::new(0, 0, 1))
+    }
+
+    fn rot_x() -> Mat3<i32> {
+        Mat3::from_rows(Vec3::new(1, 0, 0), Vec3::new(0, 0, -1), Vec3::new(0, 1, 0))
+    }
+
+    #[test]
+    fn identity_test() {
+        let m = rot_z();
+
+        assert_eq!(Mat3::identity() * m, m);
+        assert_eq!(m * Mat3::identity(), m);
+    }
+
+    #[test]
+    fn transpose_test() {
+        let m = Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+
+        assert_eq!(m.transpose().cols[0], Vec3::new(1, 4, 7));
+        assert_eq!(m.transpose().transpose(), m);
+        // the inverse of a rotation is its transpose
+        assert_eq!(rot_z() * rot_z().transpose(), Mat3::identity());
+    }
+
+    #[test]
+    fn determinant_test() {
+        let m = Mat3::new(Vec3::new(2, 0, 0), Vec3::new(1, 3, 0), Vec3::new(4, 5, 6));
+
+        assert_eq!(m.determinant(), 36);
+        assert_eq!(rot_z().determinant(), 1);
+        assert_eq!((rot_x() * rot_z()).determinant(), 1);
+    }
+
+    #[test]
+    fn compose_rotations_test() {
+        let v = Vec3::new(1, 0, 0);
+
+        // rotate about Z first (x -> y), then about X (y -> z)
+        assert_eq!((rot_x() * rot_z()) * v, Vec3::new(0, 0, 1));
+        assert_eq!(rot_x() * (rot_z() * v), Vec3::new(0, 0, 1));
+        // applied the other way around x is untouched by X, then goes to y
+        assert_eq!((rot_z() * rot_x()) * v, Vec3::new(0, 1, 0));
+    }
+
+    #[test]
+    fn to_quat_test() {
+        let axes = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(1.0, -2.0, 2.0) / 3.0,
+        ];
+        // includes half turns, where the trace is negative
+        for axis in axes {
+            for angle in [0.0, 0.3, 1.9, 3.0, std::f64::consts::PI, -2.5] {
+                let q = Quat::from_axis_angle(axis, angle);
+                let back = q.to_mat3().to_quat();
+
+                assert!(
+                    (back.dot(&q).abs() - 1.0).abs() < 1e-12,
+                    "{:?} != {:?}",
+                    back,
+                    q
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mul_vec3_test() {
+        let m = Mat3::new(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+
+        assert_eq!(m * Vec3::new(1, 0, 2), Vec3::new(15, 18, 21));
+    }
+
+    #[test]
+    fn inverse_transpose_test() {
+        let m = Mat3::new(
+            Vec3::new(2.0, 0.0, 1.0),
+            Vec3::new(1.0, 3.0, 0.0),
+            Vec3::new(0.0, -1.0, 1.0),
+        );
+        let it = m.inverse_transpose().unwrap();
+        let product = m.transpose() * it;
+
+        for (c, unit) in product.cols.iter().zip(Mat3::identity().cols) {
+            assert!((*c - unit).length() < 1e-12);
+        }
+        // a rotation is its own inverse transpose
+        let rotation = rot_z().cols.map(|c| c.as_f64());
+        assert_eq!(
+            Mat3 { cols: rotation }.inverse_transpose(),
+            Some(Mat3 { cols: rotation })
+        );
+        assert_eq!(
+            Mat3::new(
+                Vec3::new(1.0, 2.0, 3.0),
+                Vec3::new(2.0, 4.0, 6.0),
+                Vec3::new(0.0, 0.0, 1.0)
+            )
+            .inverse_transpose(),
+            None
+        );
+    }
+}
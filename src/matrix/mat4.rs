@@ -0,0 +1,488 @@
+use crate::matrix::Mat3;
+use crate::quaternion::Quat;
+use crate::vector::{Vec3, Vec4};
+use num::{Float, Num};
+use std::ops::Mul;
+
+/// A generic 4x4 Matrix implementation.
+/// Stored as 4 column vectors (column-major, like GLM).
+///
+/// Used for 3D affine and projective transforms.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Mat4<T>
+where
+    T: Num + Copy,
+{
+    pub cols: [Vec4<T>; 4],
+}
+
+impl<T> Mat4<T>
+where
+    T: Num + Copy,
+{
+    /// Create a 4x4 Matrix from its 4 columns
+    pub fn new(x: Vec4<T>, y: Vec4<T>, z: Vec4<T>, w: Vec4<T>) -> Self {
+        Self { cols: [x, y, z, w] }
+    }
+
+    /// Create a 4x4 Matrix from its 4 rows
+    pub fn from_rows(x: Vec4<T>, y: Vec4<T>, z: Vec4<T>, w: Vec4<T>) -> Self {
+        Self::new(x, y, z, w).transpose()
+    }
+
+    /// Create the 4x4 identity Matrix
+    pub fn identity() -> Self {
+        Self::scale(Vec3::new(T::one(), T::one(), T::one()))
+    }
+
+    /// Create a Matrix translating points by `offset`
+    pub fn translation(offset: Vec3<T>) -> Self {
+        let mut m = Self::identity();
+        m.cols[3] = Vec4::new(offset.x, offset.y, offset.z, T::one());
+        m
+    }
+
+    /// Create a Matrix scaling each axis by the matching component of `factors`
+    pub fn scale(factors: Vec3<T>) -> Self {
+        let (o, z) = (T::one(), T::zero());
+        Self::new(
+            Vec4::new(factors.x, z, z, z),
+            Vec4::new(z, factors.y, z, z),
+            Vec4::new(z, z, factors.z, z),
+            Vec4::new(z, z, z, o),
+        )
+    }
+
+    /// Return the given row of the Matrix
+    pub fn row(&self, i: usize) -> Vec4<T> {
+        let [x, y, z, w] = self.cols;
+        match i {
+            0 => Vec4::new(x.x, y.x, z.x, w.x),
+            1 => Vec4::new(x.y, y.y, z.y, w.y),
+            2 => Vec4::new(x.z, y.z, z.z, w.z),
+            3 => Vec4::new(x.w, y.w, z.w, w.w),
+            _ => panic!("Mat4 row index out of range: {}", i),
+        }
+    }
+
+    /// Return the transpose of the Matrix (rows swapped with columns)
+    pub fn transpose(&self) -> Self {
+        Self {
+            cols: [self.row(0), self.row(1), self.row(2), self.row(3)],
+        }
+    }
+}
+
+impl<T> Mat4<T>
+where
+    T: Float,
+{
+    /// Create a right-handed perspective projection Matrix
+    ///
+    /// `fovy` is the vertical field of view in radians. Like GLM (and OpenGL),
+    /// the view looks down -Z and depth is mapped to the `[-1, 1]` clip range.
+    pub fn perspective(fovy: T, aspect: T, near: T, far: T) -> Self {
+        let (o, z) = (T::one(), T::zero());
+        let two = o + o;
+        let f = o / (fovy / two).tan();
+        Self::new(
+            Vec4::new(f / aspect, z, z, z),
+            Vec4::new(z, f, z, z),
+            Vec4::new(z, z, (far + near) / (near - far), -o),
+            Vec4::new(z, z, two * far * near / (near - far), z),
+        )
+    }
+
+    /// Create a right-handed view Matrix for a camera at `eye` looking at `target`
+    ///
+    /// The camera looks down its local -Z axis, with `up` hinting at its local +Y.
+    pub fn look_at(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Self {
+        let (o, z) = (T::one(), T::zero());
+        let f = (target - eye).normalize();
+        let s = f.cross(&up).normalize();
+        let u = s.cross(&f);
+        Self::new(
+            Vec4::new(s.x, u.x, -f.x, z),
+            Vec4::new(s.y, u.y, -f.y, z),
+            Vec4::new(s.z, u.z, -f.z, z),
+            Vec4::new(-s.dot(&eye), -u.dot(&eye), f.dot(&eye), o),
+        )
+    }
+
+    /// Return the Matrix for transforming normals, or `None` if it is singular
+    ///
+    /// This is the inverse transpose of the upper 3x3 block (the linear part), so
+    /// normals stay perpendicular to surfaces under non-uniform scale. The results
+    /// generally need normalizing, see `Vec3::transform_normal`.
+    pub fn normal_matrix(&self) -> Option<Mat3<T>> {
+        let [x, y, z, _] = self.cols;
+        Mat3::new(x.xyz(), y.xyz(), z.xyz()).inverse_transpose()
+    }
+
+    /// Return the inverse of the Matrix, or `None` if it is singular
+    ///
+    /// Uses the cofactors, built from the 2x2 determinants of the top and bottom
+    /// two rows. `None` is returned when the determinant is zero (or the result
+    /// isn't finite), so nearly singular matrices can still give large entries.
+    pub fn inverse(&self) -> Option<Self> {
+        let m: [[T; 4]; 4] = [0, 1, 2, 3].map(|i| self.row(i).into());
+        let det2 = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r1][c0] * m[r0][c1]
+        };
+        let s = [
+            det2(0, 1, 0, 1),
+            det2(0, 1, 0, 2),
+            det2(0, 1, 0, 3),
+            det2(0, 1, 1, 2),
+            det2(0, 1, 1, 3),
+            det2(0, 1, 2, 3),
+        ];
+        let c = [
+            det2(2, 3, 0, 1),
+            det2(2, 3, 0, 2),
+            det2(2, 3, 0, 3),
+            det2(2, 3, 1, 2),
+            det2(2, 3, 1, 3),
+            det2(2, 3, 2, 3),
+        ];
+
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if det == T::zero() || !det.is_finite() {
+            return None;
+        }
+        let inv = T::one() / det;
+
+        let rows = [
+            Vec4::new(
+                m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+                -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+                m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+                -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+            ),
+            Vec4::new(
+                -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+                m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+                -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+                m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+            ),
+            Vec4::new(
+                m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+                -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+                m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+                -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+            ),
+            Vec4::new(
+                -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+                m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+                -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+                m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+            ),
+        ];
+        let [x, y, z, w] = rows.map(|r| r * inv);
+        let result = Self::from_rows(x, y, z, w);
+        if result.cols.iter().all(|c| c.iter().all(|e| e.is_finite())) {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Split an affine Matrix into its translation, rotation and scale
+    ///
+    /// The inverse of `Transform::to_mat4`. Returns `None` when the Matrix can't be
+    /// written as `translation * rotation * scale`: when it is projective (the
+    /// bottom row isn't `0, 0, 0, 1`), singular (an axis is scaled to zero), or
+    /// sheared (its axes aren't perpendicular). A mirroring Matrix (negative
+    /// determinant) is returned with the X scale negated.
+    pub fn decompose(&self) -> Option<(Vec3<T>, Quat<T>, Vec3<T>)> {
+        let (z, o) = (T::zero(), T::one());
+        let tolerance = T::epsilon().sqrt();
+        if (self.row(3) - Vec4::new(z, z, z, o)).length() > tolerance {
+            return None;
+        }
+
+        let (x, y, w) = (self.cols[0].xyz(), self.cols[1].xyz(), self.cols[2].xyz());
+        let mut scale = Vec3::new(x.length(), y.length(), w.length());
+        if scale.min_element() <= scale.max_element() * T::epsilon() {
+            return None;
+        }
+        if Mat3::new(x, y, w).determinant() < z {
+            scale.x = -scale.x;
+        }
+
+        let (x, y, w) = (x / scale.x, y / scale.y, w / scale.z);
+        if x.dot(&y).abs() > tolerance || x.dot(&w).abs() > tolerance || y.dot(&w).abs() > tolerance
+        {
+            return None;
+        }
+
+        Some((self.cols[3].xyz(), Mat3::new(x, y, w).to_quat(), scale))
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::matrix::Mat4;
+/// # use lamar::vector::Vec3;
+/// let a = Mat4::translation(Vec3::new(1, 2, 3));
+/// let b = Mat4::translation(Vec3::new(4, 5, 6));
+///
+/// assert_eq!(a * b, Mat4::translation(Vec3::new(5, 7, 9)));
+/// ```
+impl<T> Mul for Mat4<T>
+where
+    T: Num + Copy,
+{
+    type Output = Mat4<T>;
+
+    fn mul(self, other: Mat4<T>) -> Self::Output {
+        Self {
+            cols: [
+                self * other.cols[0],
+                self * other.cols[1],
+                self * other.cols[2],
+                self * other.cols[3],
+            ],
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::matrix::Mat4;
+/// # use lamar::vector::{Vec3, Vec4};
+/// let a = Mat4::scale(Vec3::new(2, 3, 4));
+/// let b = Vec4::new(1, 1, 1, 1);
+///
+/// assert_eq!(a * b, Vec4::new(2, 3, 4, 1));
+/// ```
+impl<T> Mul<Vec4<T>> for Mat4<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec4<T>;
+
+    fn mul(self, other: Vec4<T>) -> Self::Output {
+        self.cols[0] * other.x
+            + self.cols[1] * other.y
+            + self.cols[2] * other.z
+            + self.cols[3] * other.w
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat4;
+    use crate::quaternion::Quat;
+    use crate::test_rng::TestRng;
+    use crate::transform::Transform;
+    use crate::vector::{Vec3, Vec4};
+
+    fn assert_mat_close(a: &Mat4<f32>, b: &Mat4<f32>) {
+        for c in 0..4 {
+            assert!(
+                (a.cols[c] - b.cols[c]).length() < 1e-4,
+                "{:?} != {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn decompose_round_trip_test() {
+        let mut rng = TestRng::new(0x9e37_79b9);
+        for _ in 0..200 {
+            let mut r = || rng.range(-1.0, 1.0) as f32;
+            let translation = Vec3::new(r() * 10.0, r() * 10.0, r() * 10.0);
+            let axis = Vec3::new(r(), r(), r()).normalize();
+            let rotation = Quat::from_axis_angle(axis, r() * 3.1);
+            let scale = Vec3::new(
+                r().abs() * 4.0 + 0.1,
+                r().abs() * 4.0 + 0.1,
+                r().abs() * 4.0 + 0.1,
+            );
+            let m = Transform::new(translation, rotation, scale).to_mat4();
+
+            let (t, q, s) = m.decompose().unwrap();
+
+            assert!((t - translation).length() < 1e-4);
+            assert!((q.dot(&rotation).abs() - 1.0).abs() < 1e-4);
+            assert!((s - scale).length() < 1e-4);
+            assert_mat_close(&Transform::new(t, q, s).to_mat4(), &m);
+        }
+    }
+
+    #[test]
+    fn decompose_mirror_test() {
+        let rotation = Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 0.7);
+        let m = Transform::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            rotation,
+            Vec3::new(2.0, -3.0, 1.0),
+        )
+        .to_mat4();
+
+        let (t, q, s) = m.decompose().unwrap();
+
+        // the mirror moves onto the X axis, with the rotation making up for it
+        assert!(s.x < 0.0 && s.y > 0.0 && s.z > 0.0);
+        assert_mat_close(&Transform::new(t, q, s).to_mat4(), &m);
+    }
+
+    #[test]
+    fn decompose_failure_test() {
+        let mut shear = Mat4::<f32>::identity();
+        shear.cols[1] = Vec4::new(0.5, 1.0, 0.0, 0.0);
+        let singular = Mat4::scale(Vec3::new(1.0, 0.0, 2.0));
+        let projective = Mat4::perspective(1.0, 1.5, 0.1, 100.0);
+
+        assert_eq!(shear.decompose(), None);
+        assert_eq!(singular.decompose(), None);
+        assert_eq!(projective.decompose(), None);
+        assert!(Mat4::<f32>::identity().decompose().is_some());
+    }
+
+    #[test]
+    fn inverse_test() {
+        let mut rng = TestRng::new(0x2545_f491);
+        for _ in 0..100 {
+            let mut r = || rng.range(-1.0, 1.0) as f32;
+            let m = Mat4::new(
+                Vec4::new(r(), r(), r(), r()),
+                Vec4::new(r(), r(), r(), r()),
+                Vec4::new(r(), r(), r(), r()),
+                Vec4::new(r(), r(), r(), r()),
+            );
+            let inv = m.inverse().unwrap();
+
+            assert_mat_close(&(m * inv), &Mat4::identity());
+            assert_mat_close(&(inv * m), &Mat4::identity());
+        }
+
+        let proj = Mat4::perspective(1.0, 1.5, 0.1, 50.0);
+        let view = Mat4::look_at(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        let view_proj = proj * view;
+        assert_mat_close(
+            &(view_proj * view_proj.inverse().unwrap()),
+            &Mat4::identity(),
+        );
+        assert_eq!(
+            Mat4::translation(Vec3::new(1.0, 2.0, 3.0)).inverse(),
+            Some(Mat4::translation(Vec3::new(-1.0, -2.0, -3.0)))
+        );
+    }
+
+    #[test]
+    fn inverse_singular_test() {
+        assert_eq!(Mat4::scale(Vec3::new(1.0, 0.0, 2.0)).inverse(), None);
+        let repeated = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(
+            Mat4::new(
+                repeated,
+                repeated * 2.0,
+                Vec4::new(0.0, 1.0, 0.0, 0.0),
+                Vec4::new(0.0, 0.0, 0.0, 1.0)
+            )
+            .inverse(),
+            None
+        );
+    }
+
+    #[test]
+    fn identity_test() {
+        let m = Mat4::translation(Vec3::new(1, 2, 3)) * Mat4::scale(Vec3::new(2, 2, 2));
+
+        assert_eq!(Mat4::identity() * m, m);
+        assert_eq!(m * Mat4::identity(), m);
+    }
+
+    #[test]
+    fn translation_test() {
+        let m = Mat4::translation(Vec3::new(1.0, -2.0, 3.0));
+
+        // points (w = 1) move, directions (w = 0) do not
+        assert_eq!(
+            m * Vec4::new(1.0, 1.0, 1.0, 1.0),
+            Vec4::new(2.0, -1.0, 4.0, 1.0)
+        );
+        assert_eq!(
+            m * Vec4::new(1.0, 1.0, 1.0, 0.0),
+            Vec4::new(1.0, 1.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn transpose_test() {
+        let m = Mat4::translation(Vec3::new(1, 2, 3));
+
+        assert_eq!(m.transpose().row(3), Vec4::new(1, 2, 3, 1));
+        assert_eq!(m.transpose().transpose(), m);
+    }
+
+    #[test]
+    fn scale_then_translate_test() {
+        let m = Mat4::translation(Vec3::new(1, 0, 0)) * Mat4::scale(Vec3::new(2, 2, 2));
+
+        assert_eq!(m * Vec4::new(1, 1, 1, 1), Vec4::new(3, 2, 2, 1));
+    }
+
+    #[test]
+    fn perspective_test() {
+        let m = Mat4::perspective(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+
+        let near = m * Vec4::new(0.0, 0.0, -1.0, 1.0);
+        let far = m * Vec4::new(0.0, 0.0, -10.0, 1.0);
+        assert!((near.z / near.w + 1.0).abs() < 1e-6);
+        assert!((far.z / far.w - 1.0).abs() < 1e-6);
+
+        // with a 90 degree fov the frustum edge is at x == -z
+        let edge = m * Vec4::new(5.0, 0.0, -5.0, 1.0);
+        assert!((edge.x / edge.w - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn look_at_test() {
+        let eye = Vec3::new(3.0, 2.0, 5.0);
+        let m = Mat4::look_at(eye, Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+
+        // the upper 3x3 rows form an orthonormal basis
+        let rows: Vec<Vec3<f32>> = (0..3)
+            .map(|i| {
+                let r = m.row(i);
+                Vec3::new(r.x, r.y, r.z)
+            })
+            .collect();
+        for i in 0..3 {
+            assert!((rows[i].length() - 1.0).abs() < 1e-6);
+            for j in (i + 1)..3 {
+                assert!(rows[i].dot(&rows[j]).abs() < 1e-6);
+            }
+        }
+        assert!((rows[0].cross(&rows[1]).dot(&rows[2]) - 1.0).abs() < 1e-6);
+
+        // the eye maps to the origin and the target lies down -Z
+        let e = m * Vec4::new(eye.x, eye.y, eye.z, 1.0);
+        assert!(Vec3::new(e.x, e.y, e.z).length() < 1e-5);
+        let t = m * Vec4::new(0.0, 0.0, 0.0, 1.0);
+        assert!(t.x.abs() < 1e-5 && t.y.abs() < 1e-5);
+        assert!((t.z + eye.length()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn normal_matrix_test() {
+        let model =
+            Mat4::translation(Vec3::new(5.0, 6.0, 7.0)) * Mat4::scale(Vec3::new(2.0, 4.0, 0.5));
+        let normal = model.normal_matrix().unwrap();
+
+        // translation is ignored and each scale is inverted
+        assert_eq!(normal.cols[0], Vec3::new(0.5, 0.0, 0.0));
+        assert_eq!(normal.cols[1], Vec3::new(0.0, 0.25, 0.0));
+        assert_eq!(normal.cols[2], Vec3::new(0.0, 0.0, 2.0));
+        assert_eq!(Mat4::scale(Vec3::new(1.0, 0.0, 1.0)).normal_matrix(), None);
+    }
+}
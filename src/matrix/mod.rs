@@ -0,0 +1,8 @@
+mod mat2;
+mod mat3;
+mod mat4;
+
+// rexports
+pub use crate::matrix::mat2::*;
+pub use crate::matrix::mat3::*;
+pub use crate::matrix::mat4::*;
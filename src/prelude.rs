@@ -0,0 +1,16 @@
+//! Commonly used types, for glob importing
+//!
+//! ```rust
+//! use lamar::prelude::*;
+//!
+//! let a: Vec3f = Vec3::new(1.0, 0.0, 0.0);
+//! let b = IVec2::new(2, 3);
+//!
+//! assert_eq!(a.length(), 1.0);
+//! assert_eq!(b.x, 2);
+//! ```
+
+pub use crate::vector::{
+    IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec2d, Vec2f, Vec3, Vec3d, Vec3f, Vec4, Vec4d,
+    Vec4f,
+};
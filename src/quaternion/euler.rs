@@ -0,0 +1,267 @@
+use crate::matrix::Mat3;
+use crate::quaternion::Quat;
+use crate::vector::Vec3;
+use num::Float;
+
+/// The order in which the three rotations of an `EulerAngles` are applied
+///
+/// The letters name the axes in the order they rotate a vector, about the fixed
+/// world axes. `XYZ` rotates about X first, then Y, then Z, so its Matrix is
+/// `Rz * Ry * Rx`. (This is the same as rotating about the local axes in the
+/// reverse order, Z then Y then X.)
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    /// Return the axis indices in the order they are applied
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    /// Return whether the axes are a cyclic (even) permutation of `X, Y, Z`
+    fn is_even(self) -> bool {
+        matches!(self, EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY)
+    }
+}
+
+/// A 3D rotation stored as three angles (in radians) and the order to apply them in.
+///
+/// Y is up: `yaw` rotates about Y, `pitch` about X and `roll` about Z.
+///
+/// ```rust
+/// # use lamar::quaternion::{EulerAngles, EulerOrder};
+/// let e = EulerAngles::new(0.3_f64, -0.2, 0.1, EulerOrder::YXZ);
+/// let q = e.to_quat();
+/// let back = EulerAngles::from_quat(q, EulerOrder::YXZ);
+///
+/// assert!((back.yaw - 0.3).abs() < 1e-12);
+/// assert!((back.pitch + 0.2).abs() < 1e-12);
+/// assert!((back.roll - 0.1).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct EulerAngles<T>
+where
+    T: Float,
+{
+    pub yaw: T,
+    pub pitch: T,
+    pub roll: T,
+    pub order: EulerOrder,
+}
+
+impl<T> EulerAngles<T>
+where
+    T: Float,
+{
+    /// Create a set of Euler angles from its angles (in radians) and rotation order
+    pub fn new(yaw: T, pitch: T, roll: T, order: EulerOrder) -> Self {
+        Self {
+            yaw,
+            pitch,
+            roll,
+            order,
+        }
+    }
+
+    /// Return the angle about the given axis index
+    fn angle(&self, axis: usize) -> T {
+        match axis {
+            0 => self.pitch,
+            1 => self.yaw,
+            _ => self.roll,
+        }
+    }
+
+    /// Return the rotation as a quaternion
+    pub fn to_quat(&self) -> Quat<T> {
+        let (z, o) = (T::zero(), T::one());
+        let units = [Vec3::new(o, z, z), Vec3::new(z, o, z), Vec3::new(z, z, o)];
+        let [i, j, k] = self.order.axes();
+        let rotation = |axis: usize| Quat::from_axis_angle(units[axis], self.angle(axis));
+        rotation(k) * rotation(j) * rotation(i)
+    }
+
+    /// Return the rotation as a Matrix
+    pub fn to_mat3(&self) -> Mat3<T> {
+        self.to_quat().to_mat3()
+    }
+
+    /// Decompose the unit quaternion `q` into Euler angles applied in `order`
+    ///
+    /// See `from_mat3` for the ranges of the angles, and how gimbal lock is handled.
+    pub fn from_quat(q: Quat<T>, order: EulerOrder) -> Self {
+        Self::from_mat3(&q.to_mat3(), order)
+    }
+
+    /// Decompose the rotation Matrix `m` into Euler angles applied in `order`
+    ///
+    /// The middle rotation of the order is returned in `[-pi/2, pi/2]`, the others
+    /// in `[-pi, pi]`. When the middle rotation is within about `sqrt(epsilon)` of
+    /// +-90 degrees (gimbal lock, e.g. pitch for `YXZ`) the first and last axes line
+    /// up, so the last applied angle is set to zero and the first one takes up the
+    /// whole rotation.
+    pub fn from_mat3(m: &Mat3<T>, order: EulerOrder) -> Self {
+        let at = |r: usize, c: usize| {
+            let col = m.cols[c];
+            match r {
+                0 => col.x,
+                1 => col.y,
+                _ => col.z,
+            }
+        };
+        let [i, j, k] = order.axes();
+        // odd orders are mirrored even ones, which flips the sign of each angle
+        let s = if order.is_even() { T::one() } else { -T::one() };
+
+        let sin_b = -s * at(k, i);
+        let cos_b = at(k, j).hypot(at(k, k));
+        let b = sin_b.atan2(cos_b);
+        let (a, c) = if cos_b > T::epsilon().sqrt() {
+            (
+                (s * at(k, j)).atan2(at(k, k)),
+                (s * at(j, i)).atan2(at(i, i)),
+            )
+        } else {
+            ((-s * at(j, k)).atan2(at(j, j)), T::zero())
+        };
+
+        let mut angles = [T::zero(); 3];
+        angles[i] = a;
+        angles[j] = b;
+        angles[k] = c;
+        Self::new(angles[1], angles[0], angles[2], order)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat3;
+    use crate::quaternion::{EulerAngles, EulerOrder};
+    use crate::vector::Vec3;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    const ORDERS: [EulerOrder; 6] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ];
+
+    fn assert_mat_close(a: &Mat3<f64>, b: &Mat3<f64>) {
+        for c in 0..3 {
+            assert!(
+                (a.cols[c] - b.cols[c]).length() < 1e-9,
+                "{:?} != {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    // build angles with `first`, `middle` and `last` on the axes of `order`
+    fn in_order(order: EulerOrder, first: f64, middle: f64, last: f64) -> EulerAngles<f64> {
+        let mut angles = [0.0; 3];
+        let axes = order.axes();
+        angles[axes[0]] = first;
+        angles[axes[1]] = middle;
+        angles[axes[2]] = last;
+        EulerAngles::new(angles[1], angles[0], angles[2], order)
+    }
+
+    #[test]
+    fn single_axis_test() {
+        let yaw = EulerAngles::new(FRAC_PI_2, 0.0, 0.0, EulerOrder::YXZ).to_mat3();
+        let pitch = EulerAngles::new(0.0, FRAC_PI_2, 0.0, EulerOrder::YXZ).to_mat3();
+        let roll = EulerAngles::new(0.0, 0.0, FRAC_PI_2, EulerOrder::YXZ).to_mat3();
+        let v = Vec3::new(1.0, 2.0, 3.0);
+
+        assert!((yaw * v - Vec3::new(3.0, 2.0, -1.0)).length() < 1e-12);
+        assert!((pitch * v - Vec3::new(1.0, -3.0, 2.0)).length() < 1e-12);
+        assert!((roll * v - Vec3::new(-2.0, 1.0, 3.0)).length() < 1e-12);
+    }
+
+    #[test]
+    fn order_matters_test() {
+        let xyz = EulerAngles::new(0.5, 0.4, 0.3, EulerOrder::XYZ).to_mat3();
+        let zyx = EulerAngles::new(0.5, 0.4, 0.3, EulerOrder::ZYX).to_mat3();
+        let rx = EulerAngles::new(0.0, 0.4, 0.0, EulerOrder::XYZ).to_mat3();
+        let ry = EulerAngles::new(0.5, 0.0, 0.0, EulerOrder::XYZ).to_mat3();
+        let rz = EulerAngles::new(0.0, 0.0, 0.3, EulerOrder::XYZ).to_mat3();
+
+        assert_mat_close(&xyz, &(rz * ry * rx));
+        assert_mat_close(&zyx, &(rx * ry * rz));
+        assert!((xyz.cols[0] - zyx.cols[0]).length() > 0.1);
+    }
+
+    #[test]
+    fn round_trip_grid_test() {
+        let outer = [-3.0, -2.0, -1.0, -0.3, 0.0, 0.5, 1.5, 2.5, 3.0];
+        let middle = [-1.5, -1.0, -0.2, 0.0, 0.7, 1.2, 1.5];
+
+        for order in ORDERS {
+            for &a in &outer {
+                for &b in &middle {
+                    for &c in &outer {
+                        let e = in_order(order, a, b, c);
+                        let back = EulerAngles::from_quat(e.to_quat(), order);
+
+                        assert!((back.yaw - e.yaw).abs() < 1e-9, "{:?} != {:?}", back, e);
+                        assert!((back.pitch - e.pitch).abs() < 1e-9, "{:?} != {:?}", back, e);
+                        assert!((back.roll - e.roll).abs() < 1e-9, "{:?} != {:?}", back, e);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gimbal_lock_test() {
+        for order in ORDERS {
+            let last_axis = order.axes()[2];
+            for middle in [FRAC_PI_2, -FRAC_PI_2] {
+                for (a, c) in [(0.4, -0.9), (-2.5, 1.1), (0.0, PI)] {
+                    let e = in_order(order, a, middle, c);
+                    let back = EulerAngles::from_mat3(&e.to_mat3(), order);
+
+                    // the last angle is zeroed, and the rotation is still the same
+                    assert_eq!(back.angle(last_axis), 0.0);
+                    assert!((back.angle(order.axes()[1]) - middle).abs() < 1e-6);
+                    assert_mat_close(&back.to_mat3(), &e.to_mat3());
+                    assert_eq!(back, EulerAngles::from_quat(e.to_quat(), order));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pitch_lock_is_deterministic_test() {
+        // at pitch 90 degrees, yaw and roll both spin about the same world axis,
+        // so moving angle from one to the other gives the same rotation
+        let a = EulerAngles::new(0.7, FRAC_PI_2, 0.2, EulerOrder::YXZ);
+        let b = EulerAngles::new(0.5, FRAC_PI_2, 0.4, EulerOrder::YXZ);
+        assert_mat_close(&a.to_mat3(), &b.to_mat3());
+
+        let from_a = EulerAngles::from_mat3(&a.to_mat3(), EulerOrder::YXZ);
+        let from_b = EulerAngles::from_mat3(&b.to_mat3(), EulerOrder::YXZ);
+
+        assert!((from_a.yaw - from_b.yaw).abs() < 1e-9);
+        assert_eq!(from_a.roll, 0.0);
+        assert_eq!(from_b.roll, 0.0);
+    }
+}
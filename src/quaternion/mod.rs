@@ -0,0 +1,6 @@
+mod euler;
+mod quat;
+
+// rexports
+pub use crate::quaternion::euler::*;
+pub use crate::quaternion::quat::*;
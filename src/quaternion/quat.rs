@@ -0,0 +1,288 @@
+use crate::matrix::Mat3;
+use crate::vector::{Vec3, Vec4};
+use num::Float;
+use std::ops::Mul;
+
+/// A generic quaternion implementation, used to represent 3D rotations.
+///
+/// `x, y, z` is the vector part and `w` the scalar part, matching the layout
+/// used for quaternions stored in a `Vec4`. Rotations are expected to be unit
+/// quaternions.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Quat<T>
+where
+    T: Float,
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T> Quat<T>
+where
+    T: Float,
+{
+    /// Create a quaternion from its components
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Create the identity quaternion (no rotation)
+    pub fn identity() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero(), T::one())
+    }
+
+    /// Create a quaternion rotating by `angle` radians about `axis`
+    ///
+    /// `axis` must be normalized.
+    pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Self {
+        let half = angle / (T::one() + T::one());
+        let s = half.sin();
+        Self::new(axis.x * s, axis.y * s, axis.z * s, half.cos())
+    }
+
+    /// Create the shortest rotation taking the direction `from` onto `to`
+    ///
+    /// Both vectors are normalized first, so only their directions matter (and
+    /// neither may be zero). The rotation is about `from.cross(&to)`, by the angle
+    /// between them. Identical directions give the identity, and exactly opposite
+    /// ones a half turn about an arbitrary perpendicular axis (from
+    /// `any_orthonormal_pair`).
+    ///
+    /// ```rust
+    /// # use lamar::quaternion::Quat;
+    /// # use lamar::vector::Vec3;
+    /// let up = Vec3::new(0.0, 1.0, 0.0);
+    /// let normal = Vec3::new(1.0, 1.0, 0.0);
+    /// let q = Quat::from_rotation_arc(up, normal);
+    ///
+    /// assert!((q.rotate_vec3(up) - normal.normalize()).length() < 1e-12_f64);
+    /// ```
+    pub fn from_rotation_arc(from: Vec3<T>, to: Vec3<T>) -> Self {
+        let (from, to) = (from.normalize(), to.normalize());
+        let axis = from.cross(&to);
+        let sin = axis.length();
+        if sin == T::zero() {
+            if from.dot(&to) > T::zero() {
+                return Self::identity();
+            }
+            let (perpendicular, _) = from.any_orthonormal_pair();
+            return Self::new(perpendicular.x, perpendicular.y, perpendicular.z, T::zero());
+        }
+        // atan2 keeps the angle accurate even when the directions are nearly opposite
+        Self::from_axis_angle(axis / sin, sin.atan2(from.dot(&to)))
+    }
+
+    /// Return the dot product of two quaternions
+    pub fn dot(&self, other: &Quat<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Return the squared length of the quaternion
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Return the length of the quaternion
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Return the quaternion scaled to unit length
+    pub fn normalize(&self) -> Self {
+        let len = self.length();
+        Self::new(self.x / len, self.y / len, self.z / len, self.w / len)
+    }
+
+    /// Return the conjugate of the quaternion (the vector part negated)
+    ///
+    /// For a unit quaternion this is the inverse rotation.
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Return the inverse of the quaternion
+    ///
+    /// `conjugate / length_squared`
+    pub fn inverse(&self) -> Self {
+        let c = self.conjugate();
+        let len_sq = self.length_squared();
+        Self::new(c.x / len_sq, c.y / len_sq, c.z / len_sq, c.w / len_sq)
+    }
+
+    /// Rotate `v` by the quaternion
+    ///
+    /// `v + 2w(u x v) + 2(u x (u x v))` where `u` is the vector part
+    pub fn rotate_vec3(&self, v: Vec3<T>) -> Vec3<T> {
+        let two = T::one() + T::one();
+        let u = Vec3::new(self.x, self.y, self.z);
+        let t = u.cross(&v) * two;
+        v + t * self.w + u.cross(&t)
+    }
+
+    /// Return the rotation Matrix equivalent to the quaternion
+    pub fn to_mat3(&self) -> Mat3<T> {
+        let (o, two) = (T::one(), T::one() + T::one());
+        let Self { x, y, z, w } = *self;
+        Mat3::from_rows(
+            Vec3::new(
+                o - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ),
+            Vec3::new(
+                two * (x * y + w * z),
+                o - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ),
+            Vec3::new(
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                o - two * (x * x + y * y),
+            ),
+        )
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::quaternion::Quat;
+/// # use lamar::vector::Vec3;
+/// let a = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), 0.5);
+/// let b = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), 1.0);
+///
+/// assert!((a * a).dot(&b) > 0.9999);
+/// ```
+///
+/// The result applies `other` first, then `self`.
+impl<T> Mul for Quat<T>
+where
+    T: Float,
+{
+    type Output = Quat<T>;
+
+    fn mul(self, other: Quat<T>) -> Self::Output {
+        Self::new(
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        )
+    }
+}
+
+impl<T> From<Vec4<T>> for Quat<T>
+where
+    T: Float,
+{
+    fn from(v: Vec4<T>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl<T> From<Quat<T>> for Vec4<T>
+where
+    T: Float,
+{
+    fn from(q: Quat<T>) -> Self {
+        Vec4::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::quaternion::Quat;
+    use crate::test_rng::TestRng;
+    use crate::vector::Vec3;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn assert_close(a: Vec3<f64>, b: Vec3<f64>) {
+        assert!((a - b).length() < 1e-12, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn rotate_vec3_test() {
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+
+        assert_close(
+            q.rotate_vec3(Vec3::new(1.0, 0.0, 0.0)),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        assert_close(
+            q.to_mat3() * Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-2.0, 1.0, 3.0),
+        );
+        assert_close(
+            q.inverse().rotate_vec3(Vec3::new(0.0, 1.0, 0.0)),
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn compose_test() {
+        let about_z = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+        let about_x = Quat::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), FRAC_PI_2);
+        let v = Vec3::new(0.3, -1.2, 2.0);
+
+        // matches the Mat3 convention: the right hand side is applied first
+        assert_close(
+            (about_x * about_z).rotate_vec3(v),
+            about_x.rotate_vec3(about_z.rotate_vec3(v)),
+        );
+        assert_close(
+            (about_x * about_z).to_mat3() * v,
+            about_x.to_mat3() * (about_z.to_mat3() * v),
+        );
+        assert!((Quat::new(1.0_f64, 2.0, 2.0, 4.0).normalize().length() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn from_rotation_arc_test() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 3.0, 0.0);
+
+        assert_eq!(Quat::from_rotation_arc(x, x * 2.0), Quat::identity());
+        assert_close(Quat::from_rotation_arc(x, y).rotate_vec3(x), y.normalize());
+        assert_close(
+            Quat::from_rotation_arc(x, y).rotate_vec3(Vec3::new(0.0, 0.0, 1.0)),
+            Vec3::new(0.0, 0.0, 1.0),
+        );
+
+        // opposite directions, including ones that are only nearly so
+        for from in [x, y, Vec3::new(0.0, 0.0, -1.0), Vec3::new(1.0, -2.0, 0.5)] {
+            let from = from.normalize();
+            assert_close(
+                Quat::from_rotation_arc(from, from * -1.0).rotate_vec3(from),
+                from * -1.0,
+            );
+        }
+        let nudged = Vec3::new(-1.0, 1e-9, 0.0);
+        let q = Quat::from_rotation_arc(x, nudged);
+        assert!((q.rotate_vec3(x) - nudged.normalize()).length() < 1e-9);
+        assert!((q.length() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn from_rotation_arc_random_test() {
+        let mut rng = TestRng::new(0x1234_5678);
+        let mut r = move || rng.range(-1.0, 1.0);
+
+        for _ in 0..500 {
+            let from = Vec3::new(r(), r(), r());
+            let to = Vec3::new(r(), r(), r());
+            if from.length() < 1e-3 || to.length() < 1e-3 {
+                continue;
+            }
+            let q = Quat::from_rotation_arc(from, to);
+
+            assert!((q.rotate_vec3(from.normalize()) - to.normalize()).length() < 1e-12);
+            // the shortest arc leaves the axis of rotation alone
+            let axis = from.cross(&to);
+            if axis.length() > 1e-6 {
+                assert!((q.rotate_vec3(axis) - axis).length() < 1e-12);
+            }
+            assert_eq!(from.rotation_to(&to), q);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Scalar versions of the shader style helpers, e.g. `step` and `smoothstep`.
+//!
+//! The vector types apply these component-wise through their own associated
+//! functions (`Vec3::smoothstep`, ...).
+
+use num::Float;
+
+/// Return 0 when `x` is below `edge`, and 1 otherwise (GLSL `step`)
+///
+/// ```rust
+/// # use lamar::scalar::step;
+/// assert_eq!(step(0.5, 0.2), 0.0);
+/// assert_eq!(step(0.5, 0.5), 1.0);
+/// ```
+pub fn step<T: Float>(edge: T, x: T) -> T {
+    if x < edge {
+        T::zero()
+    } else {
+        T::one()
+    }
+}
+
+/// Return where `x` lies between `edge0` and `edge1`, clamped to `[0, 1]`
+///
+/// When the edges are equal this is `step(edge0, x)` rather than a division by zero.
+fn unit_position<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    if edge0 == edge1 {
+        return step(edge0, x);
+    }
+    ((x - edge0) / (edge1 - edge0)).max(T::zero()).min(T::one())
+}
+
+/// Hermite smoothing of `x` between `edge0` and `edge1` (GLSL `smoothstep`)
+///
+/// `t = clamp((x - edge0) / (edge1 - edge0), 0, 1)`, then `3t² - 2t³`
+///
+/// GLSL leaves `edge0 == edge1` undefined; here it becomes a hard `step` at the
+/// edge instead of dividing by zero.
+///
+/// ```rust
+/// # use lamar::scalar::smoothstep;
+/// assert_eq!(smoothstep(0.0, 2.0, 1.0), 0.5);
+/// assert_eq!(smoothstep(0.0, 2.0, 3.0), 1.0);
+/// ```
+pub fn smoothstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    let t = unit_position(edge0, edge1, x);
+    t * t * (T::from(3.0).unwrap() - T::from(2.0).unwrap() * t)
+}
+
+/// Like `smoothstep`, but with zero first and second derivatives at the edges
+///
+/// `t = clamp((x - edge0) / (edge1 - edge0), 0, 1)`, then `6t⁵ - 15t⁴ + 10t³`
+pub fn smootherstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    let t = unit_position(edge0, edge1, x);
+    t * t * t * (t * (t * T::from(6.0).unwrap() - T::from(15.0).unwrap()) + T::from(10.0).unwrap())
+}
+
+/// Linearly rescale `x` from `in_min..in_max` to `out_min..out_max`
+///
+/// `out_min + (x - in_min) * (out_max - out_min) / (in_max - in_min)`
+///
+/// Values outside the input range extrapolate rather than clamp, and an empty
+/// input range (`in_min == in_max`) divides by zero.
+///
+/// ```rust
+/// # use lamar::scalar::remap;
+/// assert_eq!(remap(5.0, 0.0, 10.0, -1.0, 1.0), 0.0);
+/// ```
+pub fn remap<T: Float>(x: T, in_min: T, in_max: T, out_min: T, out_max: T) -> T {
+    out_min + (x - in_min) * (out_max - out_min) / (in_max - in_min)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::scalar::{remap, smootherstep, smoothstep, step};
+
+    #[test]
+    fn step_test() {
+        assert_eq!(step(1.0, 0.999), 0.0);
+        assert_eq!(step(1.0, 1.0), 1.0);
+        assert_eq!(step(-1.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn smoothstep_reference_test() {
+        // GLSL reference values
+        assert_eq!(smoothstep(0.0, 1.0, 0.0), 0.0);
+        assert_eq!(smoothstep(0.0, 1.0, 0.25), 0.15625);
+        assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+        assert_eq!(smoothstep(0.0, 1.0, 0.75), 0.84375);
+        assert_eq!(smoothstep(0.0, 1.0, 1.0), 1.0);
+        assert_eq!(smoothstep(2.0, 4.0, -10.0), 0.0);
+        assert_eq!(smoothstep(2.0, 4.0, 10.0), 1.0);
+        // reversed edges fall instead of rising
+        assert_eq!(smoothstep(1.0, 0.0, 0.25), 0.84375);
+
+        assert_eq!(smootherstep(0.0, 1.0, 0.5), 0.5);
+        assert_eq!(smootherstep(0.0, 1.0, 0.25), 0.103515625);
+    }
+
+    #[test]
+    fn equal_edges_test() {
+        for f in [smoothstep, smootherstep] {
+            assert_eq!(f(1.0, 1.0, 0.5), 0.0);
+            assert_eq!(f(1.0, 1.0, 1.0), 1.0);
+            assert_eq!(f(1.0_f32, 1.0, 2.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn remap_test() {
+        assert_eq!(remap(0.0, 0.0, 10.0, 100.0, 200.0), 100.0);
+        assert_eq!(remap(10.0, 0.0, 10.0, 100.0, 200.0), 200.0);
+        assert_eq!(remap(2.5, 0.0, 10.0, 1.0, -1.0), 0.5);
+        // extrapolates outside the input range
+        assert_eq!(remap(20.0, 0.0, 10.0, 0.0, 1.0), 2.0);
+    }
+}
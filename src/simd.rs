@@ -0,0 +1,105 @@
+/// Four `f32` lanes, processed together by each kernel
+///
+/// Batch code works four vectors at a time, with one `Lanes` per component
+/// (structure of arrays), so the results match the scalar per-vector code exactly.
+pub(crate) type Lanes = [f32; 4];
+
+/// The portable kernels, used when the `simd` feature is off or the target has no backend
+///
+/// Always compiled, as the reference the accelerated kernels are tested against.
+#[cfg_attr(
+    all(feature = "simd", target_arch = "x86_64", not(test)),
+    allow(dead_code)
+)]
+mod scalar {
+    use super::Lanes;
+
+    pub(crate) fn add(a: Lanes, b: Lanes) -> Lanes {
+        std::array::from_fn(|i| a[i] + b[i])
+    }
+
+    pub(crate) fn mul(a: Lanes, b: Lanes) -> Lanes {
+        std::array::from_fn(|i| a[i] * b[i])
+    }
+
+    pub(crate) fn div(a: Lanes, b: Lanes) -> Lanes {
+        std::array::from_fn(|i| a[i] / b[i])
+    }
+
+    pub(crate) fn sqrt(a: Lanes) -> Lanes {
+        a.map(f32::sqrt)
+    }
+}
+
+/// SSE kernels, which every x86_64 CPU supports
+///
+/// SAFETY (for every `unsafe` block below): SSE is part of the x86_64 baseline, so
+/// the intrinsics are always available, and the unaligned loads and stores only
+/// touch the four `f32`s of a `Lanes` array.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod sse {
+    use super::Lanes;
+    use core::arch::x86_64::*;
+
+    fn load(a: &Lanes) -> __m128 {
+        unsafe { _mm_loadu_ps(a.as_ptr()) }
+    }
+
+    fn store(v: __m128) -> Lanes {
+        let mut out = [0.0; 4];
+        unsafe { _mm_storeu_ps(out.as_mut_ptr(), v) };
+        out
+    }
+
+    pub(crate) fn add(a: Lanes, b: Lanes) -> Lanes {
+        store(unsafe { _mm_add_ps(load(&a), load(&b)) })
+    }
+
+    pub(crate) fn mul(a: Lanes, b: Lanes) -> Lanes {
+        store(unsafe { _mm_mul_ps(load(&a), load(&b)) })
+    }
+
+    pub(crate) fn div(a: Lanes, b: Lanes) -> Lanes {
+        store(unsafe { _mm_div_ps(load(&a), load(&b)) })
+    }
+
+    pub(crate) fn sqrt(a: Lanes) -> Lanes {
+        store(unsafe { _mm_sqrt_ps(load(&a)) })
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+pub(crate) use scalar::*;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+pub(crate) use sse::*;
+
+#[cfg(test)]
+mod test {
+    use super::{scalar, Lanes};
+    use crate::test_rng::TestRng;
+
+    /// Reproducible pseudo-random lanes in `[-10, 10)`
+    fn random_lanes(count: usize) -> Vec<Lanes> {
+        let mut rng = TestRng::new(0x9e37_79b9);
+        let mut next = move || rng.range(-10.0, 10.0) as f32;
+        (0..count)
+            .map(|_| [next(), next(), next(), next()])
+            .collect()
+    }
+
+    #[test]
+    fn kernels_match_scalar_test() {
+        let lanes = random_lanes(2000);
+
+        // IEEE 754 requires these to be correctly rounded, so every backend agrees exactly
+        for pair in lanes.chunks(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert_eq!(super::add(a, b), scalar::add(a, b));
+            assert_eq!(super::mul(a, b), scalar::mul(a, b));
+            assert_eq!(super::div(a, b), scalar::div(a, b));
+
+            let abs = a.map(f32::abs);
+            assert_eq!(super::sqrt(abs), scalar::sqrt(abs));
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::vector::{Vec2, Vec3};
+use std::{collections::HashMap, hash::Hash};
+
+/// A position that can be bucketed into a [`HashGrid`].
+///
+/// Implemented for `Vec2<f32>` and `Vec3<f32>`.
+pub trait GridPoint: Copy {
+    /// The integer cell coordinate a position is quantized to
+    type Cell: Copy + Eq + Hash;
+
+    /// The number of axes, so `cells_around` returns `(2 * reach + 1)^DIMENSIONS` cells
+    const DIMENSIONS: i32;
+
+    /// Return the cell containing this position
+    fn cell(&self, cell_size: f32) -> Self::Cell;
+
+    /// Return every cell within `reach` cells of `cell` (inclusive) along each axis
+    ///
+    /// Cells past the `i32` range don't exist, so they are left out.
+    fn cells_around(cell: Self::Cell, reach: i32) -> Vec<Self::Cell>;
+
+    /// Return the squared distance between two positions
+    fn distance_squared(&self, other: &Self) -> f32;
+}
+
+impl GridPoint for Vec2<f32> {
+    type Cell = Vec2<i32>;
+    const DIMENSIONS: i32 = 2;
+
+    fn cell(&self, cell_size: f32) -> Self::Cell {
+        self.quantize(cell_size)
+    }
+
+    fn cells_around(cell: Self::Cell, reach: i32) -> Vec<Self::Cell> {
+        let mut cells = Vec::new();
+        for x in (-reach..=reach).filter_map(|x| cell.x.checked_add(x)) {
+            for y in (-reach..=reach).filter_map(|y| cell.y.checked_add(y)) {
+                cells.push(Vec2::new(x, y));
+            }
+        }
+        cells
+    }
+
+    fn distance_squared(&self, other: &Self) -> f32 {
+        Vec2::distance_squared(self, other)
+    }
+}
+
+impl GridPoint for Vec3<f32> {
+    type Cell = Vec3<i32>;
+    const DIMENSIONS: i32 = 3;
+
+    fn cell(&self, cell_size: f32) -> Self::Cell {
+        self.quantize(cell_size)
+    }
+
+    fn cells_around(cell: Self::Cell, reach: i32) -> Vec<Self::Cell> {
+        let mut cells = Vec::new();
+        for x in (-reach..=reach).filter_map(|x| cell.x.checked_add(x)) {
+            for y in (-reach..=reach).filter_map(|y| cell.y.checked_add(y)) {
+                for z in (-reach..=reach).filter_map(|z| cell.z.checked_add(z)) {
+                    cells.push(Vec3::new(x, y, z));
+                }
+            }
+        }
+        cells
+    }
+
+    fn distance_squared(&self, other: &Self) -> f32 {
+        Vec3::distance_squared(self, other)
+    }
+}
+
+/// A spatial hash for neighbour queries over 2D or 3D positions.
+///
+/// Each inserted `(position, index)` pair is bucketed by `position.quantize(cell_size)`,
+/// so a query only has to look at the handful of cells overlapping its radius.
+#[derive(Clone, Debug)]
+pub struct HashGrid<V>
+where
+    V: GridPoint,
+{
+    cell_size: f32,
+    cells: HashMap<V::Cell, Vec<(V, usize)>>,
+}
+
+impl<V> HashGrid<V>
+where
+    V: GridPoint,
+{
+    /// Create an empty grid with the given cell size
+    ///
+    /// A cell size close to the usual query radius keeps queries cheap.
+    pub fn new(cell_size: f32) -> Self {
+        assert!(cell_size > 0.0, "HashGrid cell size must be positive");
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Return the size of each cell
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Insert `index` at `position`
+    pub fn insert(&mut self, position: V, index: usize) {
+        self.cells
+            .entry(position.cell(self.cell_size))
+            .or_default()
+            .push((position, index));
+    }
+
+    /// Remove every entry from the grid, keeping the cell size
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Return the indices of every entry within `radius` of `pos`
+    ///
+    /// Only the cells overlapping the radius are visited, and entries in those
+    /// cells are then filtered by their exact distance. When the radius covers
+    /// more cells than are occupied, the occupied cells are scanned instead.
+    pub fn query_neighbors(&self, pos: V, radius: f32) -> Vec<usize> {
+        let reach = (radius / self.cell_size).ceil() as i32;
+        let radius_squared = radius * radius;
+        let within = |entries: &Vec<(V, usize)>| {
+            entries
+                .iter()
+                .filter(|(p, _)| p.distance_squared(&pos) <= radius_squared)
+                .map(|(_, i)| *i)
+                .collect::<Vec<_>>()
+        };
+
+        let cell_count = (2.0 * reach as f64 + 1.0).powi(V::DIMENSIONS);
+        if cell_count > self.cells.len() as f64 {
+            return self.cells.values().flat_map(within).collect();
+        }
+        V::cells_around(pos.cell(self.cell_size), reach)
+            .iter()
+            .filter_map(|cell| self.cells.get(cell))
+            .flat_map(within)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HashGrid;
+    use crate::vector::{Vec2, Vec3};
+
+    #[test]
+    fn query_neighbors_vec3_test() {
+        let mut grid = HashGrid::new(1.0);
+        let cluster = [
+            Vec3::new(0.1, 0.1, 0.1),
+            Vec3::new(0.4, -0.2, 0.3),
+            Vec3::new(-0.3, 0.2, -0.1),
+        ];
+        for (i, p) in cluster.iter().enumerate() {
+            grid.insert(*p, i);
+        }
+        grid.insert(Vec3::new(10.0, 10.0, 10.0), 3);
+        grid.insert(Vec3::new(1.2, 0.0, 0.0), 4);
+
+        let mut found = grid.query_neighbors(Vec3::new(0.0, 0.0, 0.0), 0.75);
+        found.sort();
+
+        assert_eq!(found, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn query_neighbors_vec2_test() {
+        let mut grid = HashGrid::new(0.5);
+        grid.insert(Vec2::new(-0.1, -0.1), 0);
+        grid.insert(Vec2::new(0.6, 0.0), 1);
+        grid.insert(Vec2::new(5.0, 5.0), 2);
+
+        let mut found = grid.query_neighbors(Vec2::new(0.0, 0.0), 1.0);
+        found.sort();
+
+        assert_eq!(found, vec![0, 1]);
+        assert!(grid.query_neighbors(Vec2::new(20.0, 20.0), 1.0).is_empty());
+    }
+
+    #[test]
+    fn query_neighbors_far_from_origin_test() {
+        // cells past the i32 range are left out rather than overflowing
+        let mut grid = HashGrid::new(1.0);
+        grid.insert(Vec2::new(3e9, 0.0), 0);
+        grid.insert(Vec2::new(-3e9, 0.0), 1);
+
+        assert_eq!(grid.query_neighbors(Vec2::new(3e9, 0.0), 1.0), vec![0]);
+        assert_eq!(grid.query_neighbors(Vec2::new(-3e9, 0.0), 1.0), vec![1]);
+
+        let mut grid = HashGrid::new(1.0);
+        grid.insert(Vec3::new(0.0, 3e9, -3e9), 0);
+        assert_eq!(
+            grid.query_neighbors(Vec3::new(0.0, 3e9, -3e9), 1.0),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn query_neighbors_large_radius_test() {
+        // a radius of 1000 cells scans the few occupied cells, not 8e9 empty ones
+        let mut grid = HashGrid::new(1.0);
+        grid.insert(Vec3::new(0.0, 0.0, 0.0), 0);
+        grid.insert(Vec3::new(999.0, 0.0, 0.0), 1);
+        grid.insert(Vec3::new(0.0, 2000.0, 0.0), 2);
+
+        let mut found = grid.query_neighbors(Vec3::new(0.0, 0.0, 0.0), 1000.0);
+        found.sort();
+        assert_eq!(found, vec![0, 1]);
+        assert_eq!(
+            grid.query_neighbors(Vec3::new(0.0, 0.0, 0.0), f32::INFINITY)
+                .len(),
+            3
+        );
+    }
+}
@@ -0,0 +1,4 @@
+mod hash_grid;
+
+// rexports
+pub use crate::spatial::hash_grid::*;
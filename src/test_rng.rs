@@ -0,0 +1,32 @@
+/// A small xorshift generator, so randomized tests see the same values every run
+///
+/// Only used by the tests, which can't rely on the optional `rand` dependency.
+pub(crate) struct TestRng {
+    state: u64,
+}
+
+impl TestRng {
+    /// Create a generator from a seed (a zero seed is replaced, as xorshift would stay zero)
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed.max(1) }
+    }
+
+    /// Return the next 64 random bits
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Return a number in `0..n`
+    pub(crate) fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Return a number in `[min, max)`
+    pub(crate) fn range(&mut self, min: f64, max: f64) -> f64 {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        min + unit * (max - min)
+    }
+}
@@ -0,0 +1,6 @@
+mod transform2d;
+mod transform3d;
+
+// rexports
+pub use crate::transform::transform2d::*;
+pub use crate::transform::transform3d::*;
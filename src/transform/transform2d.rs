@@ -0,0 +1,196 @@
+use crate::matrix::Mat3;
+use crate::vector::{Vec2, Vec3};
+use num::Float;
+use std::ops::Mul;
+
+/// A 2D transform made of a translation, a rotation and a (possibly non-uniform) scale.
+///
+/// Points are scaled first, then rotated counter-clockwise by `rotation` radians,
+/// then translated.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transform2D<T>
+where
+    T: Float,
+{
+    pub translation: Vec2<T>,
+    pub rotation: T,
+    pub scale: Vec2<T>,
+}
+
+impl<T> Transform2D<T>
+where
+    T: Float,
+{
+    /// Create a 2D transform from its translation, rotation (in radians) and scale
+    pub fn new(translation: Vec2<T>, rotation: T, scale: Vec2<T>) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Create the identity 2D transform
+    pub fn identity() -> Self {
+        let (z, o) = (T::zero(), T::one());
+        Self::new(Vec2::new(z, z), z, Vec2::new(o, o))
+    }
+
+    /// Return the transform as a homogeneous 3x3 Matrix
+    ///
+    /// `translation * rotation * scale`
+    pub fn to_mat3(&self) -> Mat3<T> {
+        let (z, o) = (T::zero(), T::one());
+        let (sin, cos) = self.rotation.sin_cos();
+        let (s, t) = (self.scale, self.translation);
+        Mat3::new(
+            Vec3::new(cos * s.x, sin * s.x, z),
+            Vec3::new(-sin * s.y, cos * s.y, z),
+            Vec3::new(t.x, t.y, o),
+        )
+    }
+
+    /// Transform a direction by the rotation and scale, ignoring the translation
+    pub fn transform_vector(&self, v: Vec2<T>) -> Vec2<T> {
+        Vec2::new(v.x * self.scale.x, v.y * self.scale.y).rotate(self.rotation)
+    }
+
+    /// Transform a point by the scale, rotation and translation
+    pub fn transform_point(&self, p: Vec2<T>) -> Vec2<T> {
+        self.translation + self.transform_vector(p)
+    }
+
+    /// Map a transformed point back to the untransformed space
+    ///
+    /// The same as multiplying by `inverse`, without building the Matrix.
+    pub fn inverse_transform_point(&self, p: Vec2<T>) -> Vec2<T> {
+        self.inverse_transform_vector(p - self.translation)
+    }
+
+    /// Map a transformed direction back to the untransformed space, ignoring the
+    /// translation
+    pub fn inverse_transform_vector(&self, v: Vec2<T>) -> Vec2<T> {
+        let v = v.rotate(-self.rotation);
+        Vec2::new(v.x / self.scale.x, v.y / self.scale.y)
+    }
+
+    /// Return the homogeneous Matrix undoing this transform
+    ///
+    /// Like `Transform::inverse`, this is exact for any scale, and is `None` when a
+    /// scale component is zero.
+    pub fn inverse(&self) -> Option<Mat3<T>> {
+        let (z, o) = (T::zero(), T::one());
+        if self.scale.x == z || self.scale.y == z {
+            return None;
+        }
+        let column = |v: Vec2<T>, w: T| Vec3::new(v.x, v.y, w);
+        Some(Mat3::new(
+            column(self.inverse_transform_vector(Vec2::new(o, z)), z),
+            column(self.inverse_transform_vector(Vec2::new(z, o)), z),
+            column(self.inverse_transform_point(Vec2::new(z, z)), o),
+        ))
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::transform::Transform2D;
+/// # use lamar::vector::Vec2;
+/// let parent = Transform2D::new(Vec2::new(1.0, 0.0), 0.0, Vec2::new(2.0, 2.0));
+/// let child = Transform2D::new(Vec2::new(0.0, 1.0), 0.0, Vec2::new(1.0, 1.0));
+///
+/// assert_eq!((parent * child).transform_point(Vec2::new(1.0, 0.0)), Vec2::new(3.0, 2.0));
+/// ```
+///
+/// The result applies `other` (the child) first, then `self` (the parent), and
+/// is exact when the parent's scale is uniform.
+impl<T> Mul for Transform2D<T>
+where
+    T: Float,
+{
+    type Output = Transform2D<T>;
+
+    fn mul(self, other: Transform2D<T>) -> Self::Output {
+        Self::new(
+            self.transform_point(other.translation),
+            self.rotation + other.rotation,
+            Vec2::new(self.scale.x * other.scale.x, self.scale.y * other.scale.y),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat3;
+    use crate::transform::Transform2D;
+    use crate::vector::{Vec2, Vec3};
+
+    fn assert_close(a: Vec2<f64>, b: Vec2<f64>) {
+        assert!((a - b).length() < 1e-9, "{} != {}", a, b);
+    }
+
+    fn assert_mat_close(a: &Mat3<f64>, b: &Mat3<f64>) {
+        for c in 0..3 {
+            assert!(
+                (a.cols[c] - b.cols[c]).length() < 1e-9,
+                "{:?} != {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn to_mat3_test() {
+        let t = Transform2D::new(Vec2::new(1.0, -2.0), 0.8, Vec2::new(2.0, 0.5));
+        let p = Vec2::new(0.3, 1.5);
+        let m = t.to_mat3() * Vec3::new(p.x, p.y, 1.0);
+
+        assert_close(Vec2::new(m.x, m.y), t.transform_point(p));
+        assert_eq!(m.z, 1.0);
+        assert_mat_close(&Transform2D::identity().to_mat3(), &Mat3::identity());
+    }
+
+    #[test]
+    fn compose_matches_matrices_test() {
+        let parent = Transform2D::new(Vec2::new(4.0, -1.0), 1.2, Vec2::new(1.5, 1.5));
+        let child = Transform2D::new(Vec2::new(-1.0, 2.0), -0.4, Vec2::new(2.0, 0.5));
+        let p = Vec2::new(0.3, 1.5);
+
+        assert_mat_close(
+            &(parent * child).to_mat3(),
+            &(parent.to_mat3() * child.to_mat3()),
+        );
+        assert_close(
+            (parent * child).transform_point(p),
+            parent.transform_point(child.transform_point(p)),
+        );
+    }
+
+    #[test]
+    fn inverse_test() {
+        let t = Transform2D::new(Vec2::new(1.0, -2.0), 0.8, Vec2::new(2.0, 0.5));
+        let p = Vec2::new(0.3, 1.5);
+        let inverse = t.inverse().unwrap();
+        let q = t.transform_point(p);
+        let back = inverse * Vec3::new(q.x, q.y, 1.0);
+
+        assert_close(Vec2::new(back.x, back.y), p);
+        assert_eq!(back.z, 1.0);
+        assert_mat_close(&(inverse * t.to_mat3()), &Mat3::identity());
+        assert_mat_close(&(t.to_mat3() * inverse), &Mat3::identity());
+
+        let flat = Transform2D::new(p, 0.8, Vec2::new(0.0, 1.0));
+        assert_eq!(flat.inverse(), None);
+    }
+
+    #[test]
+    fn inverse_transform_point_test() {
+        let t = Transform2D::new(Vec2::new(1.0, -2.0), 0.8, Vec2::new(2.0, 0.5));
+        let p = Vec2::new(0.3, 1.5);
+
+        assert_close(t.inverse_transform_point(t.transform_point(p)), p);
+        assert_close(t.transform_point(t.inverse_transform_point(p)), p);
+        assert_close(t.inverse_transform_vector(t.transform_vector(p)), p);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::matrix::Mat4;
+use crate::quaternion::Quat;
+use crate::vector::{Vec3, Vec4};
+use num::Float;
+use std::ops::Mul;
+
+/// A 3D transform made of a translation, a rotation and a (possibly non-uniform) scale.
+///
+/// Points are scaled first, then rotated, then translated, like a `T * R * S` Matrix.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transform<T>
+where
+    T: Float,
+{
+    pub translation: Vec3<T>,
+    pub rotation: Quat<T>,
+    pub scale: Vec3<T>,
+}
+
+impl<T> Transform<T>
+where
+    T: Float,
+{
+    /// Create a transform from its translation, rotation and scale
+    pub fn new(translation: Vec3<T>, rotation: Quat<T>, scale: Vec3<T>) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Create the identity transform
+    pub fn identity() -> Self {
+        let (z, o) = (T::zero(), T::one());
+        Self::new(Vec3::new(z, z, z), Quat::identity(), Vec3::new(o, o, o))
+    }
+
+    /// Return the transform as a Matrix
+    ///
+    /// `translation * rotation * scale`
+    pub fn to_mat4(&self) -> Mat4<T> {
+        let (z, o) = (T::zero(), T::one());
+        let r = self.rotation.to_mat3();
+        let axis = |i: usize, s: T| {
+            let c = r.cols[i] * s;
+            Vec4::new(c.x, c.y, c.z, z)
+        };
+        let t = self.translation;
+        Mat4::new(
+            axis(0, self.scale.x),
+            axis(1, self.scale.y),
+            axis(2, self.scale.z),
+            Vec4::new(t.x, t.y, t.z, o),
+        )
+    }
+
+    /// Transform a direction by the rotation and scale, ignoring the translation
+    pub fn transform_vector(&self, v: Vec3<T>) -> Vec3<T> {
+        self.rotation
+            .rotate_vec3(v.zip_with(self.scale, |a, b| a * b))
+    }
+
+    /// Transform a point by the scale, rotation and translation
+    pub fn transform_point(&self, p: Vec3<T>) -> Vec3<T> {
+        self.translation + self.transform_vector(p)
+    }
+
+    /// Map a transformed point back to the untransformed space
+    ///
+    /// The same as multiplying by `inverse`, without building the Matrix.
+    pub fn inverse_transform_point(&self, p: Vec3<T>) -> Vec3<T> {
+        self.inverse_transform_vector(p - self.translation)
+    }
+
+    /// Map a transformed direction back to the untransformed space, ignoring the
+    /// translation
+    pub fn inverse_transform_vector(&self, v: Vec3<T>) -> Vec3<T> {
+        self.rotation
+            .conjugate()
+            .rotate_vec3(v)
+            .zip_with(self.scale, |a, b| a / b)
+    }
+
+    /// Return the Matrix undoing this transform
+    ///
+    /// The inverse of `T * R * S` is `S^-1 * R^-1 * T^-1`, which in general isn't a
+    /// `Transform`: with a non-uniform scale the scaling happens along the rotated
+    /// axes. The Matrix is exact for any scale, and is `None` when a scale component
+    /// is zero.
+    ///
+    /// ```rust
+    /// # use lamar::quaternion::Quat;
+    /// # use lamar::transform::Transform;
+    /// # use lamar::vector::{Vec3, Vec4};
+    /// let t = Transform::new(Vec3::new(1.0, 2.0, 3.0), Quat::identity(), Vec3::new(2.0, 4.0, 0.5));
+    /// let p = t.inverse().unwrap() * Vec4::new(3.0, 6.0, 4.0, 1.0);
+    ///
+    /// assert_eq!(p, Vec4::new(1.0, 1.0, 2.0, 1.0));
+    /// ```
+    pub fn inverse(&self) -> Option<Mat4<T>> {
+        let (z, o) = (T::zero(), T::one());
+        if self.scale.iter().any(|s| *s == z) {
+            return None;
+        }
+        let column = |v: Vec3<T>, w: T| Vec4::new(v.x, v.y, v.z, w);
+        let axis = |v: Vec3<T>| column(self.inverse_transform_vector(v), z);
+        Some(Mat4::new(
+            axis(Vec3::new(o, z, z)),
+            axis(Vec3::new(z, o, z)),
+            axis(Vec3::new(z, z, o)),
+            column(self.inverse_transform_point(Vec3::new(z, z, z)), o),
+        ))
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::quaternion::Quat;
+/// # use lamar::transform::Transform;
+/// # use lamar::vector::Vec3;
+/// let parent = Transform::new(Vec3::new(1.0, 0.0, 0.0), Quat::identity(), Vec3::new(2.0, 2.0, 2.0));
+/// let child = Transform::new(Vec3::new(0.0, 1.0, 0.0), Quat::identity(), Vec3::new(1.0, 1.0, 1.0));
+///
+/// assert_eq!((parent * child).transform_point(Vec3::new(0.0, 0.0, 1.0)), Vec3::new(1.0, 2.0, 2.0));
+/// ```
+///
+/// The result applies `other` (the child) first, then `self` (the parent). It is
+/// exact when the parent's scale commutes with the child's rotation, as a
+/// non-uniformly scaled parent would otherwise shear its children.
+impl<T> Mul for Transform<T>
+where
+    T: Float,
+{
+    type Output = Transform<T>;
+
+    fn mul(self, other: Transform<T>) -> Self::Output {
+        Self::new(
+            self.transform_point(other.translation),
+            self.rotation * other.rotation,
+            self.scale.zip_with(other.scale, |a, b| a * b),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat4;
+    use crate::quaternion::Quat;
+    use crate::transform::Transform;
+    use crate::vector::{Vec3, Vec4};
+
+    fn assert_close(a: Vec3<f64>, b: Vec3<f64>) {
+        assert!((a - b).length() < 1e-9, "{} != {}", a, b);
+    }
+
+    fn assert_mat_close(a: &Mat4<f64>, b: &Mat4<f64>) {
+        for c in 0..4 {
+            assert!(
+                (a.cols[c] - b.cols[c]).length() < 1e-9,
+                "{:?} != {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    fn rotation(x: f64, y: f64, z: f64, angle: f64) -> Quat<f64> {
+        Quat::from_axis_angle(Vec3::new(x, y, z).normalize(), angle)
+    }
+
+    #[test]
+    fn to_mat4_test() {
+        let t = Transform::new(
+            Vec3::new(1.0, -2.0, 3.0),
+            rotation(1.0, 2.0, -1.0, 0.8),
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        let p = Vec3::new(0.3, 1.5, -0.7);
+        let m = t.to_mat4() * Vec4::new(p.x, p.y, p.z, 1.0);
+
+        assert_close(Vec3::new(m.x, m.y, m.z), t.transform_point(p));
+        assert_eq!(m.w, 1.0);
+        assert_mat_close(&Transform::identity().to_mat4(), &Mat4::identity());
+    }
+
+    #[test]
+    fn compose_matches_matrices_test() {
+        let parent = Transform::new(
+            Vec3::new(4.0, 0.0, -1.0),
+            rotation(0.0, 1.0, 1.0, 1.2),
+            Vec3::new(1.5, 1.5, 1.5),
+        );
+        let child = Transform::new(
+            Vec3::new(-1.0, 2.0, 0.5),
+            rotation(1.0, 0.0, 2.0, -0.4),
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        let p = Vec3::new(0.3, 1.5, -0.7);
+
+        assert_mat_close(
+            &(parent * child).to_mat4(),
+            &(parent.to_mat4() * child.to_mat4()),
+        );
+        assert_close(
+            (parent * child).transform_point(p),
+            parent.transform_point(child.transform_point(p)),
+        );
+    }
+
+    #[test]
+    fn inverse_test() {
+        // a non-uniform scale that doesn't commute with the rotation
+        let t = Transform::new(
+            Vec3::new(1.0, -2.0, 3.0),
+            rotation(1.0, 2.0, -1.0, 0.8),
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        let p = Vec3::new(0.3, 1.5, -0.7);
+        let inverse = t.inverse().unwrap();
+        let q = t.transform_point(p);
+        let back = inverse * Vec4::new(q.x, q.y, q.z, 1.0);
+
+        assert_close(Vec3::new(back.x, back.y, back.z), p);
+        assert_eq!(back.w, 1.0);
+        assert_mat_close(&(inverse * t.to_mat4()), &Mat4::identity());
+        assert_mat_close(&(t.to_mat4() * inverse), &Mat4::identity());
+        assert_mat_close(&inverse, &t.to_mat4().inverse().unwrap());
+
+        let flat = Transform::new(p, Quat::identity(), Vec3::new(1.0, 0.0, 1.0));
+        assert_eq!(flat.inverse(), None);
+    }
+
+    #[test]
+    fn inverse_transform_point_test() {
+        let t = Transform::new(
+            Vec3::new(1.0, -2.0, 3.0),
+            rotation(1.0, 2.0, -1.0, 0.8),
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        let p = Vec3::new(0.3, 1.5, -0.7);
+
+        assert_close(t.inverse_transform_point(t.transform_point(p)), p);
+        assert_close(t.transform_point(t.inverse_transform_point(p)), p);
+        assert_close(t.inverse_transform_vector(t.transform_vector(p)), p);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::vector::{Vec2, Vec3, Vec4};
+
+/// A 2D Vector of `f32`s (single precision float)
+///
+/// ```rust
+/// # use lamar::vector::{Vec2f, Vec2};
+/// let v: Vec2f = Vec2::new(1.5, 1.5);
+///
+/// assert_eq!(v.x, 1.5);
+/// ```
+pub type Vec2f = Vec2<f32>;
+
+/// A 3D Vector of `f32`s (single precision float)
+///
+/// ```rust
+/// # use lamar::vector::{Vec3f, Vec3};
+/// let v: Vec3f = Vec3::new(1.5, 1.5, 1.5);
+///
+/// assert_eq!(v.x, 1.5);
+/// ```
+pub type Vec3f = Vec3<f32>;
+
+/// A 4D Vector of `f32`s (single precision float)
+///
+/// ```rust
+/// # use lamar::vector::{Vec4f, Vec4};
+/// let v: Vec4f = Vec4::new(1.5, 1.5, 1.5, 1.5);
+///
+/// assert_eq!(v.x, 1.5);
+/// ```
+pub type Vec4f = Vec4<f32>;
+
+/// A 2D Vector of `f64`s (double precision float)
+///
+/// ```rust
+/// # use lamar::vector::{Vec2d, Vec2};
+/// let v: Vec2d = Vec2::new(1.5, 1.5);
+///
+/// assert_eq!(v.x, 1.5);
+/// ```
+pub type Vec2d = Vec2<f64>;
+
+/// A 3D Vector of `f64`s (double precision float)
+///
+/// ```rust
+/// # use lamar::vector::{Vec3d, Vec3};
+/// let v: Vec3d = Vec3::new(1.5, 1.5, 1.5);
+///
+/// assert_eq!(v.x, 1.5);
+/// ```
+pub type Vec3d = Vec3<f64>;
+
+/// A 4D Vector of `f64`s (double precision float)
+///
+/// ```rust
+/// # use lamar::vector::{Vec4d, Vec4};
+/// let v: Vec4d = Vec4::new(1.5, 1.5, 1.5, 1.5);
+///
+/// assert_eq!(v.x, 1.5);
+/// ```
+pub type Vec4d = Vec4<f64>;
+
+/// A 2D Vector of `i32`s (signed integer)
+///
+/// ```rust
+/// # use lamar::vector::{IVec2, Vec2};
+/// let v: IVec2 = Vec2::new(3, 3);
+///
+/// assert_eq!(v.x, 3);
+/// ```
+pub type IVec2 = Vec2<i32>;
+
+/// A 3D Vector of `i32`s (signed integer)
+///
+/// ```rust
+/// # use lamar::vector::{IVec3, Vec3};
+/// let v: IVec3 = Vec3::new(3, 3, 3);
+///
+/// assert_eq!(v.x, 3);
+/// ```
+pub type IVec3 = Vec3<i32>;
+
+/// A 4D Vector of `i32`s (signed integer)
+///
+/// ```rust
+/// # use lamar::vector::{IVec4, Vec4};
+/// let v: IVec4 = Vec4::new(3, 3, 3, 3);
+///
+/// assert_eq!(v.x, 3);
+/// ```
+pub type IVec4 = Vec4<i32>;
+
+/// A 2D Vector of `u32`s (unsigned integer)
+///
+/// ```rust
+/// # use lamar::vector::{UVec2, Vec2};
+/// let v: UVec2 = Vec2::new(3, 3);
+///
+/// assert_eq!(v.x, 3);
+/// ```
+pub type UVec2 = Vec2<u32>;
+
+/// A 3D Vector of `u32`s (unsigned integer)
+///
+/// ```rust
+/// # use lamar::vector::{UVec3, Vec3};
+/// let v: UVec3 = Vec3::new(3, 3, 3);
+///
+/// assert_eq!(v.x, 3);
+/// ```
+pub type UVec3 = Vec3<u32>;
+
+/// A 4D Vector of `u32`s (unsigned integer)
+///
+/// ```rust
+/// # use lamar::vector::{UVec4, Vec4};
+/// let v: UVec4 = Vec4::new(3, 3, 3, 3);
+///
+/// assert_eq!(v.x, 3);
+/// ```
+pub type UVec4 = Vec4<u32>;
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{IVec2, UVec4, Vec2, Vec3, Vec3d, Vec3f, Vec4};
+
+    #[test]
+    fn aliases_test() {
+        let a: Vec3f = Vec3::new(1.0, 2.0, 2.0);
+        assert_eq!(a.length(), 3.0);
+        assert_eq!(a.quantize(1.5), Vec3::new(0, 1, 1));
+
+        let b = Vec3d::new(0.0, 0.0, 2.0);
+        assert_eq!(b.normalize(), Vec3::new(0.0, 0.0, 1.0f64));
+
+        let c: IVec2 = Vec2::new(3, -4) + IVec2::new(1, 1);
+        assert_eq!(c.rotate_fixed(0, 1 << 16), Vec2::new(4, -3));
+
+        let d: Vec4<u32> = UVec4::new(1, 2, 3, 4);
+        assert_eq!(d.checked_sub(&UVec4::new(2, 0, 0, 0)), None);
+    }
+}
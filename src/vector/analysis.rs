@@ -0,0 +1,201 @@
+use crate::vector::Vec3;
+
+/// Return the Gram matrix of a set of 3D Vectors
+///
+/// Entry `(i, j)` is `vecs[i].dot(&vecs[j])`, so the result is symmetric.
+/// The vectors are linearly independent exactly when its determinant is non-zero.
+pub fn gram_matrix(vecs: &[Vec3<f32>]) -> Vec<Vec<f32>> {
+    let mut gram = vec![vec![0.0; vecs.len()]; vecs.len()];
+    for i in 0..vecs.len() {
+        for j in i..vecs.len() {
+            let d = vecs[i].dot(&vecs[j]);
+            gram[i][j] = d;
+            gram[j][i] = d;
+        }
+    }
+    gram
+}
+
+/// Return the covariance matrix of a set of points, as rows
+fn covariance(points: &[Vec3<f32>]) -> [[f32; 3]; 3] {
+    let mut cov = [[0.0; 3]; 3];
+    let mean = match Vec3::centroid(points) {
+        Some(mean) => mean,
+        None => return cov,
+    };
+    for p in points {
+        let d: [f32; 3] = (*p - mean).into();
+        for i in 0..3 {
+            for j in 0..3 {
+                cov[i][j] += d[i] * d[j];
+            }
+        }
+    }
+    let n = points.len() as f32;
+    cov.map(|row| row.map(|c| c / n))
+}
+
+/// Return the direction along which a set of points is most spread out
+///
+/// This is the dominant eigenvector of the points' covariance matrix, found by
+/// power iteration, and is the main axis of an oriented bounding box. The result
+/// is unit length, with its largest component made positive so the sign is stable.
+/// If the points have no spread (fewer than two distinct points) the X axis is
+/// returned.
+pub fn principal_axis(points: &[Vec3<f32>]) -> Vec3<f32> {
+    let cov = covariance(points);
+    let apply = |v: Vec3<f32>| {
+        Vec3::new(
+            Vec3::from(cov[0]).dot(&v),
+            Vec3::from(cov[1]).dot(&v),
+            Vec3::from(cov[2]).dot(&v),
+        )
+    };
+
+    // start from the widest column, which can't be orthogonal to the dominant axis
+    let mut axis = (0..3)
+        .map(|i| Vec3::new(cov[0][i], cov[1][i], cov[2][i]))
+        .fold(Vec3::zero(), |best, c| {
+            if c.length_squared() > best.length_squared() {
+                c
+            } else {
+                best
+            }
+        });
+    if axis.length_squared() <= f32::EPSILON * f32::EPSILON {
+        return Vec3::new(1.0, 0.0, 0.0);
+    }
+    axis = axis.normalize();
+
+    for _ in 0..64 {
+        let next = apply(axis);
+        if next.length_squared() == 0.0 {
+            break;
+        }
+        let next = next.normalize();
+        let converged = (next - axis).length_squared() < 1e-14;
+        axis = next;
+        if converged {
+            break;
+        }
+    }
+
+    let largest = axis.iter().fold(
+        0.0f32,
+        |best, &c| if c.abs() > best.abs() { c } else { best },
+    );
+    if largest < 0.0 {
+        axis * -1.0
+    } else {
+        axis
+    }
+}
+
+/// Return the index of the point closest to `query`, or `None` if `points` is empty
+///
+/// A brute force search by squared distance. Ties keep the earliest point.
+pub fn nearest_neighbor(query: &Vec3<f32>, points: &[Vec3<f32>]) -> Option<usize> {
+    let mut best: Option<(usize, f32)> = None;
+    for (i, p) in points.iter().enumerate() {
+        let d = (*p - *query).length_squared();
+        match best {
+            Some((_, best_d)) if best_d <= d => {}
+            _ => best = Some((i, d)),
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{gram_matrix, nearest_neighbor, principal_axis, Vec3};
+
+    #[test]
+    fn gram_matrix_orthonormal_test() {
+        let s = std::f32::consts::FRAC_1_SQRT_2;
+        let basis = [
+            Vec3::new(s, s, 0.0),
+            Vec3::new(-s, s, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        ];
+        let gram = gram_matrix(&basis);
+
+        for (i, row) in gram.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((value - expected).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn gram_matrix_symmetric_test() {
+        let vecs = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(4.0, 5.0, 6.0),
+            Vec3::new(2.0, 4.0, 6.0),
+        ];
+        let gram = gram_matrix(&vecs);
+
+        assert_eq!(gram[0][1], 32.0);
+        assert_eq!(gram[1][0], 32.0);
+        assert_eq!(gram[2][2], 56.0);
+        assert!(gram_matrix(&[]).is_empty());
+    }
+
+    #[test]
+    fn principal_axis_test() {
+        // spread mainly along (1, 1, 0), with a little noise across it
+        let dir = Vec3::new(1.0, 1.0, 0.0).normalize();
+        let across = Vec3::new(0.0, 0.0, 1.0);
+        let points: Vec<Vec3<f32>> = (-10..=10)
+            .map(|i| {
+                let wobble = if i % 2 == 0 { 0.3 } else { -0.3 };
+                Vec3::new(2.0, -1.0, 5.0) + dir * i as f32 + across * wobble
+            })
+            .collect();
+
+        let axis = principal_axis(&points);
+        assert!((axis.length() - 1.0).abs() < 1e-5);
+        assert!((axis - dir).length() < 1e-3);
+
+        let mut flipped = points.clone();
+        flipped.reverse();
+        assert!((principal_axis(&flipped) - axis).length() < 1e-5);
+    }
+
+    #[test]
+    fn principal_axis_degenerate_test() {
+        assert_eq!(principal_axis(&[]), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(
+            principal_axis(&[Vec3::new(2.0, 2.0, 2.0); 4]),
+            Vec3::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            principal_axis(&[Vec3::new(0.0, -3.0, 0.0), Vec3::new(0.0, 5.0, 0.0)]),
+            Vec3::new(0.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn nearest_neighbor_test() {
+        let points = [
+            Vec3::new(5.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(0.0, 0.0, -2.0),
+            Vec3::new(1.0, 1.0, 1.0),
+        ];
+
+        assert_eq!(
+            nearest_neighbor(&Vec3::new(4.0, 0.0, 0.0), &points),
+            Some(0)
+        );
+        assert_eq!(
+            nearest_neighbor(&Vec3::new(0.9, 1.2, 0.8), &points),
+            Some(3)
+        );
+        // (0, 2, 0) and (0, 0, -2) are equally far, so the first wins
+        assert_eq!(nearest_neighbor(&Vec3::zero(), &points[..3]), Some(1));
+        assert_eq!(nearest_neighbor(&Vec3::zero(), &[]), None);
+    }
+}
@@ -0,0 +1,344 @@
+use crate::matrix::Mat4;
+use crate::vector::Vec3;
+use num::{Float, Num};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::{error::Error, fmt::Display, iter::Sum, ops::Div};
+
+/// The error returned when two slices that should pair up have different lengths.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SliceLengthError {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl Display for SliceLengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "slice lengths differ: {} and {}", self.left, self.right)
+    }
+}
+
+impl Error for SliceLengthError {}
+
+/// The per-element loops behind the batch functions.
+///
+/// These take already validated slices. The `f32` kernels work on four vectors
+/// at a time through `crate::simd`, so they pick up the accelerated backend when
+/// the `simd` feature is on.
+mod kernels {
+    use crate::matrix::Mat4;
+    use crate::simd::{self, Lanes};
+    use crate::vector::{Vec3, Vec4};
+    use num::Num;
+
+    /// Split four vectors into one set of lanes per component
+    fn gather(c: &[Vec3<f32>]) -> [Lanes; 3] {
+        [
+            [c[0].x, c[1].x, c[2].x, c[3].x],
+            [c[0].y, c[1].y, c[2].y, c[3].y],
+            [c[0].z, c[1].z, c[2].z, c[3].z],
+        ]
+    }
+
+    fn scatter([x, y, z]: [Lanes; 3], chunk: &mut [Vec3<f32>]) {
+        for (i, p) in chunk.iter_mut().enumerate() {
+            *p = Vec3::new(x[i], y[i], z[i]);
+        }
+    }
+
+    pub(super) fn normalize(points: &mut [Vec3<f32>]) {
+        let mut chunks = points.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let [x, y, z] = gather(chunk);
+            // the same operations, in the same order, as `Vec3::normalize`
+            let length = simd::sqrt(simd::add(
+                simd::add(simd::mul(x, x), simd::mul(y, y)),
+                simd::mul(z, z),
+            ));
+            scatter(
+                [
+                    simd::div(x, length),
+                    simd::div(y, length),
+                    simd::div(z, length),
+                ],
+                chunk,
+            );
+        }
+        for p in chunks.into_remainder() {
+            *p = p.normalize();
+        }
+    }
+
+    pub(super) fn transform_points(matrix: &Mat4<f32>, points: &mut [Vec3<f32>]) {
+        let [c0, c1, c2, c3]: [[f32; 4]; 4] = matrix.cols.map(|c| c.into());
+        let mut chunks = points.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let [x, y, z] = gather(chunk);
+            // each output row, summed in the same order as `Mat4 * Vec4`
+            let row = |r: usize| {
+                let sum = simd::add(
+                    simd::add(simd::mul([c0[r]; 4], x), simd::mul([c1[r]; 4], y)),
+                    simd::mul([c2[r]; 4], z),
+                );
+                simd::add(sum, [c3[r]; 4])
+            };
+            let w = row(3);
+            scatter(
+                [
+                    simd::div(row(0), w),
+                    simd::div(row(1), w),
+                    simd::div(row(2), w),
+                ],
+                chunk,
+            );
+        }
+        for p in chunks.into_remainder() {
+            let v = *matrix * Vec4::new(p.x, p.y, p.z, 1.0);
+            *p = Vec3::new(v.x, v.y, v.z) / v.w;
+        }
+    }
+
+    pub(super) fn add_assign<T>(points: &mut [Vec3<T>], offset: Vec3<T>)
+    where
+        T: Num + Copy,
+    {
+        for p in points {
+            *p = *p + offset;
+        }
+    }
+
+    pub(super) fn dot_pairs<T>(a: &[Vec3<T>], b: &[Vec3<T>], out: &mut Vec<T>)
+    where
+        T: Num + Copy,
+    {
+        out.extend(a.iter().zip(b).map(|(a, b)| a.dot(b)));
+    }
+}
+
+/// Normalize every vector in the slice, in place
+///
+/// Gives exactly the same results as `Vec3::normalize`, so zero length vectors
+/// become NaN.
+pub fn normalize_slice(points: &mut [Vec3<f32>]) {
+    kernels::normalize(points);
+}
+
+/// Transform every point in the slice by `matrix`, in place
+///
+/// Each point is treated as `(x, y, z, 1)`, and the result is divided by `w`, so
+/// projective matrices work as well as affine ones. Gives exactly the same
+/// results as multiplying each point by the matrix.
+pub fn transform_points(matrix: &Mat4<f32>, points: &mut [Vec3<f32>]) {
+    kernels::transform_points(matrix, points);
+}
+
+/// Add `offset` to every vector in the slice, in place
+pub fn add_assign_slice<T>(points: &mut [Vec3<T>], offset: Vec3<T>)
+where
+    T: Num + Copy,
+{
+    kernels::add_assign(points, offset);
+}
+
+/// Return the dot product of each pair `a[i].dot(&b[i])`
+///
+/// Returns an error if the slices have different lengths.
+pub fn dot_pairs<T>(a: &[Vec3<T>], b: &[Vec3<T>]) -> Result<Vec<T>, SliceLengthError>
+where
+    T: Num + Copy,
+{
+    if a.len() != b.len() {
+        return Err(SliceLengthError {
+            left: a.len(),
+            right: b.len(),
+        });
+    }
+    let mut out = Vec::with_capacity(a.len());
+    kernels::dot_pairs(a, b, &mut out);
+    Ok(out)
+}
+
+/// Return the average of a slice of vectors (of any size), or `None` if it is empty
+///
+/// The same as the `centroid` associated function on each vector type, for use
+/// in code that is generic over the vector.
+pub fn centroid<T, V>(points: &[V]) -> Option<V>
+where
+    T: Float,
+    V: Copy + Sum<V> + Div<T, Output = V>,
+{
+    if points.is_empty() {
+        return None;
+    }
+    let sum: V = points.iter().copied().sum();
+    Some(sum / T::from(points.len())?)
+}
+
+/// How many elements each parallel task hands to a kernel
+#[cfg(feature = "rayon")]
+const PAR_CHUNK: usize = 1024;
+
+/// Normalize every vector in the slice in parallel, like `normalize_slice`
+///
+/// Requires the `rayon` feature. The result is identical to `normalize_slice`.
+#[cfg(feature = "rayon")]
+pub fn par_normalize_slice(points: &mut [Vec3<f32>]) {
+    points
+        .par_chunks_mut(PAR_CHUNK)
+        .for_each(kernels::normalize);
+}
+
+/// Transform every point in the slice in parallel, like `transform_points`
+///
+/// Requires the `rayon` feature. The result is identical to `transform_points`.
+#[cfg(feature = "rayon")]
+pub fn par_transform_points(matrix: &Mat4<f32>, points: &mut [Vec3<f32>]) {
+    points
+        .par_chunks_mut(PAR_CHUNK)
+        .for_each(|chunk| kernels::transform_points(matrix, chunk));
+}
+
+/// Return the average of a slice of vectors computed in parallel, like `centroid`
+///
+/// Requires the `rayon` feature. The parallel sum adds the points in a different
+/// order, so the result may differ from `centroid` in the last few ULPs.
+#[cfg(feature = "rayon")]
+pub fn par_centroid<T, V>(points: &[V]) -> Option<V>
+where
+    T: Float,
+    V: Copy + Send + Sync + Sum<V> + Div<T, Output = V>,
+{
+    if points.is_empty() {
+        return None;
+    }
+    let sum: V = points.par_iter().copied().sum();
+    Some(sum / T::from(points.len())?)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat4;
+    use crate::test_rng::TestRng;
+    use crate::vector::{
+        add_assign_slice, centroid, dot_pairs, normalize_slice, transform_points, SliceLengthError,
+        Vec2, Vec3, Vec4,
+    };
+
+    /// A few thousand reproducible pseudo-random points in `[-100, 100)`
+    fn scattered_points() -> Vec<Vec3<f32>> {
+        let mut rng = TestRng::new(0x2545_f491);
+        let mut next = move || rng.range(-100.0, 100.0) as f32;
+        (0..5000)
+            .map(|_| Vec3::new(next(), next(), next()))
+            .collect()
+    }
+
+    #[test]
+    fn normalize_slice_test() {
+        let mut points = [Vec3::new(3.0, 0.0, 0.0), Vec3::new(0.0, -2.0, 0.0)];
+        normalize_slice(&mut points);
+
+        assert_eq!(
+            points,
+            [Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, -1.0, 0.0)]
+        );
+        normalize_slice(&mut []);
+    }
+
+    #[test]
+    fn add_assign_slice_test() {
+        let mut points = [Vec3::new(1, 2, 3), Vec3::new(-1, 0, 1)];
+        add_assign_slice(&mut points, Vec3::new(10, 20, 30));
+
+        assert_eq!(points, [Vec3::new(11, 22, 33), Vec3::new(9, 20, 31)]);
+
+        let mut empty: [Vec3<i32>; 0] = [];
+        add_assign_slice(&mut empty, Vec3::new(1, 1, 1));
+    }
+
+    #[test]
+    fn dot_pairs_test() {
+        let a = [Vec3::new(1, 2, 3), Vec3::new(1, 0, 0)];
+        let b = [Vec3::new(4, 5, 6), Vec3::new(0, 1, 0)];
+
+        assert_eq!(dot_pairs(&a, &b), Ok(vec![32, 0]));
+        assert_eq!(dot_pairs::<i32>(&[], &[]), Ok(vec![]));
+        assert_eq!(
+            dot_pairs(&a, &b[..1]),
+            Err(SliceLengthError { left: 2, right: 1 })
+        );
+        assert_eq!(
+            SliceLengthError { left: 2, right: 1 }.to_string(),
+            "slice lengths differ: 2 and 1"
+        );
+    }
+
+    #[test]
+    fn centroid_test() {
+        let triangle = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(0.0, 6.0),
+        ];
+        assert_eq!(centroid(&triangle), Some(Vec2::new(1.0, 2.0)));
+        assert_eq!(centroid(&triangle), Vec2::centroid(&triangle));
+
+        let points = [Vec4::new(1.0, 2.0, 3.0, 4.0), Vec4::new(3.0, 2.0, 1.0, 0.0)];
+        assert_eq!(centroid(&points), Some(Vec4::new(2.0, 2.0, 2.0, 2.0)));
+        assert_eq!(centroid::<f32, Vec3<f32>>(&[]), None);
+    }
+
+    #[test]
+    fn transform_points_test() {
+        let matrix =
+            Mat4::translation(Vec3::new(1.0, 2.0, 3.0)) * Mat4::scale(Vec3::new(2.0, 2.0, 2.0));
+        let mut points = [Vec3::new(1.0, 0.0, -1.0), Vec3::zero()];
+        transform_points(&matrix, &mut points);
+
+        assert_eq!(points, [Vec3::new(3.0, 2.0, 1.0), Vec3::new(1.0, 2.0, 3.0)]);
+        transform_points(&matrix, &mut []);
+    }
+
+    #[test]
+    fn kernels_match_per_vector_test() {
+        let points = scattered_points();
+        let matrix = Mat4::perspective(1.0, 1.5, 0.1, 100.0)
+            * Mat4::translation(Vec3::new(0.0, 0.0, -300.0));
+
+        let mut normalized = points.clone();
+        normalize_slice(&mut normalized);
+        let mut transformed = points.clone();
+        transform_points(&matrix, &mut transformed);
+
+        for (i, p) in points.iter().enumerate() {
+            assert_eq!(normalized[i], p.normalize());
+            let v = matrix * Vec4::new(p.x, p.y, p.z, 1.0);
+            assert_eq!(transformed[i], Vec3::new(v.x, v.y, v.z) / v.w);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_matches_sequential_test() {
+        use crate::vector::{par_centroid, par_normalize_slice, par_transform_points};
+
+        let points = scattered_points();
+
+        let (mut seq, mut par) = (points.clone(), points.clone());
+        normalize_slice(&mut seq);
+        par_normalize_slice(&mut par);
+        assert_eq!(seq, par);
+
+        let matrix = Mat4::perspective(1.0, 1.5, 0.1, 100.0)
+            * Mat4::translation(Vec3::new(0.0, 0.0, -300.0));
+        let (mut seq, mut par) = (points.clone(), points.clone());
+        transform_points(&matrix, &mut seq);
+        par_transform_points(&matrix, &mut par);
+        assert_eq!(seq, par);
+
+        let seq = centroid(&points).unwrap();
+        let par = par_centroid(&points).unwrap();
+        assert!((seq - par).length() < 1e-3);
+        assert_eq!(par_centroid::<f32, Vec3<f32>>(&[]), None);
+    }
+}
@@ -0,0 +1,222 @@
+use std::ops::{BitAnd, BitOr, Not};
+
+/// A 2D boolean mask, produced by component-wise vector comparisons.
+///
+/// ```rust
+/// # use lamar::vector::{BVec2, Vec2};
+/// let mask = Vec2::new(1, 1).cmplt(&Vec2::new(2, 0));
+///
+/// assert_eq!(mask, BVec2::new(true, false));
+/// assert!(mask.any() && !mask.all());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct BVec2 {
+    pub x: bool,
+    pub y: bool,
+}
+
+impl BVec2 {
+    /// Create a 2D mask from its components
+    pub fn new(x: bool, y: bool) -> Self {
+        Self { x, y }
+    }
+
+    /// Create a 2D mask with every component set to `value`
+    pub fn splat(value: bool) -> Self {
+        Self::new(value, value)
+    }
+
+    /// Return whether any component is set
+    pub fn any(&self) -> bool {
+        self.x || self.y
+    }
+
+    /// Return whether every component is set
+    pub fn all(&self) -> bool {
+        self.x && self.y
+    }
+}
+
+impl BitAnd for BVec2 {
+    type Output = BVec2;
+
+    fn bitand(self, rhs: BVec2) -> Self::Output {
+        BVec2::new(self.x && rhs.x, self.y && rhs.y)
+    }
+}
+
+impl BitOr for BVec2 {
+    type Output = BVec2;
+
+    fn bitor(self, rhs: BVec2) -> Self::Output {
+        BVec2::new(self.x || rhs.x, self.y || rhs.y)
+    }
+}
+
+impl Not for BVec2 {
+    type Output = BVec2;
+
+    fn not(self) -> Self::Output {
+        BVec2::new(!self.x, !self.y)
+    }
+}
+
+/// A 3D boolean mask, produced by component-wise vector comparisons.
+///
+/// ```rust
+/// # use lamar::vector::{BVec3, Vec3};
+/// let mask = Vec3::new(1, 1, 1).cmplt(&Vec3::new(2, 0, 0));
+///
+/// assert_eq!(mask, BVec3::new(true, false, false));
+/// assert!(mask.any() && !mask.all());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct BVec3 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+}
+
+impl BVec3 {
+    /// Create a 3D mask from its components
+    pub fn new(x: bool, y: bool, z: bool) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Create a 3D mask with every component set to `value`
+    pub fn splat(value: bool) -> Self {
+        Self::new(value, value, value)
+    }
+
+    /// Return whether any component is set
+    pub fn any(&self) -> bool {
+        self.x || self.y || self.z
+    }
+
+    /// Return whether every component is set
+    pub fn all(&self) -> bool {
+        self.x && self.y && self.z
+    }
+}
+
+impl BitAnd for BVec3 {
+    type Output = BVec3;
+
+    fn bitand(self, rhs: BVec3) -> Self::Output {
+        BVec3::new(self.x && rhs.x, self.y && rhs.y, self.z && rhs.z)
+    }
+}
+
+impl BitOr for BVec3 {
+    type Output = BVec3;
+
+    fn bitor(self, rhs: BVec3) -> Self::Output {
+        BVec3::new(self.x || rhs.x, self.y || rhs.y, self.z || rhs.z)
+    }
+}
+
+impl Not for BVec3 {
+    type Output = BVec3;
+
+    fn not(self) -> Self::Output {
+        BVec3::new(!self.x, !self.y, !self.z)
+    }
+}
+
+/// A 4D boolean mask, produced by component-wise vector comparisons.
+///
+/// ```rust
+/// # use lamar::vector::{BVec4, Vec4};
+/// let mask = Vec4::new(1, 1, 1, 1).cmplt(&Vec4::new(2, 0, 0, 0));
+///
+/// assert_eq!(mask, BVec4::new(true, false, false, false));
+/// assert!(mask.any() && !mask.all());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct BVec4 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+    pub w: bool,
+}
+
+impl BVec4 {
+    /// Create a 4D mask from its components
+    pub fn new(x: bool, y: bool, z: bool, w: bool) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Create a 4D mask with every component set to `value`
+    pub fn splat(value: bool) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    /// Return whether any component is set
+    pub fn any(&self) -> bool {
+        self.x || self.y || self.z || self.w
+    }
+
+    /// Return whether every component is set
+    pub fn all(&self) -> bool {
+        self.x && self.y && self.z && self.w
+    }
+}
+
+impl BitAnd for BVec4 {
+    type Output = BVec4;
+
+    fn bitand(self, rhs: BVec4) -> Self::Output {
+        BVec4::new(
+            self.x && rhs.x,
+            self.y && rhs.y,
+            self.z && rhs.z,
+            self.w && rhs.w,
+        )
+    }
+}
+
+impl BitOr for BVec4 {
+    type Output = BVec4;
+
+    fn bitor(self, rhs: BVec4) -> Self::Output {
+        BVec4::new(
+            self.x || rhs.x,
+            self.y || rhs.y,
+            self.z || rhs.z,
+            self.w || rhs.w,
+        )
+    }
+}
+
+impl Not for BVec4 {
+    type Output = BVec4;
+
+    fn not(self) -> Self::Output {
+        BVec4::new(!self.x, !self.y, !self.z, !self.w)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{BVec2, BVec3, BVec4};
+
+    #[test]
+    fn any_all_test() {
+        assert!(BVec2::new(false, true).any());
+        assert!(!BVec2::new(false, true).all());
+        assert!(BVec3::splat(true).all());
+        assert!(!BVec3::default().any());
+        assert!(BVec4::new(true, true, true, true).all());
+        assert!(!BVec4::new(true, true, false, true).all());
+    }
+
+    #[test]
+    fn logic_ops_test() {
+        let a = BVec3::new(true, true, false);
+        let b = BVec3::new(true, false, false);
+
+        assert_eq!(a & b, BVec3::new(true, false, false));
+        assert_eq!(a | b, BVec3::new(true, true, false));
+        assert_eq!(!a, BVec3::new(false, false, true));
+    }
+}
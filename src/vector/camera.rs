@@ -0,0 +1,58 @@
+use crate::vector::Vec3;
+use std::f32::consts::FRAC_PI_2;
+
+/// How close (in radians) `orbit_position` lets the pitch get to straight up or down
+const POLE_MARGIN: f32 = 1e-3;
+
+/// Return the eye position of an orbit camera circling `target`
+///
+/// Uses a Y-up convention with angles in radians. With zero `yaw` and `pitch` the
+/// camera sits `distance` along +Z from the target (looking down -Z, as with
+/// `Mat4::look_at`). Positive `yaw` swings it around +Y towards +X, and positive
+/// `pitch` raises it above the target.
+///
+/// `pitch` is clamped just short of ±pi/2, so the view direction never lines up
+/// with the up axis (which would break `look_at`).
+pub fn orbit_position(target: Vec3<f32>, distance: f32, yaw: f32, pitch: f32) -> Vec3<f32> {
+    let pitch = pitch.clamp(-FRAC_PI_2 + POLE_MARGIN, FRAC_PI_2 - POLE_MARGIN);
+    let (sin_yaw, cos_yaw) = yaw.sin_cos();
+    let (sin_pitch, cos_pitch) = pitch.sin_cos();
+
+    target + Vec3::new(cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw) * distance
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{orbit_position, Vec3};
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn orbit_position_test() {
+        let target = Vec3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(
+            orbit_position(target, 5.0, 0.0, 0.0),
+            Vec3::new(1.0, 2.0, 8.0)
+        );
+
+        let right = orbit_position(target, 5.0, FRAC_PI_2, 0.0);
+        assert!((right - Vec3::new(6.0, 2.0, 3.0)).length() < 1e-5);
+
+        let raised = orbit_position(Vec3::zero(), 2.0, 0.0, FRAC_PI_4);
+        assert!((raised - Vec3::new(0.0, 2.0f32.sqrt(), 2.0f32.sqrt())).length() < 1e-5);
+    }
+
+    #[test]
+    fn orbit_position_pitch_clamp_test() {
+        let target = Vec3::zero();
+
+        for pitch in [FRAC_PI_2, PI, -FRAC_PI_2, -PI] {
+            let eye = orbit_position(target, 1.0, 0.0, pitch);
+
+            assert!((eye.length() - 1.0).abs() < 1e-5);
+            // never directly above or below the target
+            assert!(eye.z > 0.0);
+            assert!(eye.y.abs() < 1.0);
+        }
+    }
+}
@@ -0,0 +1,463 @@
+use crate::vector::{Vec2, Vec3, Vec4};
+use num::Float;
+use std::ops::{Add, Mul, Sub};
+
+/// Convert a constant to the scalar type
+fn lit<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// Evaluate the quadratic Bezier curve with control points `p0, p1, p2` at `t` in `[0, 1]`
+///
+/// `(1 - t)²p0 + 2(1 - t)t p1 + t²p2`
+///
+/// Like the other curve functions, this works on any vector type (`Vec2`, `Vec3`,
+/// `Vec4`) or on plain floats.
+///
+/// ```rust
+/// # use lamar::vector::{quadratic_bezier, Vec2};
+/// let p = quadratic_bezier(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0), 0.5);
+///
+/// assert_eq!(p, Vec2::new(1.0, 1.0));
+/// ```
+pub fn quadratic_bezier<V, T>(p0: V, p1: V, p2: V, t: T) -> V
+where
+    T: Float,
+    V: Copy + Add<Output = V> + Mul<T, Output = V>,
+{
+    let s = T::one() - t;
+    p0 * (s * s) + p1 * (lit::<T>(2.0) * s * t) + p2 * (t * t)
+}
+
+/// Return the derivative of `quadratic_bezier` with respect to `t`
+///
+/// `2(1 - t)(p1 - p0) + 2t(p2 - p1)`
+pub fn quadratic_bezier_tangent<V, T>(p0: V, p1: V, p2: V, t: T) -> V
+where
+    T: Float,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<T, Output = V>,
+{
+    let two = lit::<T>(2.0);
+    (p1 - p0) * (two * (T::one() - t)) + (p2 - p1) * (two * t)
+}
+
+/// Evaluate the cubic Bezier curve with control points `p0, p1, p2, p3` at `t` in `[0, 1]`
+///
+/// `(1 - t)³p0 + 3(1 - t)²t p1 + 3(1 - t)t²p2 + t³p3`
+pub fn cubic_bezier<V, T>(p0: V, p1: V, p2: V, p3: V, t: T) -> V
+where
+    T: Float,
+    V: Copy + Add<Output = V> + Mul<T, Output = V>,
+{
+    let s = T::one() - t;
+    let three = lit::<T>(3.0);
+    p0 * (s * s * s) + p1 * (three * s * s * t) + p2 * (three * s * t * t) + p3 * (t * t * t)
+}
+
+/// Return the derivative of `cubic_bezier` with respect to `t`
+///
+/// `3(1 - t)²(p1 - p0) + 6(1 - t)t(p2 - p1) + 3t²(p3 - p2)`
+pub fn cubic_bezier_tangent<V, T>(p0: V, p1: V, p2: V, p3: V, t: T) -> V
+where
+    T: Float,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<T, Output = V>,
+{
+    let s = T::one() - t;
+    let three = lit::<T>(3.0);
+    (p1 - p0) * (three * s * s) + (p2 - p1) * (lit::<T>(6.0) * s * t) + (p3 - p2) * (three * t * t)
+}
+
+/// Evaluate the uniform Catmull-Rom segment between `p1` and `p2` at `t` in `[0, 1]`
+///
+/// `p0` and `p3` are the neighbouring points, which set the tangents at `p1`
+/// (`(p2 - p0) / 2`) and `p2` (`(p3 - p1) / 2`).
+pub fn catmull_rom<V, T>(p0: V, p1: V, p2: V, p3: V, t: T) -> V
+where
+    T: Float,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<T, Output = V>,
+{
+    let (t2, t3) = (t * t, t * t * t);
+    let (two, three) = (lit::<T>(2.0), lit::<T>(3.0));
+    (p1 * two
+        + (p2 - p0) * t
+        + (p0 * two - p1 * lit(5.0) + p2 * lit(4.0) - p3) * t2
+        + (p1 * three - p0 - p2 * three + p3) * t3)
+        * lit(0.5)
+}
+
+/// Return the derivative of `catmull_rom` with respect to `t`
+pub fn catmull_rom_tangent<V, T>(p0: V, p1: V, p2: V, p3: V, t: T) -> V
+where
+    T: Float,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<T, Output = V>,
+{
+    let (two, three) = (lit::<T>(2.0), lit::<T>(3.0));
+    ((p2 - p0)
+        + (p0 * two - p1 * lit(5.0) + p2 * lit(4.0) - p3) * (two * t)
+        + (p1 * three - p0 - p2 * three + p3) * (three * t * t))
+        * lit(0.5)
+}
+
+/// Interpolate as `a(1 - t) + bt`, which is exactly `a` at `t = 0` and `b` at `t = 1`
+fn mix<V, T>(a: V, b: V, t: T) -> V
+where
+    T: Float,
+    V: Add<Output = V> + Mul<T, Output = V>,
+{
+    a * (T::one() - t) + b * t
+}
+
+macro_rules! curve_fns {
+    ($($vec:ident),+) => {
+        $(
+            impl<T> $vec<T>
+            where
+                T: Float,
+            {
+                /// Evaluate the quadratic Bezier curve with control points `p0, p1, p2` at `t`
+                ///
+                /// Uses de Casteljau's repeated interpolation, which is numerically stable
+                /// and passes exactly through `p0` at `t = 0` and `p2` at `t = 1`. See also
+                /// `quadratic_bezier`, which works on any vector type.
+                pub fn bezier_quadratic(p0: Self, p1: Self, p2: Self, t: T) -> Self {
+                    mix(mix(p0, p1, t), mix(p1, p2, t), t)
+                }
+
+                /// Evaluate the cubic Bezier curve with control points `p0, p1, p2, p3` at `t`
+                ///
+                /// Like `bezier_quadratic`, this uses de Casteljau's algorithm and is exact
+                /// at the end points.
+                pub fn bezier_cubic(p0: Self, p1: Self, p2: Self, p3: Self, t: T) -> Self {
+                    let (a, b, c) = (mix(p0, p1, t), mix(p1, p2, t), mix(p2, p3, t));
+                    mix(mix(a, b, t), mix(b, c, t), t)
+                }
+
+                /// Evaluate the uniform Catmull-Rom segment between `p1` and `p2` at `t`
+                ///
+                /// `p0` and `p3` are the neighbouring points, which set the tangents at
+                /// `p1` and `p2`. Uses the Barry-Goldman pyramid of interpolations, so
+                /// the curve passes exactly through `p1` at `t = 0` and `p2` at `t = 1`.
+                /// See also `catmull_rom`, which works on any vector type.
+                pub fn catmull_rom(p0: Self, p1: Self, p2: Self, p3: Self, t: T) -> Self {
+                    let (o, two) = (T::one(), T::one() + T::one());
+                    let (a, b, c) = (mix(p0, p1, t + o), mix(p1, p2, t), mix(p2, p3, t - o));
+                    mix(mix(a, b, (t + o) / two), mix(b, c, t / two), t)
+                }
+            }
+        )+
+    };
+}
+
+curve_fns!(Vec2, Vec3, Vec4);
+
+/// A cubic Bezier curve, for following a path by its parameter or its length.
+///
+/// ```rust
+/// # use lamar::vector::{CubicBezier, Vec2};
+/// let curve = CubicBezier::new(
+///     Vec2::new(0.0_f64, 0.0),
+///     Vec2::new(1.0, 0.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(3.0, 0.0),
+/// );
+///
+/// assert_eq!(curve.eval(0.5), Vec2::new(1.5, 0.0));
+/// assert!((curve.arc_length(16) - 3.0).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CubicBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+    pub p3: V,
+}
+
+impl<V> CubicBezier<V>
+where
+    V: Copy,
+{
+    /// Create a cubic Bezier curve from its 4 control points
+    pub fn new(p0: V, p1: V, p2: V, p3: V) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+
+    /// Evaluate the curve at `t` in `[0, 1]`
+    pub fn eval<T>(&self, t: T) -> V
+    where
+        T: Float,
+        V: Add<Output = V> + Mul<T, Output = V>,
+    {
+        cubic_bezier(self.p0, self.p1, self.p2, self.p3, t)
+    }
+
+    /// Return the derivative of the curve at `t` in `[0, 1]`
+    pub fn tangent<T>(&self, t: T) -> V
+    where
+        T: Float,
+        V: Add<Output = V> + Sub<Output = V> + Mul<T, Output = V>,
+    {
+        cubic_bezier_tangent(self.p0, self.p1, self.p2, self.p3, t)
+    }
+
+    /// Return the length of the curve sampled as `segments` straight lines
+    fn polyline_length<T, F>(&self, segments: usize, distance: F) -> T
+    where
+        T: Float,
+        V: Add<Output = V> + Mul<T, Output = V>,
+        F: Fn(&V, &V) -> T,
+    {
+        let n = T::from(segments.max(1)).unwrap();
+        let mut previous = self.p0;
+        let mut length = T::zero();
+        for i in 1..=segments.max(1) {
+            let point = self.eval(T::from(i).unwrap() / n);
+            length = length + distance(&previous, &point);
+            previous = point;
+        }
+        length
+    }
+}
+
+impl<T> CubicBezier<Vec2<T>>
+where
+    T: Float,
+{
+    /// Return the approximate length of the curve, measured along `segments` chords
+    ///
+    /// Always an underestimate, converging as `segments` grows.
+    pub fn arc_length(&self, segments: usize) -> T {
+        self.polyline_length(segments, Vec2::distance)
+    }
+}
+
+impl<T> CubicBezier<Vec3<T>>
+where
+    T: Float,
+{
+    /// Return the approximate length of the curve, measured along `segments` chords
+    ///
+    /// Always an underestimate, converging as `segments` grows.
+    pub fn arc_length(&self, segments: usize) -> T {
+        self.polyline_length(segments, Vec3::distance)
+    }
+}
+
+impl<T> CubicBezier<Vec4<T>>
+where
+    T: Float,
+{
+    /// Return the approximate length of the curve, measured along `segments` chords
+    ///
+    /// Always an underestimate, converging as `segments` grows.
+    pub fn arc_length(&self, segments: usize) -> T {
+        self.polyline_length(segments, Vec4::distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{
+        catmull_rom, catmull_rom_tangent, cubic_bezier, cubic_bezier_tangent, quadratic_bezier,
+        quadratic_bezier_tangent, CubicBezier, Vec2, Vec3, Vec4,
+    };
+
+    fn assert_close(a: Vec3<f64>, b: Vec3<f64>, epsilon: f64) {
+        assert!((a - b).length() < epsilon, "{} != {}", a, b);
+    }
+
+    fn controls() -> [Vec3<f64>; 4] {
+        [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 2.0, -1.0),
+            Vec3::new(3.0, 2.5, 1.0),
+            Vec3::new(4.0, 0.0, 0.5),
+        ]
+    }
+
+    #[test]
+    fn endpoints_test() {
+        let [p0, p1, p2, p3] = controls();
+
+        assert_eq!(quadratic_bezier(p0, p1, p2, 0.0), p0);
+        assert_eq!(quadratic_bezier(p0, p1, p2, 1.0), p2);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 1.0), p3);
+        assert_eq!(catmull_rom(p0, p1, p2, p3, 0.0), p1);
+        assert_close(catmull_rom(p0, p1, p2, p3, 1.0), p2, 1e-12);
+        // works on plain scalars too
+        assert_eq!(cubic_bezier(0.0, 1.0, 2.0, 3.0, 0.25), 0.75);
+    }
+
+    #[test]
+    fn symmetry_test() {
+        let [p0, p1, p2, p3] = controls();
+
+        for t in [0.0, 0.2, 0.5, 0.7, 1.0] {
+            let s = 1.0 - t;
+            assert_close(
+                quadratic_bezier(p0, p1, p2, t),
+                quadratic_bezier(p2, p1, p0, s),
+                1e-12,
+            );
+            assert_close(
+                cubic_bezier(p0, p1, p2, p3, t),
+                cubic_bezier(p3, p2, p1, p0, s),
+                1e-12,
+            );
+            assert_close(
+                catmull_rom(p0, p1, p2, p3, t),
+                catmull_rom(p3, p2, p1, p0, s),
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn tangent_matches_finite_difference_test() {
+        let [p0, p1, p2, p3] = controls();
+        let h = 1e-6;
+
+        for t in [0.1, 0.35, 0.5, 0.8, 0.95] {
+            let diff = |f: &dyn Fn(f64) -> Vec3<f64>| (f(t + h) - f(t - h)) / (2.0 * h);
+
+            assert_close(
+                quadratic_bezier_tangent(p0, p1, p2, t),
+                diff(&|t| quadratic_bezier(p0, p1, p2, t)),
+                1e-6,
+            );
+            assert_close(
+                cubic_bezier_tangent(p0, p1, p2, p3, t),
+                diff(&|t| cubic_bezier(p0, p1, p2, p3, t)),
+                1e-6,
+            );
+            assert_close(
+                catmull_rom_tangent(p0, p1, p2, p3, t),
+                diff(&|t| catmull_rom(p0, p1, p2, p3, t)),
+                1e-6,
+            );
+        }
+        // the Catmull-Rom end tangents come from the neighbouring points
+        assert_close(
+            catmull_rom_tangent(p0, p1, p2, p3, 0.0),
+            (p2 - p0) * 0.5,
+            1e-12,
+        );
+        assert_close(
+            catmull_rom_tangent(p0, p1, p2, p3, 1.0),
+            (p3 - p1) * 0.5,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn de_casteljau_test() {
+        let [p0, p1, p2, p3] = controls();
+        let (q0, q1, q2) = (
+            Vec2::new(0.1_f32, 0.3),
+            Vec2::new(0.7, 1.9),
+            Vec2::new(1.3, 0.2),
+        );
+
+        // exact at the ends, even where the sums don't round nicely
+        assert_eq!(Vec2::bezier_quadratic(q0, q1, q2, 0.0), q0);
+        assert_eq!(Vec2::bezier_quadratic(q0, q1, q2, 1.0), q2);
+        assert_eq!(Vec3::bezier_cubic(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(Vec3::bezier_cubic(p0, p1, p2, p3, 1.0), p3);
+
+        for t in [0.1, 0.5, 0.9] {
+            assert_close(
+                Vec3::bezier_quadratic(p0, p1, p2, t),
+                quadratic_bezier(p0, p1, p2, t),
+                1e-12,
+            );
+            assert_close(
+                Vec3::bezier_cubic(p0, p1, p2, p3, t),
+                cubic_bezier(p0, p1, p2, p3, t),
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn bezier_symmetric_midpoint_test() {
+        // an arch mirrored about x = 2 peaks halfway along
+        let arch = [
+            Vec4::new(0.0, 0.0, 1.0, 0.0),
+            Vec4::new(1.0, 4.0, 1.0, 0.0),
+            Vec4::new(3.0, 4.0, 1.0, 0.0),
+            Vec4::new(4.0, 0.0, 1.0, 0.0),
+        ];
+
+        assert_eq!(
+            Vec4::bezier_cubic(arch[0], arch[1], arch[2], arch[3], 0.5),
+            Vec4::new(2.0, 3.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            Vec2::bezier_quadratic(
+                Vec2::new(0.0, 0.0),
+                Vec2::new(2.0, 4.0),
+                Vec2::new(4.0, 0.0),
+                0.5
+            ),
+            Vec2::new(2.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn catmull_rom_assoc_test() {
+        let [p0, p1, p2, p3] = controls();
+
+        // exact at the ends, unlike the polynomial form
+        assert_eq!(Vec3::catmull_rom(p0, p1, p2, p3, 0.0), p1);
+        assert_eq!(Vec3::catmull_rom(p0, p1, p2, p3, 1.0), p2);
+        for t in [0.1, 0.5, 0.9] {
+            assert_close(
+                Vec3::catmull_rom(p0, p1, p2, p3, t),
+                catmull_rom(p0, p1, p2, p3, t),
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn catmull_rom_collinear_test() {
+        let (origin, direction) = (Vec2::new(1.0, -2.0), Vec2::new(3.0, 1.0));
+        // unevenly spaced, so the curve overshoots but stays on the line
+        let [p0, p1, p2, p3] = [-1.0, 0.5, 4.0, 4.5].map(|s| origin + direction * s);
+
+        for i in 0..=10 {
+            let p = Vec2::catmull_rom(p0, p1, p2, p3, i as f64 / 10.0);
+            assert!(direction.cross(&(p - origin)).abs() < 1e-12, "{}", p);
+        }
+    }
+
+    #[test]
+    fn cubic_bezier_struct_test() {
+        let [p0, p1, p2, p3] = controls();
+        let curve = CubicBezier::new(p0, p1, p2, p3);
+
+        assert_eq!(curve.eval(0.3), cubic_bezier(p0, p1, p2, p3, 0.3));
+        assert_eq!(
+            curve.tangent(0.3),
+            cubic_bezier_tangent(p0, p1, p2, p3, 0.3)
+        );
+    }
+
+    #[test]
+    fn arc_length_test() {
+        // the standard cubic approximation of a quarter circle of radius 1
+        let k = 0.552_284_749_831;
+        let quarter = CubicBezier::new(
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, k),
+            Vec2::new(k, 1.0),
+            Vec2::new(0.0, 1.0),
+        );
+        let coarse = quarter.arc_length(4);
+        let fine = quarter.arc_length(256);
+
+        assert!(coarse < fine);
+        assert!((fine - std::f64::consts::FRAC_PI_2).abs() < 1e-3);
+        assert_eq!(quarter.arc_length(0), quarter.arc_length(1));
+    }
+}
@@ -0,0 +1,70 @@
+//! Conversions to and from the matching `glam` vector types.
+
+use crate::vector::{Vec2, Vec3, Vec4};
+
+macro_rules! impl_glam_conversions {
+    ($vec:ident, $glam:ty, $scalar:ty { $($c:ident),+ }) => {
+        impl From<$glam> for $vec<$scalar> {
+            fn from(v: $glam) -> Self {
+                $vec::new($(v.$c),+)
+            }
+        }
+
+        impl From<$vec<$scalar>> for $glam {
+            fn from(v: $vec<$scalar>) -> Self {
+                <$glam>::new($(v.$c),+)
+            }
+        }
+    };
+}
+
+impl_glam_conversions!(Vec2, glam::Vec2, f32 { x, y });
+impl_glam_conversions!(Vec3, glam::Vec3, f32 { x, y, z });
+impl_glam_conversions!(Vec4, glam::Vec4, f32 { x, y, z, w });
+impl_glam_conversions!(Vec2, glam::DVec2, f64 { x, y });
+impl_glam_conversions!(Vec3, glam::DVec3, f64 { x, y, z });
+impl_glam_conversions!(Vec4, glam::DVec4, f64 { x, y, z, w });
+impl_glam_conversions!(Vec2, glam::IVec2, i32 { x, y });
+impl_glam_conversions!(Vec3, glam::IVec3, i32 { x, y, z });
+impl_glam_conversions!(Vec4, glam::IVec4, i32 { x, y, z, w });
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn glam_round_trip_test() {
+        let v2 = Vec2::new(1.5_f32, -2.0);
+        let v3 = Vec3::new(1.5_f32, -2.0, 3.25);
+        let v4 = Vec4::new(1.5_f32, -2.0, 3.25, 0.5);
+
+        assert_eq!(glam::Vec2::from(v2), glam::Vec2::new(1.5, -2.0));
+        assert_eq!(glam::Vec3::from(v3), glam::Vec3::new(1.5, -2.0, 3.25));
+        assert_eq!(glam::Vec4::from(v4), glam::Vec4::new(1.5, -2.0, 3.25, 0.5));
+        assert_eq!(Vec2::from(glam::Vec2::from(v2)), v2);
+        assert_eq!(Vec3::from(glam::Vec3::from(v3)), v3);
+        assert_eq!(Vec4::from(glam::Vec4::from(v4)), v4);
+    }
+
+    #[test]
+    fn glam_matches_lamar_test() {
+        let a = Vec3::new(1.0_f32, 2.0, 3.0);
+        let b = Vec3::new(-4.0_f32, 0.5, 2.0);
+        let ga: glam::Vec3 = a.into();
+        let gb: glam::Vec3 = b.into();
+
+        assert_eq!(Vec3::from(ga.cross(gb)), a.cross(&b));
+        assert_eq!(ga.dot(gb), a.dot(&b));
+    }
+
+    #[test]
+    fn glam_double_and_int_test() {
+        let d = Vec3::new(0.1_f64, -0.2, 0.3);
+        let i = Vec4::new(1, -2, 3, -4);
+
+        assert_eq!(Vec3::from(glam::DVec3::from(d)), d);
+        assert_eq!(glam::IVec4::from(i), glam::IVec4::new(1, -2, 3, -4));
+        assert_eq!(Vec4::from(glam::IVec4::from(i)), i);
+        assert_eq!(Vec2::<i32>::from(glam::IVec2::new(7, 8)), Vec2::new(7, 8));
+    }
+}
@@ -0,0 +1,38 @@
+use std::{error::Error, fmt::Display};
+
+/// The error returned when building a vector from a slice of the wrong length.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ComponentCountError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for ComponentCountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected {} components, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl Error for ComponentCountError {}
+
+/// Collect exactly `N` components from an iterator.
+pub(crate) fn collect_components<T, I, const N: usize>(
+    iter: I,
+) -> Result<[T; N], ComponentCountError>
+where
+    T: Copy,
+    I: IntoIterator<Item = T>,
+{
+    let items: Vec<T> = iter.into_iter().collect();
+    if items.len() != N {
+        return Err(ComponentCountError {
+            expected: N,
+            found: items.len(),
+        });
+    }
+    Ok(std::array::from_fn(|i| items[i]))
+}
@@ -0,0 +1,82 @@
+//! Conversions to and from the `mint` interop vector types.
+
+use crate::vector::{Vec2, Vec3, Vec4};
+use num::Num;
+
+macro_rules! impl_mint_conversions {
+    ($vec:ident, $mint:ident, $($bound:ident)+ { $($c:ident),+ }) => {
+        impl<T> From<mint::$mint<T>> for $vec<T>
+        where
+            T: $($bound +)+,
+        {
+            fn from(v: mint::$mint<T>) -> Self {
+                $vec::new($(v.$c),+)
+            }
+        }
+
+        impl<T> From<$vec<T>> for mint::$mint<T>
+        where
+            T: $($bound +)+,
+        {
+            fn from(v: $vec<T>) -> Self {
+                mint::$mint { $($c: v.$c),+ }
+            }
+        }
+
+        impl<T> mint::IntoMint for $vec<T>
+        where
+            T: $($bound +)+,
+        {
+            type MintType = mint::$mint<T>;
+        }
+    };
+}
+
+impl_mint_conversions!(Vec2, Vector2, Num Copy { x, y });
+impl_mint_conversions!(Vec3, Vector3, Num Clone Copy { x, y, z });
+impl_mint_conversions!(Vec4, Vector4, Num Clone Copy { x, y, z, w });
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn mint_round_trip_test() {
+        let v2 = Vec2::new(1.5_f32, -2.0);
+        let v3 = Vec3::new(1.5_f32, -2.0, 3.25);
+        let v4 = Vec4::new(1.5_f32, -2.0, 3.25, 0.5);
+
+        assert_eq!(mint::Vector2::from(v2), mint::Vector2 { x: 1.5, y: -2.0 });
+        assert_eq!(
+            mint::Vector3::from(v3),
+            mint::Vector3 {
+                x: 1.5,
+                y: -2.0,
+                z: 3.25
+            }
+        );
+        let m4: mint::Vector4<f32> = v4.into();
+        assert_eq!((m4.x, m4.y, m4.z, m4.w), (1.5, -2.0, 3.25, 0.5));
+
+        assert_eq!(Vec2::from(mint::Vector2::from(v2)), v2);
+        assert_eq!(Vec3::from(mint::Vector3::from(v3)), v3);
+        assert_eq!(Vec4::from(m4), v4);
+    }
+
+    #[test]
+    fn mint_generic_test() {
+        // the conversions work for any scalar, and through `IntoMint`
+        fn to_mint<V: mint::IntoMint>(v: V) -> V::MintType {
+            v.into()
+        }
+
+        assert_eq!(
+            to_mint(Vec3::new(1, 2, 3)),
+            mint::Vector3 { x: 1, y: 2, z: 3 }
+        );
+        assert_eq!(
+            Vec2::from(mint::Vector2 { x: 0.5_f64, y: 4.0 }),
+            Vec2::new(0.5, 4.0)
+        );
+    }
+}
@@ -1,8 +1,46 @@
+mod aliases;
+mod analysis;
+mod batch;
+mod bvec;
+mod camera;
+mod curve;
+#[cfg(feature = "glam")]
+mod glam_interop;
+mod iter;
+#[cfg(feature = "mint")]
+mod mint_interop;
+mod orthonormal;
+mod parse;
+mod polygon;
+mod projection;
+#[cfg(feature = "rand")]
+mod random;
+mod rotation;
+mod scalar_ops;
+mod shading;
+mod space;
+mod spline;
+mod swizzle;
 mod vec2;
 mod vec3;
 mod vec4;
 
 // rexports
+pub use crate::vector::aliases::*;
+pub use crate::vector::analysis::*;
+pub use crate::vector::batch::*;
+pub use crate::vector::bvec::*;
+pub use crate::vector::camera::*;
+pub use crate::vector::curve::*;
+pub use crate::vector::iter::ComponentCountError;
+pub use crate::vector::orthonormal::LinearDependenceError;
+pub use crate::vector::parse::ParseVecError;
+pub use crate::vector::polygon::*;
+pub use crate::vector::projection::*;
+pub use crate::vector::rotation::*;
+pub use crate::vector::shading::*;
+pub use crate::vector::space::*;
+pub use crate::vector::spline::*;
 pub use crate::vector::vec2::*;
 pub use crate::vector::vec3::*;
 pub use crate::vector::vec4::*;
@@ -0,0 +1,173 @@
+use crate::vector::Vec3;
+use num::Float;
+use std::{error::Error, fmt::Display};
+
+/// The error returned when orthonormalizing vectors that are linearly dependent.
+///
+/// `index` is the first vector that lies (within tolerance) in the span of the
+/// ones before it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LinearDependenceError {
+    pub index: usize,
+}
+
+impl Display for LinearDependenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "vector {} is linearly dependent on the ones before it",
+            self.index
+        )
+    }
+}
+
+impl Error for LinearDependenceError {}
+
+impl<T> Vec3<T>
+where
+    T: Float,
+{
+    /// Orthonormalize `vectors` in place with modified Gram-Schmidt
+    ///
+    /// Each vector has its components along the earlier ones removed, then is
+    /// normalized, so the first keeps its direction and the rest are bent as little
+    /// as possible. The projections are removed twice, which keeps the result
+    /// orthonormal to rounding error even for nearly dependent input.
+    ///
+    /// A vector is dependent when less than `sqrt(epsilon)` of its length is left
+    /// after removing the projections (so a zero vector always is, and so is any
+    /// vector after the third). The error gives its index; the vectors before it
+    /// are orthonormalized, and it and the rest are left unchanged.
+    pub fn orthonormalize(vectors: &mut [Vec3<T>]) -> Result<(), LinearDependenceError> {
+        for index in 0..vectors.len() {
+            let (done, rest) = vectors.split_at_mut(index);
+            let original = rest[0];
+            let mut v = original;
+            for _ in 0..2 {
+                for u in done.iter() {
+                    v = v - *u * u.dot(&v);
+                }
+            }
+
+            let length = v.length();
+            if length <= original.length() * T::epsilon().sqrt() {
+                return Err(LinearDependenceError { index });
+            }
+            rest[0] = v / length;
+        }
+        Ok(())
+    }
+
+    /// Repair a tangent frame: normalize `normal`, then make `tangent` a unit vector
+    /// perpendicular to it
+    ///
+    /// The normal keeps its direction and the tangent loses its component along it.
+    /// If the tangent is (nearly) parallel to the normal there is no direction left
+    /// to keep, so it is replaced with an arbitrary perpendicular from
+    /// `any_orthonormal_pair`. `normal` must not be zero.
+    pub fn orthonormalize_pair(normal: &mut Vec3<T>, tangent: &mut Vec3<T>) {
+        *normal = normal.normalize();
+        let mut frame = [*normal, *tangent];
+        *tangent = match Self::orthonormalize(&mut frame) {
+            Ok(()) => frame[1],
+            Err(_) => normal.any_orthonormal_pair().0,
+        };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{LinearDependenceError, Vec3};
+
+    fn assert_orthonormal(vectors: &[Vec3<f64>]) {
+        for (i, a) in vectors.iter().enumerate() {
+            assert!((a.length() - 1.0).abs() < 1e-6, "{}", a);
+            for b in &vectors[i + 1..] {
+                assert!(a.dot(b).abs() < 1e-6, "{} . {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn orthonormalize_test() {
+        let mut vectors = [
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(1.0, 3.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.5),
+        ];
+
+        assert_eq!(Vec3::orthonormalize(&mut vectors), Ok(()));
+        assert_eq!(vectors[0], Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(vectors[1], Vec3::new(0.0, 1.0, 0.0));
+        assert_eq!(vectors[2], Vec3::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn nearly_dependent_test() {
+        let mut vectors = [
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(1.0, 1.0, 1.0 + 1e-6),
+            Vec3::new(1.0 + 1e-6, 1.0, 1.0),
+        ];
+        let mut floats = [Vec3::new(1.0_f32, 1.0, 1.0), Vec3::new(1.0, 1.0, 1.01)];
+
+        assert_eq!(Vec3::orthonormalize(&mut vectors), Ok(()));
+        assert_orthonormal(&vectors);
+        assert_eq!(Vec3::orthonormalize(&mut floats), Ok(()));
+        assert!(floats[0].dot(&floats[1]).abs() < 1e-6);
+    }
+
+    #[test]
+    fn dependent_test() {
+        let original = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(3.0, -1.0, 0.0),
+        ];
+        let mut vectors = original;
+
+        assert_eq!(
+            Vec3::orthonormalize(&mut vectors),
+            Err(LinearDependenceError { index: 2 })
+        );
+        // the vectors before the failure are done, the rest are untouched
+        assert_eq!(vectors[1], Vec3::new(0.0, 1.0, 0.0));
+        assert_eq!(vectors[2], original[2]);
+
+        let mut four = [
+            original[0],
+            original[1],
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(1.0, 1.0, 1.0),
+        ];
+        assert_eq!(
+            Vec3::orthonormalize(&mut four),
+            Err(LinearDependenceError { index: 3 })
+        );
+        let mut zero = [Vec3::new(0.0, 0.0, 0.0)];
+        assert_eq!(
+            Vec3::orthonormalize(&mut zero),
+            Err(LinearDependenceError { index: 0 })
+        );
+    }
+
+    #[test]
+    fn orthonormalize_pair_test() {
+        // a skinned tangent frame that has drifted
+        let mut normal = Vec3::new(0.1, 2.0, -0.05);
+        let mut tangent = Vec3::new(1.0, 0.2, 0.01);
+        let direction = normal.normalize();
+
+        Vec3::orthonormalize_pair(&mut normal, &mut tangent);
+
+        assert!((normal - direction).length() < 1e-12);
+        assert_orthonormal(&[normal, tangent]);
+        assert!(tangent.x > 0.9);
+
+        // a tangent along the normal is replaced with some perpendicular
+        let mut normal = Vec3::new(0.0, 0.0, 3.0);
+        let mut tangent = Vec3::new(0.0, 0.0, -1.0);
+        Vec3::orthonormalize_pair(&mut normal, &mut tangent);
+        assert_orthonormal(&[normal, tangent]);
+    }
+}
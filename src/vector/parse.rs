@@ -0,0 +1,63 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// The error returned when parsing a vector from a string fails.
+///
+/// `E` is the error type of the component's own `FromStr` implementation.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseVecError<E> {
+    /// The string held a different number of components than the vector has
+    WrongComponentCount { expected: usize, found: usize },
+    /// One of the components failed to parse as the scalar type
+    InvalidComponent(E),
+}
+
+impl<E> Display for ParseVecError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrongComponentCount { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            Self::InvalidComponent(e) => write!(f, "invalid component: {}", e),
+        }
+    }
+}
+
+impl<E> Error for ParseVecError<E> where E: Error {}
+
+/// Split a vector string into exactly `N` parsed components.
+///
+/// Accepts an optional pair of surrounding `()` or `[]`, with components
+/// separated by commas or whitespace, e.g. `"1,2,3"`, `"(1, 2, 3)"` or `"[1 2 3]"`.
+pub(crate) fn parse_components<T, const N: usize>(s: &str) -> Result<[T; N], ParseVecError<T::Err>>
+where
+    T: FromStr + Copy,
+{
+    let s = s.trim();
+    let s = s
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .or_else(|| s.strip_prefix('[').and_then(|s| s.strip_suffix(']')))
+        .unwrap_or(s)
+        .trim();
+
+    let tokens: Vec<&str> = if s.contains(',') {
+        s.split(',').map(str::trim).collect()
+    } else {
+        s.split_whitespace().collect()
+    };
+    if tokens.len() != N {
+        return Err(ParseVecError::WrongComponentCount {
+            expected: N,
+            found: tokens.len(),
+        });
+    }
+
+    let mut parsed = Vec::with_capacity(N);
+    for token in tokens {
+        parsed.push(token.parse().map_err(ParseVecError::InvalidComponent)?);
+    }
+    Ok(std::array::from_fn(|i| parsed[i]))
+}
@@ -0,0 +1,913 @@
+use crate::vector::Vec2;
+use num::Float;
+use std::cmp::Ordering;
+
+/// Return the convex hull of `points` in counter-clockwise order (Andrew's monotone chain)
+///
+/// Starts from the lowest-x (then lowest-y) point. Duplicate points are merged, and
+/// points lying along a hull edge are only returned when `keep_collinear` is set.
+/// When there's no area to enclose the result is the distinct points themselves for
+/// fewer than three, or the two extremes when they're all on one line.
+///
+/// ```rust
+/// # use lamar::vector::{convex_hull, Vec2};
+/// let points = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(1.0, 0.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(1.0, 1.0),
+///     Vec2::new(1.0, 0.5),
+/// ];
+/// assert_eq!(
+///     convex_hull(&points, false),
+///     vec![Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0), Vec2::new(1.0, 1.0)]
+/// );
+/// assert_eq!(convex_hull(&points, true).len(), 4);
+/// ```
+pub fn convex_hull<T: Float>(points: &[Vec2<T>], keep_collinear: bool) -> Vec<Vec2<T>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let (first, last) = (sorted[0], sorted[sorted.len() - 1]);
+    if sorted
+        .iter()
+        .all(|p| (last - first).cross(&(*p - first)) == T::zero())
+    {
+        return vec![first, last];
+    }
+
+    let mut hull: Vec<Vec2<T>> = Vec::with_capacity(sorted.len() * 2);
+    // lower chain left to right, then the upper chain back again
+    for pass in [sorted.clone(), sorted.into_iter().rev().collect()] {
+        let start = hull.len();
+        for p in pass {
+            while hull.len() >= start + 2 {
+                let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+                let turn = (b - a).cross(&(p - a));
+                if turn > T::zero() || (keep_collinear && turn == T::zero()) {
+                    break;
+                }
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // the last point of each chain starts the next one
+        hull.pop();
+    }
+    hull
+}
+
+/// Return the Minkowski sum of two convex polygons
+///
+/// This is the convex hull of every pairwise sum of their vertices, in
+/// counter-clockwise order. Summing `a` with the negated vertices of `b` gives
+/// the Minkowski difference, which contains the origin exactly when the
+/// polygons overlap.
+pub fn minkowski_sum(a: &[Vec2<f32>], b: &[Vec2<f32>]) -> Vec<Vec2<f32>> {
+    let sums: Vec<Vec2<f32>> = a
+        .iter()
+        .flat_map(|p| b.iter().map(move |q| *p + *q))
+        .collect();
+    convex_hull(&sums, false)
+}
+
+/// Return twice the signed area of a polygon, positive when counter-clockwise
+fn doubled_area<T: Float>(polygon: &[Vec2<T>]) -> T {
+    (0..polygon.len()).fold(T::zero(), |sum, i| {
+        sum + polygon[i].cross(&polygon[(i + 1) % polygon.len()])
+    })
+}
+
+/// The direction a polygon's vertices go around it
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum WindingOrder {
+    CounterClockwise,
+    Clockwise,
+}
+
+/// Return the signed area of a polygon (the shoelace formula)
+///
+/// `sum(p[i].cross(p[i + 1])) / 2`, closing the polygon from the last vertex back to
+/// the first. The area is positive when the vertices are counter-clockwise (with Y
+/// up) and negative when clockwise. Fewer than three vertices have zero area, and
+/// the lobes of a self-intersecting polygon cancel out by their winding.
+///
+/// ```rust
+/// # use lamar::vector::{polygon_area, Vec2};
+/// let square = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(2.0, 2.0),
+///     Vec2::new(0.0, 2.0),
+/// ];
+/// assert_eq!(polygon_area(&square), 4.0);
+/// ```
+pub fn polygon_area<T: Float>(polygon: &[Vec2<T>]) -> T {
+    doubled_area(polygon) / (T::one() + T::one())
+}
+
+/// Return the centroid (centre of mass) of a polygon's area
+///
+/// Unlike `Vec2::centroid` of the vertices, this isn't pulled towards edges
+/// with many vertices. Returns `None` for fewer than three vertices or zero area,
+/// and works for either winding.
+pub fn polygon_centroid<T: Float>(polygon: &[Vec2<T>]) -> Option<Vec2<T>> {
+    let doubled = doubled_area(polygon);
+    if polygon.len() < 3 || doubled == T::zero() {
+        return None;
+    }
+    let weighted = (0..polygon.len()).fold(Vec2::new(T::zero(), T::zero()), |sum, i| {
+        let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+        sum + (a + b) * a.cross(&b)
+    });
+    Some(weighted / (doubled * T::from(3.0).unwrap()))
+}
+
+/// Return which way a polygon's vertices go around, from the sign of its area
+///
+/// Returns `None` when the area is zero, e.g. for fewer than three vertices or
+/// collinear ones.
+pub fn winding_order<T: Float>(polygon: &[Vec2<T>]) -> Option<WindingOrder> {
+    let area = doubled_area(polygon);
+    if area > T::zero() {
+        Some(WindingOrder::CounterClockwise)
+    } else if area < T::zero() {
+        Some(WindingOrder::Clockwise)
+    } else {
+        None
+    }
+}
+
+/// Return whether a polygon is convex, in either winding
+///
+/// Repeated vertices and straight (collinear) corners are allowed. The polygon
+/// must turn the same way at every other corner, and go around exactly once, which
+/// rules out self-intersecting shapes like pentagrams. Fewer than three distinct
+/// vertices, or zero area, are not convex.
+pub fn is_convex<T: Float>(polygon: &[Vec2<T>]) -> bool {
+    let mut edges: Vec<Vec2<T>> = (0..polygon.len())
+        .map(|i| polygon[(i + 1) % polygon.len()] - polygon[i])
+        .collect();
+    edges.retain(|e| e.length_squared() > T::zero());
+    if edges.len() < 3 || doubled_area(polygon) == T::zero() {
+        return false;
+    }
+
+    let (mut sign, mut turning) = (T::zero(), T::zero());
+    for i in 0..edges.len() {
+        let (a, b) = (edges[i], edges[(i + 1) % edges.len()]);
+        let turn = a.cross(&b);
+        if turn != T::zero() {
+            if turn.signum() == -sign {
+                return false;
+            }
+            sign = turn.signum();
+        }
+        turning = turning + turn.atan2(a.dot(&b));
+    }
+    let full_turn = T::from(std::f64::consts::TAU).unwrap();
+    (turning.abs() - full_turn).abs() <= T::epsilon().sqrt()
+}
+
+/// Return the barycentric coordinates `(u, v, w)` of `p` in the 2D triangle `a, b, c`
+///
+/// `p == a * u + b * v + c * w` with `u + v + w == 1`, and each weight is the
+/// signed area of the sub-triangle opposite its vertex over the whole area, so
+/// points outside have at least one negative weight. Either winding works. A
+/// degenerate (zero area) triangle divides by zero, giving non-finite weights.
+///
+/// ```rust
+/// # use lamar::vector::{barycentric, Vec2};
+/// let (a, b, c) = (Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0), Vec2::new(0.0, 4.0));
+/// assert_eq!(barycentric(&Vec2::new(1.0, 2.0), &a, &b, &c), (0.25, 0.25, 0.5));
+/// ```
+pub fn barycentric<T: Float>(p: &Vec2<T>, a: &Vec2<T>, b: &Vec2<T>, c: &Vec2<T>) -> (T, T, T) {
+    let area = (*b - *a).cross(&(*c - *a));
+    let v = (*p - *a).cross(&(*c - *a)) / area;
+    let w = (*b - *a).cross(&(*p - *a)) / area;
+    (T::one() - v - w, v, w)
+}
+
+/// Return whether `p` lies inside the 2D triangle `a, b, c`, including its edges
+///
+/// Checks that all three barycentric coordinates are non-negative, with no
+/// tolerance. Either winding works, and degenerate triangles contain no points.
+pub fn point_in_triangle<T: Float>(p: &Vec2<T>, a: &Vec2<T>, b: &Vec2<T>, c: &Vec2<T>) -> bool {
+    if (*b - *a).cross(&(*c - *a)) == T::zero() {
+        return false;
+    }
+    let (u, v, w) = barycentric(p, a, b, c);
+    u >= T::zero() && v >= T::zero() && w >= T::zero()
+}
+
+/// Return whether `p` is inside a polygon, using the even-odd rule
+///
+/// A ray from `p` crossing the edges an odd number of times is inside, so the
+/// overlapping parts of a self-intersecting polygon (such as the centre of a
+/// pentagram) count as outside. Points exactly on an edge or vertex are inside.
+/// Either winding works, repeated vertices are ignored, and fewer than three
+/// vertices only contain the points on their edges.
+pub fn point_in_polygon<T: Float>(p: &Vec2<T>, polygon: &[Vec2<T>]) -> bool {
+    let mut inside = false;
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+        let on_edge = (b - a).cross(&(*p - a)) == T::zero()
+            && p.x >= a.x.min(b.x)
+            && p.x <= a.x.max(b.x)
+            && p.y >= a.y.min(b.y)
+            && p.y <= a.y.max(b.y);
+        if on_edge {
+            return true;
+        }
+        // count crossings of a ray towards +X, with each edge half-open in Y so a
+        // vertex shared by two edges is only counted once
+        if (a.y > p.y) != (b.y > p.y) {
+            let x = a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y);
+            if p.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside && polygon.len() >= 3
+}
+
+/// Triangulate a simple polygon by ear clipping, returning triangles as vertex indices
+///
+/// The polygon should be counter-clockwise and not self-intersecting; clockwise
+/// polygons are walked in reverse, so the triangles are always counter-clockwise.
+/// Collinear vertices are skipped rather than producing zero-area triangles. Fewer
+/// than three vertices, zero area, or a polygon that can't be clipped (such as a
+/// self-intersecting one) give an empty result. The zero area and collinear tests
+/// are relative to the polygon's size, so tiny polygons work as well as large ones.
+pub fn triangulate(polygon: &[Vec2<f32>]) -> Vec<[usize; 3]> {
+    if polygon.len() < 3 {
+        return Vec::new();
+    }
+    let area = doubled_area(polygon);
+    let extent_squared = polygon
+        .iter()
+        .map(|p| (*p - polygon[0]).length_squared())
+        .fold(0.0, f32::max);
+    if area.abs() <= f32::EPSILON * extent_squared {
+        return Vec::new();
+    }
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+    if area < 0.0 {
+        remaining.reverse();
+    }
+
+    let mut triangles = Vec::with_capacity(polygon.len() - 2);
+    while remaining.len() >= 3 {
+        let n = remaining.len();
+        let ear = (0..n).find_map(|i| {
+            let (a, b, c) = (
+                remaining[(i + n - 1) % n],
+                remaining[i],
+                remaining[(i + 1) % n],
+            );
+            let (pa, pb, pc) = (polygon[a], polygon[b], polygon[c]);
+            let turn = (pb - pa).cross(&(pc - pb));
+            if turn.abs() <= f32::EPSILON * (pb - pa).length() * (pc - pb).length() {
+                // collinear, so dropping it doesn't change the shape
+                return Some((i, None));
+            }
+            if turn < 0.0 {
+                return None;
+            }
+            let blocked = remaining.iter().any(|&j| {
+                let p = polygon[j];
+                p != pa
+                    && p != pb
+                    && p != pc
+                    && (pb - pa).cross(&(p - pa)) >= 0.0
+                    && (pc - pb).cross(&(p - pb)) >= 0.0
+                    && (pa - pc).cross(&(p - pc)) >= 0.0
+            });
+            if blocked {
+                None
+            } else {
+                Some((i, Some([a, b, c])))
+            }
+        });
+
+        match ear {
+            Some((i, triangle)) => {
+                triangles.extend(triangle);
+                remaining.remove(i);
+            }
+            None => return Vec::new(),
+        }
+    }
+    triangles
+}
+
+/// Return where segment `p1..p2` crosses segment `p3..p4`, with the parameter along each
+///
+/// The result is `(point, t, u)` with `point == p1 + (p2 - p1) * t == p3 + (p4 - p3) * u`,
+/// and is only returned when both `t` and `u` are within `[0, 1]`, so touching at an
+/// endpoint counts. Parallel (including collinear) segments have no unique crossing
+/// and give `None`; see `Segment2::intersect` for overlap handling.
+pub fn segment_intersection(
+    p1: Vec2<f32>,
+    p2: Vec2<f32>,
+    p3: Vec2<f32>,
+    p4: Vec2<f32>,
+) -> Option<(Vec2<f32>, f32, f32)> {
+    let r = p2 - p1;
+    let s = p4 - p3;
+    let denom = r.cross(&s);
+    // relative to the lengths, as the cross product scales with the square of the input
+    if denom.abs() <= f32::EPSILON * r.length() * s.length() {
+        return None;
+    }
+    let qp = p3 - p1;
+    let t = qp.cross(&s) / denom;
+    let u = qp.cross(&r) / denom;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some((p1 + r * t, t, u))
+    } else {
+        None
+    }
+}
+
+/// Accumulates polygon vertices one at a time, keeping track of the signed area
+///
+/// The shoelace sum over the open chain of edges is updated on every `push`, so
+/// `area` only needs to add the closing edge and is O(1).
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct PolygonBuilder {
+    vertices: Vec<Vec2<f32>>,
+    chain_sum: f32,
+}
+
+impl PolygonBuilder {
+    /// Create a builder with no vertices
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a vertex to the end of the polygon
+    pub fn push(&mut self, v: Vec2<f32>) {
+        if let Some(last) = self.vertices.last() {
+            self.chain_sum += last.cross(&v);
+        }
+        self.vertices.push(v);
+    }
+
+    /// Return the signed area of the polygon, positive when counter-clockwise
+    ///
+    /// The polygon is implicitly closed from the last vertex back to the first.
+    /// Fewer than three vertices have zero area.
+    pub fn area(&self) -> f32 {
+        match (self.vertices.first(), self.vertices.last()) {
+            (Some(first), Some(last)) => (self.chain_sum + last.cross(first)) / 2.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Return the vertices pushed so far
+    pub fn vertices(&self) -> &[Vec2<f32>] {
+        &self.vertices
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_rng::TestRng;
+    use crate::vector::{
+        barycentric, convex_hull, is_convex, minkowski_sum, point_in_polygon, point_in_triangle,
+        polygon_area, polygon_centroid, segment_intersection, triangulate, winding_order,
+        PolygonBuilder, Vec2, WindingOrder,
+    };
+
+    fn square(min: f32, max: f32) -> Vec<Vec2<f32>> {
+        vec![
+            Vec2::new(min, min),
+            Vec2::new(max, min),
+            Vec2::new(max, max),
+            Vec2::new(min, max),
+        ]
+    }
+
+    fn random_points(seed: u64, count: usize) -> Vec<Vec2<f64>> {
+        let mut rng = TestRng::new(seed);
+        // a coarse grid, so there are plenty of duplicate and collinear points
+        let mut next = move || rng.below(9) as f64;
+        (0..count).map(|_| Vec2::new(next(), next())).collect()
+    }
+
+    fn on_segment(p: Vec2<f64>, a: Vec2<f64>, b: Vec2<f64>) -> bool {
+        (b - a).cross(&(p - a)) == 0.0 && (p - a).dot(&(p - b)) <= 0.0
+    }
+
+    // a point is a hull vertex when it isn't in the triangle (or on the segment)
+    // of any other points
+    fn brute_force_vertices(points: &[Vec2<f64>]) -> Vec<Vec2<f64>> {
+        let mut distinct = points.to_vec();
+        distinct.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        distinct.dedup();
+        let covered = |p: Vec2<f64>| {
+            let others: Vec<Vec2<f64>> = distinct.iter().copied().filter(|q| *q != p).collect();
+            others.iter().any(|&a| {
+                others.iter().any(|&b| {
+                    on_segment(p, a, b)
+                        || others
+                            .iter()
+                            .any(|&c| a != b && point_in_triangle(&p, &a, &b, &c))
+                })
+            })
+        };
+        distinct.iter().copied().filter(|p| !covered(*p)).collect()
+    }
+
+    #[test]
+    fn convex_hull_brute_force_test() {
+        for seed in 1..40 {
+            let points = random_points(seed * 7919, 4 + seed as usize % 20);
+            let hull = convex_hull(&points, false);
+            let with_collinear = convex_hull(&points, true);
+
+            let mut expected = brute_force_vertices(&points);
+            let mut sorted = hull.clone();
+            expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(sorted, expected, "{:?}", points);
+
+            // every point on the boundary, and nothing else, when keeping collinear points
+            let n = hull.len();
+            let mut boundary: Vec<Vec2<f64>> = points
+                .iter()
+                .copied()
+                .filter(|p| (0..n).any(|i| on_segment(*p, hull[i], hull[(i + 1) % n])))
+                .collect();
+            boundary.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            boundary.dedup();
+            let mut sorted = with_collinear.clone();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(sorted, boundary, "{:?}", points);
+
+            // counter-clockwise, with everything inside
+            for h in [&hull, &with_collinear] {
+                assert!(polygon_area(h) > 0.0);
+                assert!(points.iter().all(|p| point_in_polygon(p, h)));
+            }
+            assert!(is_convex(&hull));
+        }
+    }
+
+    #[test]
+    fn convex_hull_degenerate_test() {
+        let p = Vec2::new(1.0, 2.0);
+        let line = [
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(3.0, 3.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(3.0, 3.0),
+        ];
+
+        assert!(convex_hull::<f32>(&[], false).is_empty());
+        assert_eq!(convex_hull(&[p, p, p], false), vec![p]);
+        assert_eq!(
+            convex_hull(&[p, Vec2::new(0.0, 0.0), p], true),
+            vec![Vec2::new(0.0, 0.0), p]
+        );
+        for keep_collinear in [false, true] {
+            assert_eq!(
+                convex_hull(&line, keep_collinear),
+                vec![Vec2::new(0.0, 0.0), Vec2::new(3.0, 3.0)]
+            );
+        }
+        // duplicated corners are merged
+        let mut doubled = square(0.0, 1.0);
+        doubled.extend(square(0.0, 1.0));
+        assert_eq!(convex_hull(&doubled, false), square(0.0, 1.0));
+    }
+
+    #[test]
+    fn minkowski_sum_squares_test() {
+        let sum = minkowski_sum(&square(0.0, 1.0), &square(-1.0, 1.0));
+
+        assert_eq!(sum, square(-1.0, 2.0));
+    }
+
+    #[test]
+    fn minkowski_sum_triangle_test() {
+        let triangle = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        let sum = minkowski_sum(&triangle, &[Vec2::new(2.0, 3.0)]);
+
+        assert_eq!(
+            sum,
+            vec![
+                Vec2::new(2.0, 3.0),
+                Vec2::new(3.0, 3.0),
+                Vec2::new(2.0, 4.0)
+            ]
+        );
+        assert!(minkowski_sum(&triangle, &[]).is_empty());
+    }
+
+    #[test]
+    fn minkowski_difference_overlap_test() {
+        let a = square(0.0, 2.0);
+        let negate = |p: &[Vec2<f32>]| p.iter().map(|v| *v * -1.0).collect::<Vec<_>>();
+        let contains_origin = |poly: &[Vec2<f32>]| {
+            (0..poly.len()).all(|i| {
+                let (p, q) = (poly[i], poly[(i + 1) % poly.len()]);
+                (q - p).cross(&(Vec2::new(0.0, 0.0) - p)) >= 0.0
+            })
+        };
+
+        assert!(contains_origin(&minkowski_sum(
+            &a,
+            &negate(&square(1.0, 3.0))
+        )));
+        assert!(!contains_origin(&minkowski_sum(
+            &a,
+            &negate(&square(3.0, 4.0))
+        )));
+    }
+
+    fn triangulated_area(polygon: &[Vec2<f32>], triangles: &[[usize; 3]]) -> f32 {
+        triangles
+            .iter()
+            .map(|[a, b, c]| {
+                let area = (polygon[*b] - polygon[*a]).cross(&(polygon[*c] - polygon[*a])) / 2.0;
+                // every triangle keeps the polygon's winding
+                assert!(area > 0.0);
+                area
+            })
+            .sum()
+    }
+
+    #[test]
+    fn triangulate_square_test() {
+        let sq = square(0.0, 2.0);
+        let triangles = triangulate(&sq);
+
+        assert_eq!(triangles.len(), 2);
+        assert_eq!(triangulated_area(&sq, &triangles), 4.0);
+
+        let mut clockwise = sq.clone();
+        clockwise.reverse();
+        assert_eq!(triangulated_area(&clockwise, &triangulate(&clockwise)), 4.0);
+    }
+
+    #[test]
+    fn triangulate_concave_test() {
+        let l_shape = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        let triangles = triangulate(&l_shape);
+
+        assert_eq!(triangles.len(), 4);
+        assert_eq!(triangulated_area(&l_shape, &triangles), 3.0);
+        // the reflex corner can't be clipped, so every triangle around it stays inside
+        for t in &triangles {
+            let centroid = Vec2::centroid(&t.map(|i| l_shape[i])).unwrap();
+            assert!(!(centroid.x > 1.0 && centroid.y > 1.0));
+        }
+    }
+
+    #[test]
+    fn triangulate_collinear_test() {
+        let with_midpoints = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        let triangles = triangulate(&with_midpoints);
+
+        assert_eq!(triangulated_area(&with_midpoints, &triangles), 4.0);
+    }
+
+    #[test]
+    fn triangulate_small_test() {
+        let tiny = square(0.0, 1e-4);
+        let triangles = triangulate(&tiny);
+
+        assert_eq!(triangles.len(), 2);
+        assert!((triangulated_area(&tiny, &triangles) - 1e-8).abs() < 1e-12);
+
+        let with_midpoint = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1e-4, 0.0),
+            Vec2::new(2e-4, 0.0),
+            Vec2::new(2e-4, 2e-4),
+            Vec2::new(0.0, 2e-4),
+        ];
+        let triangles = triangulate(&with_midpoint);
+        assert_eq!(triangles.len(), 3);
+        assert!((triangulated_area(&with_midpoint, &triangles) - 4e-8).abs() < 1e-12);
+        assert!(triangulate(&[
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1e-4, 1e-4),
+            Vec2::new(2e-4, 2e-4)
+        ])
+        .is_empty());
+    }
+
+    #[test]
+    fn triangulate_degenerate_test() {
+        assert!(triangulate(&[]).is_empty());
+        assert!(triangulate(&[Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)]).is_empty());
+        assert!(triangulate(&[
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0)
+        ])
+        .is_empty());
+        // a self-intersecting bow tie has zero signed area
+        assert!(triangulate(&[
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0)
+        ])
+        .is_empty());
+    }
+
+    fn l_shape() -> [Vec2<f32>; 6] {
+        [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn polygon_area_test() {
+        let unit = square(0.0, 1.0);
+        let mut clockwise = l_shape();
+        clockwise.reverse();
+
+        assert_eq!(polygon_area(&unit), 1.0);
+        assert_eq!(polygon_area(&l_shape()), 3.0);
+        assert_eq!(polygon_area(&clockwise), -3.0);
+        assert_eq!(polygon_area(&unit[..2]), 0.0);
+        assert_eq!(winding_order(&unit), Some(WindingOrder::CounterClockwise));
+        assert_eq!(winding_order(&clockwise), Some(WindingOrder::Clockwise));
+        assert_eq!(winding_order(&unit[..2]), None);
+    }
+
+    #[test]
+    fn polygon_area_triangle_test() {
+        let triangle = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(5.0, 1.0),
+            Vec2::new(1.0, 4.0),
+        ];
+        let mut clockwise = square(0.0, 1.0);
+        clockwise.reverse();
+
+        assert_eq!(polygon_area(&triangle), 6.0);
+        assert_eq!(polygon_centroid(&triangle), Some(Vec2::new(7.0, 6.0) / 3.0));
+        assert_eq!(polygon_area(&clockwise), -1.0);
+        assert_eq!(polygon_centroid(&clockwise), Some(Vec2::new(0.5, 0.5)));
+    }
+
+    #[test]
+    fn polygon_centroid_test() {
+        let mut clockwise = l_shape();
+        clockwise.reverse();
+
+        assert_eq!(
+            polygon_centroid(&square(0.0, 1.0)),
+            Some(Vec2::new(0.5, 0.5))
+        );
+        // three unit squares centred on (0.5, 0.5), (1.5, 0.5) and (0.5, 1.5)
+        let expected = Vec2::new(2.5, 2.5) / 3.0;
+        assert!((polygon_centroid(&l_shape()).unwrap() - expected).length() < 1e-6);
+        assert!((polygon_centroid(&clockwise).unwrap() - expected).length() < 1e-6);
+        // extra vertices along an edge don't move it, unlike the vertex centroid
+        let mut dense = square(0.0, 1.0);
+        dense.splice(1..1, (1..10).map(|i| Vec2::new(i as f32 / 10.0, 0.0)));
+        assert_eq!(polygon_centroid(&dense), Some(Vec2::new(0.5, 0.5)));
+        assert_eq!(
+            polygon_centroid(&[Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)]),
+            None
+        );
+    }
+
+    #[test]
+    fn is_convex_test() {
+        let mut clockwise = square(0.0, 1.0);
+        clockwise.reverse();
+        let mut repeated = square(0.0, 1.0);
+        repeated.insert(2, repeated[2]);
+        repeated.insert(1, Vec2::new(0.5, 0.0));
+        let pentagram: Vec<Vec2<f32>> = (0..5)
+            .map(|i| Vec2::new(0.0, 1.0).rotate(i as f32 * std::f32::consts::TAU * 2.0 / 5.0))
+            .collect();
+
+        assert!(is_convex(&square(0.0, 1.0)));
+        assert!(is_convex(&clockwise));
+        assert!(is_convex(&repeated));
+        assert!(!is_convex(&l_shape()));
+        assert!(!is_convex(&pentagram));
+        assert!(!is_convex(&square(0.0, 1.0)[..2]));
+    }
+
+    #[test]
+    fn point_in_polygon_test() {
+        let l = l_shape();
+
+        assert!(point_in_polygon(&Vec2::new(0.5, 0.5), &l));
+        assert!(point_in_polygon(&Vec2::new(1.5, 0.5), &l));
+        assert!(!point_in_polygon(&Vec2::new(1.5, 1.5), &l));
+        assert!(!point_in_polygon(&Vec2::new(-0.5, 0.5), &l));
+        // level with a vertex, which must only be counted once
+        assert!(point_in_polygon(&Vec2::new(0.5, 1.0), &l));
+        assert!(!point_in_polygon(&Vec2::new(-1.0, 1.0), &l));
+        assert!(!point_in_polygon(&Vec2::new(-1.0, 2.0), &l));
+    }
+
+    #[test]
+    fn point_in_polygon_boundary_test() {
+        let l = l_shape();
+        let unit = square(0.0, 1.0);
+
+        // edges and vertices count as inside, including the reflex corner
+        assert!(l.iter().all(|p| point_in_polygon(p, &l)));
+        assert!(unit.iter().all(|p| point_in_polygon(p, &unit)));
+        assert!(point_in_polygon(&Vec2::new(1.0, 1.0), &l));
+        assert!(point_in_polygon(&Vec2::new(1.5, 1.0), &l));
+        assert!(point_in_polygon(&Vec2::new(1.0, 0.5), &unit));
+        assert!(point_in_polygon(&Vec2::new(0.0, 1.0), &unit));
+        assert!(!point_in_polygon(&Vec2::new(1.0, 1.5), &unit));
+    }
+
+    #[test]
+    fn point_in_polygon_degenerate_test() {
+        let bow_tie = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        let mut repeated = square(0.0, 1.0);
+        repeated.insert(1, repeated[1]);
+        let segment = [Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0)];
+
+        assert!(point_in_polygon(&Vec2::new(1.5, 1.0), &bow_tie));
+        assert!(!point_in_polygon(&Vec2::new(1.0, 1.5), &bow_tie));
+        assert!(point_in_polygon(&Vec2::new(0.5, 0.5), &repeated));
+        assert!(point_in_polygon(&Vec2::new(1.0, 0.0), &segment));
+        assert!(!point_in_polygon(&Vec2::new(1.0, 0.1), &segment));
+        assert!(!point_in_polygon(&Vec2::new(1.0, 0.0), &[]));
+    }
+
+    #[test]
+    fn barycentric_test() {
+        let (a, b, c) = (
+            Vec2::new(1.0, 1.0),
+            Vec2::new(3.0, 1.0),
+            Vec2::new(1.0, 5.0),
+        );
+
+        assert_eq!(barycentric(&a, &a, &b, &c), (1.0, 0.0, 0.0));
+        assert_eq!(barycentric(&c, &a, &b, &c), (0.0, 0.0, 1.0));
+        assert_eq!(
+            barycentric(&Vec2::new(2.0, 3.0), &a, &b, &c),
+            (0.0, 0.5, 0.5)
+        );
+        // the winding doesn't matter
+        assert_eq!(
+            barycentric(&Vec2::new(2.0, 3.0), &a, &c, &b),
+            (0.0, 0.5, 0.5)
+        );
+        // outside has a negative weight
+        assert_eq!(
+            barycentric(&Vec2::new(0.0, 1.0), &a, &b, &c),
+            (1.5, -0.5, 0.0)
+        );
+    }
+
+    #[test]
+    fn point_in_triangle_test() {
+        let (a, b, c) = (
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(0.0, 4.0),
+        );
+
+        assert!(point_in_triangle(&Vec2::new(1.0, 1.0), &a, &b, &c));
+        assert!(point_in_triangle(&Vec2::new(1.0, 1.0), &c, &b, &a));
+        assert!(!point_in_triangle(&Vec2::new(3.0, 3.0), &a, &b, &c));
+        assert!(!point_in_triangle(&Vec2::new(-0.1, 1.0), &a, &b, &c));
+        // edges and vertices are inside
+        assert!(point_in_triangle(&Vec2::new(2.0, 2.0), &a, &b, &c));
+        assert!(point_in_triangle(&Vec2::new(2.0, 0.0), &a, &b, &c));
+        assert!(point_in_triangle(&b, &a, &b, &c));
+        // degenerate triangles contain nothing
+        assert!(!point_in_triangle(
+            &Vec2::new(1.0, 0.0),
+            &a,
+            &b,
+            &Vec2::new(2.0, 0.0)
+        ));
+    }
+
+    #[test]
+    fn polygon_builder_test() {
+        let points = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 3.0),
+            Vec2::new(2.0, 5.0),
+            Vec2::new(0.0, 3.0),
+        ];
+        let mut builder = PolygonBuilder::new();
+        assert_eq!(builder.area(), 0.0);
+
+        for (i, p) in points.iter().enumerate() {
+            builder.push(*p);
+            let shoelace: f32 = (0..=i)
+                .map(|j| points[j].cross(&points[(j + 1) % (i + 1)]))
+                .sum();
+            assert_eq!(builder.area(), shoelace / 2.0);
+        }
+        assert_eq!(builder.area(), 16.0);
+        assert_eq!(builder.vertices(), &points);
+    }
+
+    #[test]
+    fn segment_intersection_test() {
+        let (p1, p2) = (Vec2::new(0.0, 0.0), Vec2::new(4.0, 4.0));
+
+        // crossing
+        assert_eq!(
+            segment_intersection(p1, p2, Vec2::new(0.0, 4.0), Vec2::new(4.0, 0.0)),
+            Some((Vec2::new(2.0, 2.0), 0.5, 0.5))
+        );
+        assert_eq!(
+            segment_intersection(p1, p2, Vec2::new(3.0, 0.0), Vec2::new(3.0, 4.0)),
+            Some((Vec2::new(3.0, 3.0), 0.75, 0.75))
+        );
+        // touching at an endpoint
+        assert_eq!(
+            segment_intersection(p1, p2, Vec2::new(4.0, 4.0), Vec2::new(8.0, 0.0)),
+            Some((Vec2::new(4.0, 4.0), 1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn segment_intersection_small_test() {
+        let crossing = segment_intersection(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(3e-4, 0.0),
+            Vec2::new(1.5e-4, -1.5e-4),
+            Vec2::new(1.5e-4, 1.5e-4),
+        );
+        let (point, t, u) = crossing.unwrap();
+
+        assert!(
+            (point - Vec2::new(1.5e-4, 0.0)).length() < 1e-10,
+            "{}",
+            point
+        );
+        assert!((t - 0.5).abs() < 1e-6 && (u - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn segment_intersection_miss_test() {
+        let (p1, p2) = (Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+
+        // parallel and collinear
+        assert_eq!(
+            segment_intersection(p1, p2, Vec2::new(0.0, 1.0), Vec2::new(4.0, 1.0)),
+            None
+        );
+        assert_eq!(
+            segment_intersection(p1, p2, Vec2::new(2.0, 0.0), Vec2::new(6.0, 0.0)),
+            None
+        );
+        // the lines cross just past the end of a segment
+        assert_eq!(
+            segment_intersection(p1, p2, Vec2::new(4.1, -1.0), Vec2::new(4.1, 1.0)),
+            None
+        );
+        assert_eq!(
+            segment_intersection(p1, p2, Vec2::new(2.0, 0.1), Vec2::new(2.0, 1.0)),
+            None
+        );
+    }
+}
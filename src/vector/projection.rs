@@ -0,0 +1,288 @@
+use crate::matrix::Mat4;
+use crate::vector::{Vec2, Vec3, Vec4};
+use num::Float;
+
+/// Clip space `w` below which a point counts as behind the camera
+const NEAR_W: f32 = 1e-5;
+
+/// Return the screen space rectangle `(min, max)` covering a 3D axis-aligned box
+///
+/// Each corner of `aabb_min..aabb_max` is transformed by the view projection matrix,
+/// given as its four rows, then divided by `w` and mapped onto a `viewport` sized
+/// screen with the origin at the top left (`y` pointing down).
+///
+/// Corners behind the camera are not projected (that would flip them across the
+/// screen); instead the box edges are clipped where they cross the camera plane.
+/// The result is clamped to the viewport, and a box entirely behind the camera
+/// gives an empty rectangle at the origin.
+pub fn project_aabb_to_rect(
+    aabb_min: Vec3<f32>,
+    aabb_max: Vec3<f32>,
+    view_proj_rows: [Vec4<f32>; 4],
+    viewport: Vec2<f32>,
+) -> (Vec2<f32>, Vec2<f32>) {
+    let corners: Vec<Vec4<f32>> = (0..8)
+        .map(|i| {
+            let p = Vec4::new(
+                if i & 1 == 0 { aabb_min.x } else { aabb_max.x },
+                if i & 2 == 0 { aabb_min.y } else { aabb_max.y },
+                if i & 4 == 0 { aabb_min.z } else { aabb_max.z },
+                1.0,
+            );
+            Vec4::new(
+                view_proj_rows[0].dot(&p),
+                view_proj_rows[1].dot(&p),
+                view_proj_rows[2].dot(&p),
+                view_proj_rows[3].dot(&p),
+            )
+        })
+        .collect();
+
+    let mut visible: Vec<Vec4<f32>> = corners.iter().copied().filter(|c| c.w > NEAR_W).collect();
+    // edges join corners whose indices differ by a single bit
+    for i in 0..8 {
+        for bit in [1, 2, 4] {
+            let j = i | bit;
+            let (a, b) = (corners[i], corners[j]);
+            if j != i && (a.w > NEAR_W) != (b.w > NEAR_W) {
+                visible.push(a.lerp(&b, (NEAR_W - a.w) / (b.w - a.w)));
+            }
+        }
+    }
+    if visible.is_empty() {
+        return (Vec2::zero(), Vec2::zero());
+    }
+
+    let to_screen = |c: &Vec4<f32>| {
+        let ndc = Vec2::new(c.x / c.w, c.y / c.w);
+        Vec2::new(
+            (ndc.x * 0.5 + 0.5) * viewport.x,
+            (0.5 - ndc.y * 0.5) * viewport.y,
+        )
+    };
+    let first = to_screen(&visible[0]);
+    let (min, max) = visible
+        .iter()
+        .map(to_screen)
+        .fold((first, first), |(min, max), p| (min.min(p), max.max(p)));
+
+    let origin = Vec2::zero();
+    (min.max(origin).min(viewport), max.max(origin).min(viewport))
+}
+
+/// Reconstruct a world space position from a screen `uv` and a depth buffer value
+///
+/// Uses OpenGL conventions to match `Mat4::perspective`: `uv` is in `[0, 1]` with
+/// the origin at the bottom left, and `depth` is the `[0, 1]` depth buffer value.
+/// Both are mapped to `[-1, 1]` normalized device coordinates, then transformed
+/// by the inverse view projection matrix (given as its four rows) and divided by `w`.
+pub fn position_from_depth(
+    uv: Vec2<f32>,
+    depth: f32,
+    inv_view_proj_rows: [Vec4<f32>; 4],
+) -> Vec3<f32> {
+    let ndc = Vec4::new(uv.x * 2.0 - 1.0, uv.y * 2.0 - 1.0, depth * 2.0 - 1.0, 1.0);
+    let [x, y, z, w] = inv_view_proj_rows.map(|row| row.dot(&ndc));
+    Vec3::new(x, y, z) / w
+}
+
+/// Project a world space point to window coordinates, like `gluProject`
+///
+/// `viewport` is `(x, y, width, height)`, with `y` pointing up from the bottom left
+/// as in OpenGL. The result is `(window x, window y, depth)`, with depth in `[0, 1]`
+/// between the near and far planes. Returns `None` when the point is on the
+/// camera plane (clip space `w` is zero); points behind the camera still project,
+/// mirrored, so check the depth to tell them apart.
+///
+/// ```rust
+/// # use lamar::matrix::Mat4;
+/// # use lamar::vector::{project, Vec3};
+/// let proj = Mat4::perspective(std::f64::consts::FRAC_PI_2, 2.0, 1.0, 100.0);
+/// let screen = project(Vec3::new(0.0, 0.0, -10.0), &proj, (0.0, 0.0, 800.0, 400.0)).unwrap();
+///
+/// assert_eq!((screen.x, screen.y), (400.0, 200.0));
+/// ```
+pub fn project<T: Float>(
+    world: Vec3<T>,
+    view_proj: &Mat4<T>,
+    viewport: (T, T, T, T),
+) -> Option<Vec3<T>> {
+    let clip = *view_proj * Vec4::new(world.x, world.y, world.z, T::one());
+    if clip.w == T::zero() {
+        return None;
+    }
+    let ndc = clip.xyz() / clip.w;
+    let half = T::one() / (T::one() + T::one());
+    let (x, y, width, height) = viewport;
+    Some(Vec3::new(
+        x + (ndc.x + T::one()) * half * width,
+        y + (ndc.y + T::one()) * half * height,
+        (ndc.z + T::one()) * half,
+    ))
+}
+
+/// Unproject window coordinates back to a world space point, like `gluUnProject`
+///
+/// The inverse of `project`, taking the inverse view projection matrix (see
+/// `Mat4::inverse`) and the same `viewport`. Depth 0 gives the point on the near
+/// plane and depth 1 the point on the far plane. Returns `None` when the result
+/// is at infinity (`w` is zero).
+pub fn unproject<T: Float>(
+    screen: Vec3<T>,
+    inv_view_proj: &Mat4<T>,
+    viewport: (T, T, T, T),
+) -> Option<Vec3<T>> {
+    let (o, two) = (T::one(), T::one() + T::one());
+    let (x, y, width, height) = viewport;
+    let ndc = Vec4::new(
+        (screen.x - x) / width * two - o,
+        (screen.y - y) / height * two - o,
+        screen.z * two - o,
+        o,
+    );
+    let world = *inv_view_proj * ndc;
+    if world.w == T::zero() {
+        return None;
+    }
+    Some(world.xyz() / world.w)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat4;
+    use crate::vector::{
+        position_from_depth, project, project_aabb_to_rect, unproject, Vec2, Vec3, Vec4,
+    };
+
+    fn identity() -> [Vec4<f32>; 4] {
+        [
+            Vec4::new(1.0, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 1.0, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 1.0, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+        ]
+    }
+
+    #[test]
+    fn project_aabb_to_rect_identity_test() {
+        let viewport = Vec2::new(800.0, 600.0);
+        let unit = (Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+
+        assert_eq!(
+            project_aabb_to_rect(unit.0, unit.1, identity(), viewport),
+            (Vec2::new(400.0, 0.0), Vec2::new(800.0, 300.0))
+        );
+
+        let centered = (Vec3::new(-0.5, -0.5, -0.5), Vec3::new(0.5, 0.5, 0.5));
+        assert_eq!(
+            project_aabb_to_rect(centered.0, centered.1, identity(), viewport),
+            (Vec2::new(200.0, 150.0), Vec2::new(600.0, 450.0))
+        );
+    }
+
+    #[test]
+    fn project_aabb_to_rect_behind_camera_test() {
+        let viewport = Vec2::new(800.0, 600.0);
+        // perspective divide by -z, so the camera looks down -Z
+        let mut view_proj = identity();
+        view_proj[3] = Vec4::new(0.0, 0.0, -1.0, 0.0);
+
+        let behind = project_aabb_to_rect(
+            Vec3::new(-1.0, -1.0, 1.0),
+            Vec3::new(1.0, 1.0, 2.0),
+            view_proj,
+            viewport,
+        );
+        assert_eq!(behind, (Vec2::zero(), Vec2::zero()));
+
+        // straddling the camera plane, so the box fills the screen
+        let straddling = project_aabb_to_rect(
+            Vec3::new(-1.0, -1.0, -2.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            view_proj,
+            viewport,
+        );
+        assert_eq!(straddling, (Vec2::zero(), viewport));
+    }
+
+    #[test]
+    fn position_from_depth_round_trip_test() {
+        let (fovy, aspect, near, far) = (1.0f32, 1.5, 0.1, 100.0);
+        let eye = Vec3::new(2.0, 3.0, 10.0);
+        let view = Mat4::look_at(eye, Vec3::zero(), Vec3::new(0.0, 1.0, 0.0));
+        let proj = Mat4::perspective(fovy, aspect, near, far);
+
+        // the camera's world transform undoes the (rigid) view matrix
+        let rows = [view.row(0), view.row(1), view.row(2)];
+        let inv_view = Mat4::new(
+            Vec4::new(rows[0].x, rows[0].y, rows[0].z, 0.0),
+            Vec4::new(rows[1].x, rows[1].y, rows[1].z, 0.0),
+            Vec4::new(rows[2].x, rows[2].y, rows[2].z, 0.0),
+            Vec4::new(eye.x, eye.y, eye.z, 1.0),
+        );
+        let f = 1.0 / (fovy / 2.0).tan();
+        let (a, b) = ((far + near) / (near - far), 2.0 * far * near / (near - far));
+        let inv_proj = Mat4::new(
+            Vec4::new(aspect / f, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 1.0 / f, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, 1.0 / b),
+            Vec4::new(0.0, 0.0, -1.0, a / b),
+        );
+        let inv = inv_view * inv_proj;
+        let inv_rows = [inv.row(0), inv.row(1), inv.row(2), inv.row(3)];
+
+        for world in [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, -0.5, 2.0),
+            Vec3::new(-3.0, 1.0, -20.0),
+        ] {
+            let clip = proj * view * Vec4::new(world.x, world.y, world.z, 1.0);
+            let ndc = Vec3::new(clip.x, clip.y, clip.z) / clip.w;
+            let uv = Vec2::new(ndc.x * 0.5 + 0.5, ndc.y * 0.5 + 0.5);
+            let depth = ndc.z * 0.5 + 0.5;
+
+            let reconstructed = position_from_depth(uv, depth, inv_rows);
+            assert!((reconstructed - world).length() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn project_round_trip_test() {
+        let view_proj = Mat4::perspective(0.9, 1.5, 0.5, 200.0)
+            * Mat4::look_at(
+                Vec3::new(3.0, 2.0, 8.0),
+                Vec3::new(0.0, 0.5, 0.0),
+                Vec3::new(0.0, 1.0, 0.0),
+            );
+        let inverse = view_proj.inverse().unwrap();
+        let viewport = (10.0, 20.0, 1280.0, 720.0);
+
+        for world in [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, -2.0, 0.5),
+            Vec3::new(-20.0, 5.0, -60.0),
+        ] {
+            let screen = project(world, &view_proj, viewport).unwrap();
+            let back = unproject(screen, &inverse, viewport).unwrap();
+
+            assert!(screen.z > 0.0 && screen.z < 1.0);
+            assert!((back - world).length() < 1e-9, "{} != {}", back, world);
+        }
+    }
+
+    #[test]
+    fn project_viewport_test() {
+        let proj = Mat4::perspective(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+        let viewport = (100.0, 50.0, 200.0, 200.0);
+
+        // the top right edge of the view, on the near and far planes
+        assert_eq!(
+            project(Vec3::new(1.0, 1.0, -1.0), &proj, viewport),
+            Some(Vec3::new(300.0, 250.0, 0.0))
+        );
+        let far = project(Vec3::new(-10.0, -10.0, -10.0), &proj, viewport).unwrap();
+        assert!((far - Vec3::new(100.0, 50.0, 1.0)).length() < 1e-12);
+        // on the camera plane
+        assert_eq!(project(Vec3::new(1.0, 0.0, 0.0), &proj, viewport), None);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::vector::{Vec2, Vec3, Vec4};
+use num::{Float, Num};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+/// Return `tau` (a full turn in radians) as the scalar type
+fn tau<T: Float>() -> T {
+    T::from(std::f64::consts::TAU).unwrap()
+}
+
+/// Allows for `rng.gen::<Vec2<T>>()`, sampling each component independently
+/// from `T`'s own standard distribution (`[0, 1)` for floats, the full range for
+/// integers)
+impl<T> Distribution<Vec2<T>> for Standard
+where
+    T: Num + Copy,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2<T> {
+        Vec2::new(rng.gen(), rng.gen())
+    }
+}
+
+/// Allows for `rng.gen::<Vec3<T>>()`, sampling each component independently
+/// from `T`'s own standard distribution (`[0, 1)` for floats, the full range for
+/// integers)
+impl<T> Distribution<Vec3<T>> for Standard
+where
+    T: Num + Clone + Copy,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3<T> {
+        Vec3::new(rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+/// Allows for `rng.gen::<Vec4<T>>()`, sampling each component independently
+/// from `T`'s own standard distribution (`[0, 1)` for floats, the full range for
+/// integers)
+impl<T> Distribution<Vec4<T>> for Standard
+where
+    T: Num + Clone + Copy,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec4<T> {
+        Vec4::new(rng.gen(), rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Float,
+    Standard: Distribution<T>,
+{
+    /// Return a random unit 2D Vector, uniformly distributed around the circle
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let (sin, cos) = (rng.gen::<T>() * tau()).sin_cos();
+        Self::new(cos, sin)
+    }
+
+    /// Return a random point uniformly distributed inside the unit disk
+    ///
+    /// The radius is the square root of a uniform sample, so the points aren't
+    /// bunched up towards the centre.
+    pub fn random_in_unit_disk<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let r = rng.gen::<T>().sqrt();
+        Self::random_unit(rng) * r
+    }
+}
+
+impl<T> Vec3<T>
+where
+    T: Float,
+    Standard: Distribution<T>,
+{
+    /// Return a random unit 3D Vector, uniformly distributed over the sphere
+    ///
+    /// Picks `z` uniformly in `[-1, 1]` and an angle around the Z axis (Archimedes'
+    /// hat-box theorem), rather than normalizing a point in a cube, which would
+    /// favour the directions towards its corners.
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let o = T::one();
+        let z = o - (o + o) * rng.gen::<T>();
+        let r = (o - z * z).max(T::zero()).sqrt();
+        let (sin, cos) = (rng.gen::<T>() * tau()).sin_cos();
+        Self::new(r * cos, r * sin, z)
+    }
+
+    /// Return a random point uniformly distributed inside the unit sphere
+    pub fn random_in_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let r = rng.gen::<T>().cbrt();
+        Self::random_unit(rng) * r
+    }
+
+    /// Return a random point uniformly distributed inside the box from `min` to `max`
+    pub fn random_in_aabb<R: Rng + ?Sized>(rng: &mut R, min: &Vec3<T>, max: &Vec3<T>) -> Self {
+        let mut sample = |lo: T, hi: T| lo + (hi - lo) * rng.gen::<T>();
+        Self::new(
+            sample(min.x, max.x),
+            sample(min.y, max.y),
+            sample(min.z, max.z),
+        )
+    }
+
+    /// Return a random unit direction in the hemisphere around `normal`,
+    /// cosine-weighted so directions near `normal` are more likely
+    ///
+    /// The probability density is `cos(theta) / pi`, which cancels the cosine term
+    /// of diffuse lighting. `normal` must be normalized.
+    pub fn random_cosine_hemisphere<R: Rng + ?Sized>(rng: &mut R, normal: &Vec3<T>) -> Self {
+        // project a uniform disk sample up onto the hemisphere (Malley's method)
+        let disk = Vec2::random_in_unit_disk(rng);
+        let up = (T::one() - disk.length_squared()).max(T::zero()).sqrt();
+        let (tangent, bitangent) = normal.any_orthonormal_pair();
+        tangent * disk.x + bitangent * disk.y + *normal * up
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{Vec2, Vec3, Vec4};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const SAMPLES: usize = 10_000;
+
+    #[test]
+    fn random_unit_test() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut mean2 = Vec2::new(0.0, 0.0);
+        let mut mean3 = Vec3::new(0.0, 0.0, 0.0);
+
+        for _ in 0..SAMPLES {
+            let v2: Vec2<f64> = Vec2::random_unit(&mut rng);
+            let v3: Vec3<f64> = Vec3::random_unit(&mut rng);
+            assert!((v2.length() - 1.0).abs() < 1e-12);
+            assert!((v3.length() - 1.0).abs() < 1e-12);
+            mean2 = mean2 + v2 / SAMPLES as f64;
+            mean3 = mean3 + v3 / SAMPLES as f64;
+        }
+
+        assert!(mean2.length() < 0.05, "{}", mean2);
+        assert!(mean3.length() < 0.05, "{}", mean3);
+    }
+
+    #[test]
+    fn random_unit_is_uniform_test() {
+        // a uniform sphere puts a third of its area in each band of equal height
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut counts = [0; 3];
+
+        for _ in 0..SAMPLES {
+            let v: Vec3<f32> = Vec3::random_unit(&mut rng);
+            counts[((v.x + 1.0) * 1.5).min(2.0) as usize] += 1;
+        }
+
+        for count in counts {
+            assert!(
+                (count as f32 / SAMPLES as f32 - 1.0 / 3.0).abs() < 0.02,
+                "{:?}",
+                counts
+            );
+        }
+    }
+
+    #[test]
+    fn random_in_volume_test() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let (min, max) = (Vec3::new(-1.0, 2.0, 0.5), Vec3::new(3.0, 2.5, 4.0));
+        let mut inner = 0;
+
+        for _ in 0..SAMPLES {
+            let disk: Vec2<f64> = Vec2::random_in_unit_disk(&mut rng);
+            let ball: Vec3<f64> = Vec3::random_in_unit_sphere(&mut rng);
+            let boxed = Vec3::random_in_aabb(&mut rng, &min, &max);
+
+            assert!(disk.length() <= 1.0);
+            assert!(ball.length() <= 1.0);
+            assert!(boxed.cmpge(&min).all() && boxed.cmple(&max).all());
+            // the inner half of the radius holds an eighth of the ball's volume
+            if ball.length() < 0.5 {
+                inner += 1;
+            }
+        }
+
+        assert!((inner as f64 / SAMPLES as f64 - 0.125).abs() < 0.02);
+    }
+
+    #[test]
+    fn random_cosine_hemisphere_test() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let normal = Vec3::new(1.0, 2.0, -2.0) / 3.0;
+        let mut mean_cos = 0.0;
+
+        for _ in 0..SAMPLES {
+            let d: Vec3<f64> = Vec3::random_cosine_hemisphere(&mut rng, &normal);
+
+            assert!((d.length() - 1.0).abs() < 1e-9);
+            assert!(d.dot(&normal) >= 0.0);
+            mean_cos += d.dot(&normal) / SAMPLES as f64;
+        }
+
+        // the mean of cos(theta) under a cos(theta) / pi density is 2/3
+        assert!((mean_cos - 2.0 / 3.0).abs() < 0.02, "{}", mean_cos);
+    }
+
+    #[test]
+    fn seeded_is_deterministic_test() {
+        let a: Vec3<f32> = Vec3::random_unit(&mut StdRng::seed_from_u64(42));
+        let b: Vec3<f32> = Vec3::random_unit(&mut StdRng::seed_from_u64(42));
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn standard_distribution_test() {
+        let mut a = StdRng::seed_from_u64(9);
+        let mut b = StdRng::seed_from_u64(9);
+
+        for _ in 0..100 {
+            let v: Vec3<f32> = a.gen();
+            assert_eq!(v, b.gen());
+            assert!(v.cmpge(&Vec3::new(0.0, 0.0, 0.0)).all());
+            assert!(v.cmplt(&Vec3::new(1.0, 1.0, 1.0)).all());
+        }
+
+        // integer vectors are reproducible too
+        assert_eq!(a.gen::<Vec2<i32>>(), b.gen::<Vec2<i32>>());
+
+        // components are independent draws, not one value repeated
+        let v: Vec4<f64> = a.gen();
+        assert!(v.x != v.y && v.y != v.z && v.z != v.w);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::vector::{Vec3, Vec4};
+
+/// Return the Hamilton product `a * b` of two quaternions stored as `Vec4`s
+/// (`x, y, z` is the vector part, `w` the scalar part)
+fn quat_mul(a: &Vec4<f32>, b: &Vec4<f32>) -> Vec4<f32> {
+    Vec4::new(
+        a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+        a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+    )
+}
+
+/// Split the rotation `q` into a swing and a twist about `axis`
+///
+/// `q` is a unit quaternion stored as a `Vec4` (`x, y, z` is the vector part,
+/// `w` the scalar part) and `axis` must be normalized. Returns `(swing, twist)`
+/// where `twist` rotates purely about `axis`, `swing` rotates about an axis
+/// perpendicular to it, and `swing * twist == q`.
+///
+/// When `q` is a half turn about an axis perpendicular to `axis` the twist is
+/// undefined, so the identity twist is returned.
+pub fn swing_twist_decompose(q: &Vec4<f32>, axis: &Vec3<f32>) -> (Vec4<f32>, Vec4<f32>) {
+    let p = *axis * Vec3::new(q.x, q.y, q.z).dot(axis);
+    let twist = Vec4::new(p.x, p.y, p.z, q.w);
+
+    let twist = if twist.length_squared() < 1e-12 {
+        Vec4::new(0.0, 0.0, 0.0, 1.0)
+    } else {
+        twist.normalize()
+    };
+    let twist_conjugate = Vec4::new(-twist.x, -twist.y, -twist.z, twist.w);
+    let swing = quat_mul(q, &twist_conjugate);
+
+    (swing, twist)
+}
+
+#[cfg(test)]
+mod test {
+    use super::quat_mul;
+    use crate::vector::{swing_twist_decompose, Vec3, Vec4};
+
+    fn axis_angle(axis: Vec3<f32>, angle: f32) -> Vec4<f32> {
+        let s = (angle / 2.0).sin();
+        Vec4::new(axis.x * s, axis.y * s, axis.z * s, (angle / 2.0).cos())
+    }
+
+    fn assert_close(a: Vec4<f32>, b: Vec4<f32>) {
+        assert!((a - b).length() < 1e-5, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn swing_twist_reconstructs_test() {
+        let axis = Vec3::new(0.0, 0.0, 1.0);
+        let swing = axis_angle(Vec3::new(1.0, 0.0, 0.0), 0.4);
+        let twist = axis_angle(axis, 0.7);
+        let q = quat_mul(&swing, &twist);
+
+        let (s, t) = swing_twist_decompose(&q, &axis);
+
+        assert_close(quat_mul(&s, &t), q);
+        assert_close(s, swing);
+        assert_close(t, twist);
+    }
+
+    #[test]
+    fn swing_twist_axes_test() {
+        let axis = Vec3::new(1.0, 2.0, 2.0) / 3.0;
+        let q = axis_angle(Vec3::new(0.0, 0.6, 0.8), 1.1);
+
+        let (s, t) = swing_twist_decompose(&q, &axis);
+        let twist_axis = Vec3::new(t.x, t.y, t.z);
+        let swing_axis = Vec3::new(s.x, s.y, s.z);
+
+        assert_close(quat_mul(&s, &t), q);
+        assert!(twist_axis.cross(&axis).length() < 1e-5);
+        assert!(swing_axis.dot(&axis).abs() < 1e-5);
+        assert!((t.length() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn swing_twist_degenerate_test() {
+        // a half turn about X has no twist component about Z
+        let q = axis_angle(Vec3::new(1.0, 0.0, 0.0), std::f32::consts::PI);
+
+        let (s, t) = swing_twist_decompose(&q, &Vec3::new(0.0, 0.0, 1.0));
+
+        assert_close(t, Vec4::new(0.0, 0.0, 0.0, 1.0));
+        assert_close(s, q);
+    }
+}
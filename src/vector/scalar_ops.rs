@@ -0,0 +1,75 @@
+//! Operators with the scalar on the left hand side, e.g. `2.0 * v`.
+//!
+//! A blanket `impl<T> Mul<Vec3<T>> for T` is not allowed by the orphan rules, so
+//! these are implemented for each primitive scalar type instead.
+
+use crate::vector::{Vec2, Vec3, Vec4};
+use std::ops::{Add, Div, Mul, Sub};
+
+macro_rules! impl_scalar_lhs_op {
+    ($scalar:ty, $vec:ident { $($c:ident),+ }, $op:ident, $method:ident, $sym:tt) => {
+        impl $op<$vec<$scalar>> for $scalar {
+            type Output = $vec<$scalar>;
+
+            fn $method(self, other: $vec<$scalar>) -> Self::Output {
+                $vec {
+                    $($c: self $sym other.$c),+
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_lhs_ops {
+    ($($scalar:ty),+) => {
+        $(
+            impl_scalar_lhs_op!($scalar, Vec2 { x, y }, Add, add, +);
+            impl_scalar_lhs_op!($scalar, Vec2 { x, y }, Sub, sub, -);
+            impl_scalar_lhs_op!($scalar, Vec2 { x, y }, Mul, mul, *);
+            impl_scalar_lhs_op!($scalar, Vec2 { x, y }, Div, div, /);
+            impl_scalar_lhs_op!($scalar, Vec3 { x, y, z }, Add, add, +);
+            impl_scalar_lhs_op!($scalar, Vec3 { x, y, z }, Sub, sub, -);
+            impl_scalar_lhs_op!($scalar, Vec3 { x, y, z }, Mul, mul, *);
+            impl_scalar_lhs_op!($scalar, Vec3 { x, y, z }, Div, div, /);
+            impl_scalar_lhs_op!($scalar, Vec4 { x, y, z, w }, Add, add, +);
+            impl_scalar_lhs_op!($scalar, Vec4 { x, y, z, w }, Sub, sub, -);
+            impl_scalar_lhs_op!($scalar, Vec4 { x, y, z, w }, Mul, mul, *);
+            impl_scalar_lhs_op!($scalar, Vec4 { x, y, z, w }, Div, div, /);
+        )+
+    };
+}
+
+// `Div` and `Sub` apply the scalar to each component, i.e. `s / v` is `(s / x, s / y, ...)`
+impl_scalar_lhs_ops!(f32, f64, i32, i64, u32, u64);
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn scalar_mul_test() {
+        assert_eq!(3 * Vec3::new(1, 2, 3), Vec3::new(3, 6, 9));
+        assert_eq!(2.0 * Vec2::new(1.5, -2.0), Vec2::new(3.0, -4.0));
+        assert_eq!(2u64 * Vec4::new(1, 2, 3, 4), Vec4::new(2, 4, 6, 8));
+        assert_eq!(
+            3.0f64 * Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(1.0, 2.0, 3.0) * 3.0
+        );
+    }
+
+    #[test]
+    fn scalar_add_sub_test() {
+        assert_eq!(1 + Vec3::new(1, 2, 3), Vec3::new(2, 3, 4));
+        assert_eq!(10i64 - Vec2::new(1, 12), Vec2::new(9, -2));
+        assert_eq!(
+            1.0f32 - Vec4::new(0.25, 0.5, 0.75, 1.0),
+            Vec4::new(0.75, 0.5, 0.25, 0.0)
+        );
+    }
+
+    #[test]
+    fn scalar_div_test() {
+        assert_eq!(1.0 / Vec2::new(2.0, 4.0), Vec2::new(0.5, 0.25));
+        assert_eq!(12u32 / Vec3::new(1, 2, 3), Vec3::new(12, 6, 4));
+    }
+}
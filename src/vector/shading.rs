@@ -0,0 +1,90 @@
+use crate::geometry::Ray3;
+use crate::vector::Vec3;
+
+/// Return the per-channel Fresnel reflectance using Schlick's approximation
+///
+/// `f0 + (1 - f0) * (1 - cos_theta)^5`, where `cos_theta` is the cosine of the
+/// angle between the view direction and the surface normal and `f0` is the
+/// reflectance at normal incidence. `cos_theta` is clamped to `[0, 1]`.
+pub fn fresnel_schlick(cos_theta: f32, f0: Vec3<f32>) -> Vec3<f32> {
+    let t = (1.0 - cos_theta.clamp(0.0, 1.0)).powi(5);
+    f0.lerp(&Vec3::new(1.0, 1.0, 1.0), t)
+}
+
+/// Parallax-correct a reflection direction against a box projected reflection probe
+///
+/// The reflection ray from `frag_pos` is intersected with the probe volume
+/// `box_min..box_max`, and the returned (normalized) direction points from
+/// `probe_center` to the hit, which is what should be used to sample the probe's
+/// cubemap. If the ray misses the box (the fragment is outside it and facing
+/// away) `reflect_dir` is returned normalized and uncorrected.
+pub fn parallax_correct(
+    reflect_dir: &Vec3<f32>,
+    frag_pos: &Vec3<f32>,
+    probe_center: &Vec3<f32>,
+    box_min: &Vec3<f32>,
+    box_max: &Vec3<f32>,
+) -> Vec3<f32> {
+    let ray = Ray3::new(*frag_pos, *reflect_dir);
+    match ray.intersect_aabb(*box_min, *box_max) {
+        Some((_, exit)) => (ray.point_at(exit) - *probe_center).normalize(),
+        None => reflect_dir.normalize(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{fresnel_schlick, parallax_correct, Vec3};
+
+    #[test]
+    fn fresnel_schlick_normal_incidence_test() {
+        let f0 = Vec3::new(0.04, 0.5, 0.9);
+
+        assert_eq!(fresnel_schlick(1.0, f0), f0);
+    }
+
+    #[test]
+    fn fresnel_schlick_grazing_test() {
+        let f0 = Vec3::new(0.04, 0.5, 0.9);
+
+        assert_eq!(fresnel_schlick(0.0, f0), Vec3::new(1.0, 1.0, 1.0));
+
+        let near_grazing = fresnel_schlick(0.01, f0);
+        for c in near_grazing {
+            assert!(c > 0.95 && c <= 1.0);
+        }
+    }
+
+    #[test]
+    fn fresnel_schlick_increases_with_angle_test() {
+        let f0 = Vec3::new(0.04, 0.04, 0.04);
+
+        let mut last = 0.0;
+        for i in (0..=10).rev() {
+            let r = fresnel_schlick(i as f32 / 10.0, f0).x;
+            assert!(r >= last);
+            last = r;
+        }
+    }
+
+    #[test]
+    fn parallax_correct_center_test() {
+        let center = Vec3::new(1.0, 2.0, 3.0);
+        let (min, max) = (center - 5.0, center + 5.0);
+        let dir = Vec3::new(0.3, -0.5, 0.8);
+
+        let corrected = parallax_correct(&dir, &center, &center, &min, &max);
+        assert!((corrected - dir.normalize()).length() < 1e-6);
+    }
+
+    #[test]
+    fn parallax_correct_offset_test() {
+        let center = Vec3::new(0.0, 0.0, 0.0);
+        let (min, max) = (Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+        // looking along +X from a fragment offset along +Y hits the wall at (1, 0.5, 0)
+        let frag = Vec3::new(0.0, 0.5, 0.0);
+
+        let corrected = parallax_correct(&Vec3::new(1.0, 0.0, 0.0), &frag, &center, &min, &max);
+        assert!((corrected - Vec3::new(1.0, 0.5, 0.0).normalize()).length() < 1e-6);
+    }
+}
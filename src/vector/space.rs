@@ -0,0 +1,130 @@
+use crate::vector::{Vec2, Vec3, Vec4};
+use num::{Num, Zero};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// The operations shared by `Vec2`, `Vec3` and `Vec4`, for code that works in any dimension.
+///
+/// Only the component-wise arithmetic is required: `Vec2 * Vec2` and
+/// `Vec3 * Vec3` are cross products, so multiplying two vectors isn't part of it.
+/// The zero vector comes from the `num::Zero` supertrait.
+///
+/// ```rust
+/// # use lamar::vector::{Vec2, Vec3, VectorSpace};
+/// use num::{One, Zero};
+///
+/// fn centroid<V: VectorSpace>(points: &[V]) -> V {
+///     let mut n = V::Scalar::zero();
+///     let mut sum = V::zero();
+///     for p in points {
+///         sum = sum + *p;
+///         n = n + V::Scalar::one();
+///     }
+///     sum / n
+/// }
+///
+/// assert_eq!(centroid(&[Vec2::new(0.0, 0.0), Vec2::new(2.0, 4.0)]), Vec2::new(1.0, 2.0));
+/// assert_eq!(centroid(&[Vec3::new(1, 2, 3)]), Vec3::new(1, 2, 3));
+/// ```
+pub trait VectorSpace:
+    Copy
+    + Zero
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<<Self as VectorSpace>::Scalar, Output = Self>
+    + Div<<Self as VectorSpace>::Scalar, Output = Self>
+{
+    /// The type of each component
+    type Scalar: Num + Copy;
+
+    /// Return the dot product of two vectors
+    fn dot(&self, other: &Self) -> Self::Scalar;
+
+    /// Return the squared length of the vector
+    fn length_squared(&self) -> Self::Scalar;
+
+    /// Linearly interpolate between two vectors
+    ///
+    /// `a + (b - a) * t`
+    fn lerp(&self, other: &Self, t: Self::Scalar) -> Self;
+}
+
+macro_rules! vector_space {
+    ($($vec:ident),+ $(,)?) => {
+        $(
+            impl<T> VectorSpace for $vec<T>
+            where
+                T: Num + Copy,
+            {
+                type Scalar = T;
+
+                fn dot(&self, other: &Self) -> T {
+                    $vec::dot(self, other)
+                }
+
+                fn length_squared(&self) -> T {
+                    $vec::length_squared(self)
+                }
+
+                fn lerp(&self, other: &Self, t: T) -> Self {
+                    $vec::lerp(self, other, t)
+                }
+            }
+        )+
+    };
+}
+
+vector_space!(Vec2, Vec3, Vec4);
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{Vec2, Vec3, Vec4, VectorSpace};
+    use num::{One, Zero};
+
+    fn average<V: VectorSpace>(a: V, b: V) -> V {
+        let two = V::Scalar::one() + V::Scalar::one();
+        (a + b) / two
+    }
+
+    // a generic function using every method, to check they all reach the vector types
+    fn projected_length_squared<V: VectorSpace>(v: V, onto: V) -> V::Scalar {
+        let scale = v.dot(&onto) / onto.length_squared();
+        (V::zero().lerp(&onto, scale)).length_squared()
+    }
+
+    #[test]
+    fn average_test() {
+        assert_eq!(
+            average(Vec2::new(1.0, 2.0), Vec2::new(3.0, 6.0)),
+            Vec2::new(2.0, 4.0)
+        );
+        assert_eq!(
+            average(Vec3::new(0.0, -2.0, 4.0), Vec3::new(2.0, 2.0, 0.0)),
+            Vec3::new(1.0, 0.0, 2.0)
+        );
+        assert_eq!(
+            average(Vec4::new(2, 4, 6, 8), Vec4::new(0, 0, 0, 0)),
+            Vec4::new(1, 2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn vector_space_methods_test() {
+        assert_eq!(
+            projected_length_squared(Vec2::new(3.0, 4.0), Vec2::new(2.0, 0.0)),
+            9.0
+        );
+        assert_eq!(
+            projected_length_squared(Vec3::new(1.0, 2.0, 3.0), Vec3::new(0.0, 0.0, 1.0)),
+            9.0
+        );
+        assert_eq!(<Vec4<i32> as Zero>::zero(), Vec4::new(0, 0, 0, 0));
+    }
+
+    #[test]
+    fn zero_with_both_traits_test() {
+        // with `VectorSpace` and `Zero` both in scope there is only one `zero`
+        assert_eq!(Vec3::<f64>::zero(), Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(Vec2::<i32>::zero(), Vec2::new(0, 0));
+        assert!(Vec4::<f64>::zero().is_zero());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::vector::{catmull_rom, Vec3};
+
+/// Sample a Catmull-Rom spline passing through every point in `points`
+///
+/// `t` in `[0, 1]` spans the whole path (and is clamped to it), with each segment
+/// taking an equal share. The first and last points are duplicated to provide the
+/// missing end tangents, so the path starts and ends exactly on them.
+///
+/// # Panics
+///
+/// Panics if `points` is empty.
+pub fn catmull_rom_path(points: &[Vec3<f32>], t: f32) -> Vec3<f32> {
+    assert!(
+        !points.is_empty(),
+        "catmull_rom_path needs at least one point"
+    );
+    if points.len() == 1 {
+        return points[0];
+    }
+
+    let segments = points.len() - 1;
+    let s = t.clamp(0.0, 1.0) * segments as f32;
+    let i = (s.floor() as usize).min(segments - 1);
+    let local = s - i as f32;
+
+    let p0 = points[i.saturating_sub(1)];
+    let p3 = points[(i + 2).min(segments)];
+    catmull_rom(p0, points[i], points[i + 1], p3, local)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{catmull_rom_path, Vec3};
+
+    fn waypoints() -> Vec<Vec3<f32>> {
+        vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(3.0, 2.0, 1.0),
+            Vec3::new(4.0, 0.0, -1.0),
+            Vec3::new(6.0, 1.0, 0.0),
+        ]
+    }
+
+    #[test]
+    fn catmull_rom_path_control_points_test() {
+        let points = waypoints();
+        let segments = (points.len() - 1) as f32;
+
+        for (i, p) in points.iter().enumerate() {
+            let sampled = catmull_rom_path(&points, i as f32 / segments);
+            assert!((sampled - *p).length() < 1e-5, "{} != {}", sampled, p);
+        }
+    }
+
+    #[test]
+    fn catmull_rom_path_clamp_test() {
+        let points = waypoints();
+
+        assert_eq!(catmull_rom_path(&points, -1.0), points[0]);
+        assert_eq!(catmull_rom_path(&points, 2.0), points[4]);
+        assert_eq!(catmull_rom_path(&points[..1], 0.5), points[0]);
+    }
+
+    #[test]
+    fn catmull_rom_path_straight_line_test() {
+        let points = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(2.0, 2.0, 2.0),
+        ];
+
+        let p = catmull_rom_path(&points, 0.25);
+        assert!((p.x - p.y).abs() < 1e-6 && (p.y - p.z).abs() < 1e-6);
+        assert!((catmull_rom_path(&points, 0.5) - points[1]).length() < 1e-6);
+    }
+}
@@ -0,0 +1,96 @@
+//! Swizzle accessors, returning some of a vector's components in a new order.
+//!
+//! Generating every combination would add hundreds of methods (256 for the 4
+//! component swizzles of a `Vec4` alone), so only the most used ones are here.
+//! `Vec4::xyz` lives with the homogeneous coordinate helpers.
+
+use crate::vector::{Vec2, Vec3, Vec4};
+use num::Num;
+
+macro_rules! swizzles {
+    ($vec:ident => $out:ident { $($name:ident: $($c:ident)+),+ $(,)? }) => {
+        impl<T> $vec<T>
+        where
+            T: Num + Copy,
+        {
+            $(
+                #[doc = concat!("Return the `", stringify!($name), "` swizzle as a ", stringify!($out))]
+                pub fn $name(&self) -> $out<T> {
+                    $out::new($(self.$c),+)
+                }
+            )+
+        }
+    };
+}
+
+swizzles!(Vec2 => Vec2 { yx: y x });
+
+swizzles!(Vec3 => Vec2 {
+    xy: x y,
+    xz: x z,
+    yz: y z,
+    yx: y x,
+    zx: z x,
+    zy: z y,
+});
+swizzles!(Vec3 => Vec3 {
+    xzy: x z y,
+    yzx: y z x,
+    zxy: z x y,
+    zyx: z y x,
+});
+
+swizzles!(Vec4 => Vec2 {
+    xy: x y,
+    xz: x z,
+    xw: x w,
+    yz: y z,
+    zw: z w,
+});
+swizzles!(Vec4 => Vec3 {
+    xyw: x y w,
+    xzw: x z w,
+    yzw: y z w,
+    zyx: z y x,
+});
+swizzles!(Vec4 => Vec4 {
+    wxyz: w x y z,
+    yzwx: y z w x,
+    wzyx: w z y x,
+});
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn truncating_swizzle_test() {
+        let v = Vec4::new(1, 2, 3, 4);
+
+        assert_eq!(v.xyz(), Vec3::new(1, 2, 3));
+        assert_eq!(v.yzw(), Vec3::new(2, 3, 4));
+        assert_eq!(v.xy(), Vec2::new(1, 2));
+        assert_eq!(v.zw(), Vec2::new(3, 4));
+        assert_eq!(Vec3::new(1, 2, 3).xz(), Vec2::new(1, 3));
+    }
+
+    #[test]
+    fn reversing_swizzle_test() {
+        let v = Vec4::new(1, 2, 3, 4);
+
+        assert_eq!(v.wzyx(), Vec4::new(4, 3, 2, 1));
+        assert_eq!(v.wzyx().wzyx(), v);
+        assert_eq!(v.zyx(), Vec3::new(3, 2, 1));
+        assert_eq!(Vec3::new(1, 2, 3).zyx(), Vec3::new(3, 2, 1));
+        assert_eq!(Vec2::new(1, 2).yx(), Vec2::new(2, 1));
+    }
+
+    #[test]
+    fn rotating_swizzle_test() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v.yzx().yzx().yzx(), v);
+        assert_eq!(v.zxy(), Vec3::new(3.0, 1.0, 2.0));
+        assert_eq!(Vec4::new(1, 2, 3, 4).yzwx(), Vec4::new(2, 3, 4, 1));
+    }
+}
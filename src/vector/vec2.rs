@@ -1,12 +1,29 @@
-use num::Num;
+use crate::matrix::Mat2;
+use crate::scalar;
+use crate::vector::iter::{collect_components, ComponentCountError};
+use crate::vector::parse::{parse_components, ParseVecError};
+use crate::vector::{BVec2, Vec3};
+use num::{
+    traits::{
+        AsPrimitive, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Euclid, SaturatingAdd,
+        SaturatingMul, SaturatingSub, WrappingAdd, WrappingMul, WrappingSub,
+    },
+    Float, Num, NumCast, Signed, Zero,
+};
 use std::{
     fmt::Display,
-    ops::{Add, Div, Mul, Sub},
+    iter::{FromIterator, Sum},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
 };
 
 /// A generic 2D Vector implementation.
 /// Takes 2 generic numbers (both must be same type).
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+///
+/// Vectors are ordered lexicographically (compare x, then y), which gives a
+/// deterministic total order for sorting and `BTreeSet`s. This is not a
+/// geometric comparison: `a < b` says nothing about their lengths.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct Vec2<T>
 where
     T: Num + Copy,
@@ -38,261 +55,2150 @@ where
         self.x * rhs.y - self.y * rhs.x
     }
 
-    // TODO: Swizzle?
-}
+    /// Return the perp-dot product of two 2D Vectors
+    ///
+    /// The 2D "cross" product is really the dot product with the perpendicular
+    /// vector, `a.perp().dot(&b) == a.cross(&b)`, so this is an alias for `cross`.
+    /// It is positive when `b` is counter-clockwise from `a`.
+    pub fn perp_dot(&self, rhs: &Vec2<T>) -> T {
+        self.cross(rhs)
+    }
 
-impl Vec2<f32> {
-    /// Creates a 2D Vector with all values set to 0.0
-    pub fn zero() -> Self {
-        Self { x: 0.0, y: 0.0 }
+    /// Return the 2D Vector crossed with a vector of length `s` along the Z axis
+    ///
+    /// `(s * y, -s * x)`, the XY part of `(x, y, 0).cross((0, 0, s))`. The velocity
+    /// of a point at offset `r` on a body spinning at `w` radians per second is
+    /// `w x r`, which is `r.cross_scalar(-w)`.
+    pub fn cross_scalar(&self, s: T) -> Vec2<T>
+    where
+        T: Neg<Output = T>,
+    {
+        Vec2 {
+            x: s * self.y,
+            y: -(s * self.x),
+        }
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec2;
-/// let a = Vec2::new(10, 12);
-/// let b = Vec2::new(16, 48);
-/// let c = a + b;
-///
-/// assert_eq!(c, Vec2::new(a.x + b.x, a.y + b.y));
-/// ```
-impl<T> Add for Vec2<T>
-where
-    T: Num + Copy,
-{
-    type Output = Vec2<T>;
+    /// Return the full 3D cross product of two 2D Vectors lifted into the XY plane
+    ///
+    /// `(0, 0, a.cross(&b))`
+    pub fn cross3(&self, rhs: &Vec2<T>) -> Vec3<T> {
+        Vec3::new(T::zero(), T::zero(), self.cross(rhs))
+    }
 
-    fn add(self, other: Vec2<T>) -> Self::Output {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
+    /// Return the vector rotated 90 degrees counter-clockwise
+    ///
+    /// `(-y, x)`
+    pub fn perp(&self) -> Vec2<T>
+    where
+        T: Neg<Output = T>,
+    {
+        Vec2 {
+            x: -self.y,
+            y: self.x,
         }
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec2;
-/// let a = Vec2::new(10, 12);
-/// let b = 10;
-/// let c = a + b;
-///
-/// assert_eq!(c, Vec2::new(10 + 10, 12 + 10));
-/// ```
-impl<T> Add<T> for Vec2<T>
-where
-    T: Num + Copy,
-{
-    type Output = Vec2<T>;
+    /// Return the vector rotated 90 degrees counter-clockwise
+    ///
+    /// The same as `perp`, exact for integer vectors.
+    pub fn rotate90(&self) -> Vec2<T>
+    where
+        T: Neg<Output = T>,
+    {
+        self.perp()
+    }
 
-    fn add(self, other: T) -> Self::Output {
-        Self {
-            x: self.x + other,
-            y: self.y + other,
+    /// Return the vector rotated 180 degrees
+    ///
+    /// `(-x, -y)`
+    pub fn rotate180(&self) -> Vec2<T>
+    where
+        T: Neg<Output = T>,
+    {
+        Vec2 {
+            x: -self.x,
+            y: -self.y,
         }
     }
-}
-
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec2;
-/// let a = Vec2::new(10, 12);
-/// let b = Vec2::new(16, 48);
-/// let c = a - b;
-///
-/// assert_eq!(c, Vec2::new(10 - 16, 12 - 48));
-/// ```
-impl<T> Sub for Vec2<T>
-where
-    T: Num + Copy,
-{
-    type Output = Vec2<T>;
 
-    fn sub(self, other: Vec2<T>) -> Self::Output {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
+    /// Return the vector rotated 270 degrees counter-clockwise (90 degrees clockwise)
+    ///
+    /// `(y, -x)`
+    pub fn rotate270(&self) -> Vec2<T>
+    where
+        T: Neg<Output = T>,
+    {
+        Vec2 {
+            x: self.y,
+            y: -self.x,
         }
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec2;
-/// let a = Vec2::new(10, 12);
-/// let b = 10;
-/// let c = a - b;
-///
-/// assert_eq!(c, Vec2::new(10 - 10, 12 - 10));
-/// ```
-impl<T> Sub<T> for Vec2<T>
-where
-    T: Num + Copy,
-{
-    type Output = Vec2<T>;
+    /// Return the dot product of two 2D Vectors, taking both by value
+    ///
+    /// Equivalent to `a.dot(&b)`, but convenient for owned `Copy` vectors.
+    pub fn dot_v(self, rhs: Vec2<T>) -> T {
+        self.dot(&rhs)
+    }
 
-    fn sub(self, other: T) -> Self::Output {
-        Self {
-            x: self.x - other,
-            y: self.y - other,
-        }
+    /// Return the cross product of two 2D Vectors, taking both by value
+    ///
+    /// Equivalent to `a.cross(&b)`, but convenient for owned `Copy` vectors.
+    pub fn cross_v(self, rhs: Vec2<T>) -> T {
+        self.cross(&rhs)
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec2;
-/// let a = Vec2::new(10, 12);
-/// let b = Vec2::new(16, 48);
-/// let c = a * b;
-///
-/// assert_eq!(c, a.cross(&b));
-/// ```
-impl<T> Mul for Vec2<T>
-where
-    T: Num + Copy,
-{
-    type Output = T;
+    /// Return the squared length of the 2D Vector
+    ///
+    /// `v.dot(&v)`, which avoids the square root needed by `length`
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
+    }
 
-    fn mul(self, other: Vec2<T>) -> Self::Output {
-        self.cross(&other)
+    /// Return the squared distance between two 2D Vectors
+    ///
+    /// `(self - rhs).length_squared()`, which is cheap enough for comparing distances
+    pub fn distance_squared(&self, rhs: &Vec2<T>) -> T {
+        (*self - *rhs).length_squared()
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec2;
-/// let a = Vec2::new(10, 12);
-/// let b = 10;
-/// let c = a * b;
-///
-/// assert_eq!(c, Vec2::new(10 * 10, 12 * 10));
-/// ```
-impl<T> Mul<T> for Vec2<T>
-where
-    T: Num + Copy,
-{
-    type Output = Vec2<T>;
+    /// Linearly interpolate between two 2D Vectors
+    ///
+    /// `a + (b - a) * t`, so `t = 0` gives `a` and `t = 1` gives `b`
+    pub fn lerp(&self, rhs: &Vec2<T>, t: T) -> Vec2<T> {
+        *self + (*rhs - *self) * t
+    }
 
-    fn mul(self, other: T) -> Self::Output {
-        Self {
-            x: self.x * other,
-            y: self.y * other,
-        }
+    /// Return the outer (tensor) product of two 2D Vectors
+    ///
+    /// `self * other^T`, so entry `(row i, column j)` is `self[i] * other[j]`.
+    /// Its trace is `self.dot(&other)`.
+    pub fn outer(&self, other: &Vec2<T>) -> Mat2<T> {
+        Mat2::new(*self * other.x, *self * other.y)
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec2;
-/// let a = Vec2::new(10, 12);
-/// let b = 2;
-/// let c = a / b;
-///
-/// assert_eq!(c, Vec2::new(10 / 2, 12 / 2));
-/// ```
-impl<T> Div<T> for Vec2<T>
-where
-    T: Num + Copy,
-{
-    type Output = Vec2<T>;
+    /// Apply `f` to each component, producing a new 2D Vector
+    ///
+    /// The output scalar may differ from the input, but must still be a number
+    /// (`U: Num + Copy`), so e.g. `Vec2<f32>` to `Vec2<i32>` works but `Vec2<bool>` does not.
+    pub fn map<U, F>(self, f: F) -> Vec2<U>
+    where
+        U: Num + Copy,
+        F: Fn(T) -> U,
+    {
+        Vec2::new(f(self.x), f(self.y))
+    }
 
-    fn div(self, other: T) -> Self::Output {
-        Self {
-            x: self.x / other,
-            y: self.y / other,
+    /// Like `map`, but `f` also receives the component index (x = 0, y = 1)
+    pub fn map_with_index<U, F>(self, f: F) -> Vec2<U>
+    where
+        U: Num + Copy,
+        F: Fn(usize, T) -> U,
+    {
+        Vec2::new(f(0, self.x), f(1, self.y))
+    }
+
+    /// Combine the matching components of two 2D Vectors with `f`
+    pub fn zip_with<F>(self, other: Vec2<T>, f: F) -> Vec2<T>
+    where
+        F: Fn(T, T) -> T,
+    {
+        Vec2::new(f(self.x, other.x), f(self.y, other.y))
+    }
+
+    /// Return the component-wise minimum of two 2D Vectors
+    ///
+    /// Takes both vectors by value, so this takes precedence over `Ord::min`
+    /// (which compares vectors lexicographically).
+    pub fn min(self, rhs: Vec2<T>) -> Vec2<T>
+    where
+        T: PartialOrd,
+    {
+        Vec2 {
+            x: if self.x < rhs.x { self.x } else { rhs.x },
+            y: if self.y < rhs.y { self.y } else { rhs.y },
         }
     }
-}
 
-impl<T> Display for Vec2<T>
-where
-    T: Display + Num + Copy,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "x: {}\ny: {}", self.x, self.y)
+    /// Return the component-wise maximum of two 2D Vectors
+    ///
+    /// Takes both vectors by value, so this takes precedence over `Ord::max`
+    /// (which compares vectors lexicographically).
+    pub fn max(self, rhs: Vec2<T>) -> Vec2<T>
+    where
+        T: PartialOrd,
+    {
+        Vec2 {
+            x: if self.x > rhs.x { self.x } else { rhs.x },
+            y: if self.y > rhs.y { self.y } else { rhs.y },
+        }
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::Vec2;
+    /// Return a mask of which components equal the matching component of `rhs`
+    pub fn cmpeq(&self, rhs: &Vec2<T>) -> BVec2 {
+        BVec2::new(self.x == rhs.x, self.y == rhs.y)
+    }
 
-    #[test]
-    fn zero_vec2_test() {
-        assert_eq!(Vec2::zero(), Vec2::new(0.0, 0.0));
+    /// Return a mask of which components are less than the matching component of `rhs`
+    pub fn cmplt(&self, rhs: &Vec2<T>) -> BVec2
+    where
+        T: PartialOrd,
+    {
+        BVec2::new(self.x < rhs.x, self.y < rhs.y)
     }
 
-    #[test]
-    fn dot_product_test() {
-        let lhs = Vec2::new(32, 64);
-        let rhs = Vec2::new(12, 10);
+    /// Return a mask of which components are less than or equal to the matching component of `rhs`
+    pub fn cmple(&self, rhs: &Vec2<T>) -> BVec2
+    where
+        T: PartialOrd,
+    {
+        BVec2::new(self.x <= rhs.x, self.y <= rhs.y)
+    }
 
-        assert_eq!(lhs.dot(&rhs), 1024);
+    /// Return a mask of which components are greater than the matching component of `rhs`
+    pub fn cmpgt(&self, rhs: &Vec2<T>) -> BVec2
+    where
+        T: PartialOrd,
+    {
+        BVec2::new(self.x > rhs.x, self.y > rhs.y)
     }
 
-    #[test]
-    fn cross_product_test() {
-        let lhs = Vec2::new(32, 64);
-        let rhs = Vec2::new(12, 10);
+    /// Return a mask of which components are greater than or equal to the matching component of `rhs`
+    pub fn cmpge(&self, rhs: &Vec2<T>) -> BVec2
+    where
+        T: PartialOrd,
+    {
+        BVec2::new(self.x >= rhs.x, self.y >= rhs.y)
+    }
 
-        assert_eq!(lhs.cross(&rhs), -448);
+    /// Pick each component from `if_true` where `mask` is set, and from `if_false` otherwise
+    pub fn select(mask: BVec2, if_true: Vec2<T>, if_false: Vec2<T>) -> Self {
+        Self::new(
+            if mask.x { if_true.x } else { if_false.x },
+            if mask.y { if_true.y } else { if_false.y },
+        )
     }
 
-    #[test]
-    fn cross_product_mul_trait_test() {
-        let lhs = Vec2::new(32, 64);
-        let rhs = Vec2::new(12, 10);
+    /// Convert each component to another numeric type, or `None` if any component is out of range
+    pub fn cast<U>(&self) -> Option<Vec2<U>>
+    where
+        T: NumCast,
+        U: Num + NumCast + Copy,
+    {
+        Some(Vec2::new(U::from(self.x)?, U::from(self.y)?))
+    }
 
-        assert_eq!(lhs * rhs, -448);
+    /// Convert each component to `f32` with `as` semantics, truncating or saturating where needed
+    pub fn as_f32(&self) -> Vec2<f32>
+    where
+        T: AsPrimitive<f32>,
+    {
+        Vec2::new(self.x.as_(), self.y.as_())
     }
 
-    #[test]
-    fn add_test() {
-        let lhs = Vec2::new(32, 64);
-        let rhs = Vec2::new(12, 10);
+    /// Convert each component to `f64` with `as` semantics, truncating or saturating where needed
+    pub fn as_f64(&self) -> Vec2<f64>
+    where
+        T: AsPrimitive<f64>,
+    {
+        Vec2::new(self.x.as_(), self.y.as_())
+    }
 
-        assert_eq!(lhs + rhs, Vec2::new(44, 74));
+    /// Convert each component to `i32` with `as` semantics, truncating or saturating where needed
+    pub fn as_i32(&self) -> Vec2<i32>
+    where
+        T: AsPrimitive<i32>,
+    {
+        Vec2::new(self.x.as_(), self.y.as_())
     }
 
-    #[test]
-    fn sub_test() {
-        let lhs = Vec2::new(32, 64);
-        let rhs = Vec2::new(12, 10);
+    /// Convert each component to `u32` with `as` semantics, truncating or saturating where needed
+    pub fn as_u32(&self) -> Vec2<u32>
+    where
+        T: AsPrimitive<u32>,
+    {
+        Vec2::new(self.x.as_(), self.y.as_())
+    }
 
-        assert_eq!(lhs - rhs, Vec2::new(20, 54));
+    /// Divide each component by `rhs`, rounding towards negative infinity for a positive `rhs`
+    ///
+    /// Unlike `/`, negative components land in the right cell of a grid of size `rhs`:
+    /// `-1` divided by `16` is `-1`, not `0`. Matches `i32::div_euclid` and `f32::div_euclid`.
+    pub fn div_euclid(self, rhs: T) -> Self
+    where
+        T: Euclid,
+    {
+        Vec2::new(self.x.div_euclid(&rhs), self.y.div_euclid(&rhs))
     }
 
-    #[test]
-    fn add_scalar_test() {
-        let lhs = Vec2::new(32, 64);
-        let rhs = 10;
+    /// Return the remainder of each component divided by `rhs`, which is never negative
+    ///
+    /// Unlike `%`, `-1` modulo `16` is `15`. Matches `i32::rem_euclid` and `f32::rem_euclid`.
+    pub fn rem_euclid(self, rhs: T) -> Self
+    where
+        T: Euclid,
+    {
+        Vec2::new(self.x.rem_euclid(&rhs), self.y.rem_euclid(&rhs))
+    }
 
-        assert_eq!(lhs + rhs, Vec2::new(42, 74));
+    /// Like `div_euclid`, dividing by the matching component of `rhs`
+    pub fn div_euclid_vec(self, rhs: Vec2<T>) -> Self
+    where
+        T: Euclid,
+    {
+        Vec2::new(self.x.div_euclid(&rhs.x), self.y.div_euclid(&rhs.y))
     }
 
-    #[test]
-    fn sub_scalar_test() {
-        let lhs = Vec2::new(32, 64);
-        let rhs = 10;
+    /// Like `rem_euclid`, dividing by the matching component of `rhs`
+    pub fn rem_euclid_vec(self, rhs: Vec2<T>) -> Self
+    where
+        T: Euclid,
+    {
+        Vec2::new(self.x.rem_euclid(&rhs.x), self.y.rem_euclid(&rhs.y))
+    }
 
-        assert_eq!(lhs - rhs, Vec2::new(22, 54));
+    /// Return the sum of the components
+    ///
+    /// `x + y`
+    pub fn element_sum(&self) -> T {
+        self.x + self.y
     }
 
-    #[test]
-    fn mul_scalar_test() {
-        let lhs = Vec2::new(32, 64);
-        let rhs = 10;
+    /// Return the product of the components
+    ///
+    /// `x * y`
+    pub fn element_product(&self) -> T {
+        self.x * self.y
+    }
 
-        assert_eq!(lhs * rhs, Vec2::new(320, 640));
+    /// Return the smallest component
+    pub fn min_element(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        let mut m = self.x;
+        if self.y < m {
+            m = self.y;
+        }
+        m
     }
 
-    #[test]
-    fn div_scalar_test() {
-        let lhs = Vec2::new(32, 64);
-        let rhs = 4;
+    /// Return the largest component
+    pub fn max_element(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        let mut m = self.x;
+        if self.y > m {
+            m = self.y;
+        }
+        m
+    }
 
-        assert_eq!(lhs / rhs, Vec2::new(8, 16));
+    /// Return the index of the smallest component (0 for x, 1 for y)
+    ///
+    /// Ties go to the lowest index, so `(1, 1)` gives 0.
+    pub fn min_element_index(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        self.iter()
+            .enumerate()
+            .fold(
+                (0, self.x),
+                |(index, m), (i, &c)| if c < m { (i, c) } else { (index, m) },
+            )
+            .0
+    }
+
+    /// Return the index of the largest component (0 for x, 1 for y)
+    ///
+    /// Ties go to the lowest index, so `(1, 1)` gives 0.
+    pub fn max_element_index(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        self.iter()
+            .enumerate()
+            .fold(
+                (0, self.x),
+                |(index, m), (i, &c)| if c > m { (i, c) } else { (index, m) },
+            )
+            .0
+    }
+
+    /// Return the sign of each component
+    ///
+    /// Integer components give 0 for zero. Float components follow IEEE 754 and
+    /// give 1 for +0.0 and -1 for -0.0, and NaN stays NaN.
+    pub fn signum(&self) -> Self
+    where
+        T: Signed,
+    {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Return the Manhattan (taxicab) distance between two 2D Vectors
+    ///
+    /// The sum of the absolute component differences.
+    pub fn manhattan_distance(&self, other: &Vec2<T>) -> T
+    where
+        T: Signed,
+    {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Return the Chebyshev (chessboard) distance between two 2D Vectors
+    ///
+    /// The largest absolute component difference.
+    pub fn chebyshev_distance(&self, other: &Vec2<T>) -> T
+    where
+        T: Signed + PartialOrd,
+    {
+        (*self - *other).map(|c| c.abs()).max_element()
+    }
+
+    /// Return an iterator over references to the components, in x, y order
+    pub fn iter(&self) -> std::array::IntoIter<&T, 2> {
+        [&self.x, &self.y].into_iter()
+    }
+
+    /// Return an iterator over mutable references to the components, in x, y order
+    pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut T, 2> {
+        [&mut self.x, &mut self.y].into_iter()
+    }
+}
+
+impl Vec2<f32> {
+    /// Creates a 2D Vector with all values set to 0.0
+    pub fn zero() -> Self {
+        Self { x: 0.0, y: 0.0 }
+    }
+
+    /// Snap the 2D Vector onto an integer grid made of cells of size `cell_size`
+    ///
+    /// `floor(v / cell_size)`
+    pub fn quantize(&self, cell_size: f32) -> Vec2<i32> {
+        Vec2 {
+            x: (self.x / cell_size).floor() as i32,
+            y: (self.y / cell_size).floor() as i32,
+        }
+    }
+
+    /// Return the components packed as little-endian bytes, in `x, y` order
+    pub fn to_le_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        for (chunk, c) in bytes.chunks_exact_mut(4).zip([self.x, self.y]) {
+            chunk.copy_from_slice(&c.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 2D Vector from components packed as little-endian bytes, in `x, y` order
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        let c = |i: usize| f32::from_le_bytes(bytes[i * 4..(i + 1) * 4].try_into().unwrap());
+        Self::new(c(0), c(1))
+    }
+
+    /// Return the components packed as big-endian bytes, in `x, y` order
+    pub fn to_be_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        for (chunk, c) in bytes.chunks_exact_mut(4).zip([self.x, self.y]) {
+            chunk.copy_from_slice(&c.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 2D Vector from components packed as big-endian bytes, in `x, y` order
+    pub fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        let c = |i: usize| f32::from_be_bytes(bytes[i * 4..(i + 1) * 4].try_into().unwrap());
+        Self::new(c(0), c(1))
+    }
+}
+
+impl Vec2<f64> {
+    /// Return the components packed as little-endian bytes, in `x, y` order
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (chunk, c) in bytes.chunks_exact_mut(8).zip([self.x, self.y]) {
+            chunk.copy_from_slice(&c.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 2D Vector from components packed as little-endian bytes, in `x, y` order
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        let c = |i: usize| f64::from_le_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap());
+        Self::new(c(0), c(1))
+    }
+
+    /// Return the components packed as big-endian bytes, in `x, y` order
+    pub fn to_be_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (chunk, c) in bytes.chunks_exact_mut(8).zip([self.x, self.y]) {
+            chunk.copy_from_slice(&c.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 2D Vector from components packed as big-endian bytes, in `x, y` order
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let c = |i: usize| f64::from_be_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap());
+        Self::new(c(0), c(1))
+    }
+}
+
+impl Vec2<i32> {
+    /// Rotate the 2D Vector using Q16.16 fixed-point `sin` and `cos` of the angle
+    ///
+    /// `(x*cos - y*sin, x*sin + y*cos) >> 16`, rounded to nearest. Only integer
+    /// arithmetic is used, so the result is bit-identical on every platform.
+    ///
+    /// Intermediates are 64 bit, but the rotated vector has to fit back in `i32`:
+    /// with unit trig values that holds when `self.length() <= i32::MAX`. Components
+    /// outside the `i32` range saturate rather than wrap, so e.g. `(2e9, 2e9)`
+    /// rotated by 45° gives `(0, i32::MAX)`.
+    pub fn rotate_fixed(&self, sin_q16: i32, cos_q16: i32) -> Vec2<i32> {
+        let (x, y) = (self.x as i64, self.y as i64);
+        let (sin, cos) = (sin_q16 as i64, cos_q16 as i64);
+        let half = 1i64 << 15;
+        let narrow = |c: i64| c.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        Vec2 {
+            x: narrow((x * cos - y * sin + half) >> 16),
+            y: narrow((x * sin + y * cos + half) >> 16),
+        }
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Float,
+{
+    /// Return the length (magnitude) of the 2D Vector
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Return the distance between two 2D Vectors
+    ///
+    /// `(self - rhs).length()`
+    pub fn distance(&self, rhs: &Vec2<T>) -> T {
+        (*self - *rhs).length()
+    }
+
+    /// Return a unit length copy of the 2D Vector
+    ///
+    /// A zero length vector has no direction, so the result will be NaN.
+    pub fn normalize(&self) -> Self {
+        *self / self.length()
+    }
+
+    /// Return the 2D Vector flipped if needed so it points to the same side as `reference`
+    ///
+    /// Returns `self` when `self.dot(&reference) >= 0`, and `-self` otherwise. A
+    /// vector exactly perpendicular to `reference` is kept as it is.
+    ///
+    /// GLSL's `faceforward(N, I, Nref)` instead takes an incident direction `I`
+    /// pointing *towards* the surface, and returns `N` when `dot(Nref, I) < 0`.
+    /// So `n.face_forward(&r)` is `faceforward(n, -r, n)`, except that GLSL negates
+    /// the perpendicular case.
+    pub fn face_forward(&self, reference: &Vec2<T>) -> Self {
+        if self.is_in_hemisphere(reference) {
+            *self
+        } else {
+            *self * -T::one()
+        }
+    }
+
+    /// Return whether the 2D Vector lies in the closed hemisphere around `normal`
+    ///
+    /// `self.dot(&normal) >= 0`, so vectors on the boundary count as inside, and
+    /// `face_forward` leaves exactly these vectors unchanged.
+    pub fn is_in_hemisphere(&self, normal: &Vec2<T>) -> bool {
+        self.dot(normal) >= T::zero()
+    }
+
+    /// Return a unit length copy of the 2D Vector, or the zero vector when it has
+    /// no usable direction (a zero, infinite or NaN length)
+    pub fn normalize_or_zero(&self) -> Self {
+        let inv = self.length().recip();
+        if inv.is_finite() && inv > T::zero() {
+            *self * inv
+        } else {
+            Self::new(T::zero(), T::zero())
+        }
+    }
+
+    /// Return whether the 2D Vector has unit length
+    ///
+    /// The squared length must be within `sqrt(epsilon)` of 1, which allows for
+    /// the rounding error of a `normalize`.
+    pub fn is_normalized(&self) -> bool {
+        (self.length_squared() - T::one()).abs() <= T::epsilon().sqrt()
+    }
+
+    /// Return the point halfway between two 2D Vectors
+    ///
+    /// `(self + rhs) / 2`
+    pub fn midpoint(&self, rhs: &Vec2<T>) -> Self {
+        (*self + *rhs) / (T::one() + T::one())
+    }
+
+    /// Return the average of a slice of 2D Vectors, or `None` if it is empty
+    pub fn centroid(points: &[Vec2<T>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let sum: Vec2<T> = points.iter().sum();
+        Some(sum / T::from(points.len())?)
+    }
+
+    /// Component-wise step: 0 where `x` is below `edge`, and 1 otherwise
+    pub fn step(edge: &Vec2<T>, x: &Vec2<T>) -> Self {
+        Self::new(scalar::step(edge.x, x.x), scalar::step(edge.y, x.y))
+    }
+
+    /// Component-wise Hermite smoothing of `x` between `edge0` and `edge1`
+    ///
+    /// `t = clamp((x - edge0) / (edge1 - edge0), 0, 1)`, then `3t² - 2t³`
+    ///
+    /// Components with equal edges become a hard `step` instead of dividing by zero.
+    pub fn smoothstep(edge0: &Vec2<T>, edge1: &Vec2<T>, x: &Vec2<T>) -> Self {
+        Self::new(
+            scalar::smoothstep(edge0.x, edge1.x, x.x),
+            scalar::smoothstep(edge0.y, edge1.y, x.y),
+        )
+    }
+
+    /// Like `smoothstep`, but with zero first and second derivatives at the edges
+    ///
+    /// `t = clamp((x - edge0) / (edge1 - edge0), 0, 1)`, then `6t⁵ - 15t⁴ + 10t³`
+    pub fn smootherstep(edge0: &Vec2<T>, edge1: &Vec2<T>, x: &Vec2<T>) -> Self {
+        Self::new(
+            scalar::smootherstep(edge0.x, edge1.x, x.x),
+            scalar::smootherstep(edge0.y, edge1.y, x.y),
+        )
+    }
+
+    /// Linearly rescale each component from `in_min..in_max` to `out_min..out_max`
+    ///
+    /// `out_min + (v - in_min) * (out_max - out_min) / (in_max - in_min)`
+    ///
+    /// Values outside the input range extrapolate rather than clamp. A component
+    /// with `in_min == in_max` divides by zero.
+    pub fn remap(
+        &self,
+        in_min: &Vec2<T>,
+        in_max: &Vec2<T>,
+        out_min: &Vec2<T>,
+        out_max: &Vec2<T>,
+    ) -> Self {
+        Self::new(
+            scalar::remap(self.x, in_min.x, in_max.x, out_min.x, out_max.x),
+            scalar::remap(self.y, in_min.y, in_max.y, out_min.y, out_max.y),
+        )
+    }
+
+    /// Return the magnitude of each component with the sign of the matching component of `sign`
+    ///
+    /// The sign bit is copied directly, so a `sign` of -0.0 makes the result negative.
+    pub fn copysign(&self, sign: &Vec2<T>) -> Self {
+        Self::new(self.x.copysign(sign.x), self.y.copysign(sign.y))
+    }
+
+    /// Return the Lp norm of the 2D Vector
+    ///
+    /// `(|x|^p + |y|^p + ...)^(1/p)`. `p = 1` is the Manhattan length, `p = 2` the
+    /// Euclidean `length`, and `p = infinity` the largest absolute component.
+    pub fn lp_norm(&self, p: T) -> T {
+        let abs = self.map(|c| c.abs());
+        if p.is_infinite() {
+            return abs.max_element();
+        }
+        abs.map(|c| c.powf(p)).element_sum().powf(p.recip())
+    }
+
+    /// Return the Lp distance between two 2D Vectors
+    ///
+    /// `(self - other).lp_norm(p)`
+    pub fn lp_distance(&self, other: &Vec2<T>, p: T) -> T {
+        (*self - *other).lp_norm(p)
+    }
+
+    /// Raise each component to the float power `exp`
+    pub fn powf(&self, exp: T) -> Self {
+        self.map(|c| c.powf(exp))
+    }
+
+    /// Raise each component to the integer power `exp`
+    pub fn powi(&self, exp: i32) -> Self {
+        self.map(|c| c.powi(exp))
+    }
+
+    /// Return the square root of each component
+    ///
+    /// Negative components give NaN.
+    pub fn sqrt(&self) -> Self {
+        self.map(|c| c.sqrt())
+    }
+
+    /// Return the reciprocal `1 / c` of each component
+    ///
+    /// Zero components give infinity (with the sign of the zero).
+    pub fn recip(&self) -> Self {
+        self.map(|c| c.recip())
+    }
+
+    /// Return `self * mul + add` component-wise, as fused multiply-adds
+    ///
+    /// Each component is computed with a single rounding, which is more accurate
+    /// (and often faster) than a separate multiply and add.
+    pub fn mul_add(&self, mul: &Vec2<T>, add: &Vec2<T>) -> Self {
+        Self::new(self.x.mul_add(mul.x, add.x), self.y.mul_add(mul.y, add.y))
+    }
+
+    /// Move towards `target` by at most `max_delta`, without overshooting
+    ///
+    /// When `target` is within `max_delta` it is returned exactly.
+    pub fn move_towards(&self, target: &Vec2<T>, max_delta: T) -> Self {
+        let delta = *target - *self;
+        let distance = delta.length();
+        if distance <= max_delta || distance == T::zero() {
+            return *target;
+        }
+        *self + delta * (max_delta / distance)
+    }
+
+    /// Return the 2D Vector rescaled so its length is within `min..=max`
+    ///
+    /// The zero vector has no direction to grow along, so it is returned unchanged.
+    pub fn clamp_length(&self, min: T, max: T) -> Self {
+        let length = self.length();
+        if length == T::zero() {
+            *self
+        } else if length > max {
+            *self * (max / length)
+        } else if length < min {
+            *self * (min / length)
+        } else {
+            *self
+        }
+    }
+
+    /// Return the 2D Vector shortened to a length of at most `max`
+    ///
+    /// Shorter vectors (including the zero vector) are returned unchanged.
+    pub fn clamp_length_max(&self, max: T) -> Self {
+        self.clamp_length(T::zero(), max)
+    }
+
+    /// Return the 2D Vector lengthened to a length of at least `min`
+    ///
+    /// The zero vector has no direction to grow along, so it is returned unchanged.
+    pub fn clamp_length_min(&self, min: T) -> Self {
+        self.clamp_length(min, T::infinity())
+    }
+
+    /// Return the vector rotated counter-clockwise by `radians`
+    ///
+    /// `(x * cos - y * sin, x * sin + y * cos)`
+    pub fn rotate(&self, radians: T) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+
+    /// Create a 2D Vector from polar coordinates
+    ///
+    /// `angle` is in radians, measured counter-clockwise from the +X axis.
+    pub fn from_polar(radius: T, angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            x: radius * cos,
+            y: radius * sin,
+        }
+    }
+
+    /// Return the polar coordinates `(radius, angle)` of the 2D Vector
+    ///
+    /// `angle` is `atan2(y, x)`, in radians within `[-pi, pi]`.
+    pub fn to_polar(&self) -> (T, T) {
+        (self.length(), self.y.atan2(self.x))
+    }
+}
+
+/// Overflow aware arithmetic for integer 2D Vectors.
+///
+/// Each method applies the matching scalar operation from `num` to every component.
+impl<T> Vec2<T>
+where
+    T: Num + Copy,
+{
+    /// Add two 2D Vectors, returning `None` if any component overflows
+    pub fn checked_add(&self, rhs: &Vec2<T>) -> Option<Vec2<T>>
+    where
+        T: CheckedAdd,
+    {
+        Some(Vec2 {
+            x: self.x.checked_add(&rhs.x)?,
+            y: self.y.checked_add(&rhs.y)?,
+        })
+    }
+
+    /// Subtract two 2D Vectors, returning `None` if any component overflows
+    pub fn checked_sub(&self, rhs: &Vec2<T>) -> Option<Vec2<T>>
+    where
+        T: CheckedSub,
+    {
+        Some(Vec2 {
+            x: self.x.checked_sub(&rhs.x)?,
+            y: self.y.checked_sub(&rhs.y)?,
+        })
+    }
+
+    /// Multiply a 2D Vector by a scalar, returning `None` if any component overflows
+    pub fn checked_mul(&self, rhs: T) -> Option<Vec2<T>>
+    where
+        T: CheckedMul,
+    {
+        Some(Vec2 {
+            x: self.x.checked_mul(&rhs)?,
+            y: self.y.checked_mul(&rhs)?,
+        })
+    }
+
+    /// Divide a 2D Vector by a scalar, returning `None` if `rhs` is zero or a
+    /// component overflows
+    pub fn checked_div(&self, rhs: T) -> Option<Vec2<T>>
+    where
+        T: CheckedDiv,
+    {
+        Some(Vec2 {
+            x: self.x.checked_div(&rhs)?,
+            y: self.y.checked_div(&rhs)?,
+        })
+    }
+
+    /// Add two 2D Vectors, wrapping around at the bounds of the type
+    pub fn wrapping_add(&self, rhs: &Vec2<T>) -> Vec2<T>
+    where
+        T: WrappingAdd,
+    {
+        Vec2 {
+            x: self.x.wrapping_add(&rhs.x),
+            y: self.y.wrapping_add(&rhs.y),
+        }
+    }
+
+    /// Subtract two 2D Vectors, wrapping around at the bounds of the type
+    pub fn wrapping_sub(&self, rhs: &Vec2<T>) -> Vec2<T>
+    where
+        T: WrappingSub,
+    {
+        Vec2 {
+            x: self.x.wrapping_sub(&rhs.x),
+            y: self.y.wrapping_sub(&rhs.y),
+        }
+    }
+
+    /// Multiply a 2D Vector by a scalar, wrapping around at the bounds of the type
+    pub fn wrapping_mul(&self, rhs: T) -> Vec2<T>
+    where
+        T: WrappingMul,
+    {
+        Vec2 {
+            x: self.x.wrapping_mul(&rhs),
+            y: self.y.wrapping_mul(&rhs),
+        }
+    }
+
+    /// Add two 2D Vectors, clamping each component to the bounds of the type
+    pub fn saturating_add(&self, rhs: &Vec2<T>) -> Vec2<T>
+    where
+        T: SaturatingAdd,
+    {
+        Vec2 {
+            x: self.x.saturating_add(&rhs.x),
+            y: self.y.saturating_add(&rhs.y),
+        }
+    }
+
+    /// Subtract two 2D Vectors, clamping each component to the bounds of the type
+    pub fn saturating_sub(&self, rhs: &Vec2<T>) -> Vec2<T>
+    where
+        T: SaturatingSub,
+    {
+        Vec2 {
+            x: self.x.saturating_sub(&rhs.x),
+            y: self.y.saturating_sub(&rhs.y),
+        }
+    }
+
+    /// Multiply a 2D Vector by a scalar, clamping each component to the bounds
+    /// of the type
+    pub fn saturating_mul(&self, rhs: T) -> Vec2<T>
+    where
+        T: SaturatingMul,
+    {
+        Vec2 {
+            x: self.x.saturating_mul(&rhs),
+            y: self.y.saturating_mul(&rhs),
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(10, 12);
+/// let b = Vec2::new(16, 48);
+/// let c = a + b;
+///
+/// assert_eq!(c, Vec2::new(a.x + b.x, a.y + b.y));
+/// ```
+impl<T> Add for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn add(self, other: Vec2<T>) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(10, 12);
+/// let b = 10;
+/// let c = a + b;
+///
+/// assert_eq!(c, Vec2::new(10 + 10, 12 + 10));
+/// ```
+impl<T> Add<T> for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn add(self, other: T) -> Self::Output {
+        Self {
+            x: self.x + other,
+            y: self.y + other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(10, 12);
+/// let b = Vec2::new(16, 48);
+/// let c = a - b;
+///
+/// assert_eq!(c, Vec2::new(10 - 16, 12 - 48));
+/// ```
+impl<T> Sub for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Vec2<T>) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(10, 12);
+/// let b = 10;
+/// let c = a - b;
+///
+/// assert_eq!(c, Vec2::new(10 - 10, 12 - 10));
+/// ```
+impl<T> Sub<T> for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn sub(self, other: T) -> Self::Output {
+        Self {
+            x: self.x - other,
+            y: self.y - other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(10, 12);
+/// let b = Vec2::new(16, 48);
+/// let c = a * b;
+///
+/// assert_eq!(c, a.cross(&b));
+/// ```
+impl<T> Mul for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn mul(self, other: Vec2<T>) -> Self::Output {
+        self.cross(&other)
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(10, 12);
+/// let b = 10;
+/// let c = a * b;
+///
+/// assert_eq!(c, Vec2::new(10 * 10, 12 * 10));
+/// ```
+impl<T> Mul<T> for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn mul(self, other: T) -> Self::Output {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(10, 12);
+/// let b = 2;
+/// let c = a / b;
+///
+/// assert_eq!(c, Vec2::new(10 / 2, 12 / 2));
+/// ```
+impl<T> Div<T> for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn div(self, other: T) -> Self::Output {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(10, 7);
+/// let b = Vec2::new(4, 3);
+/// let c = a % b;
+///
+/// assert_eq!(c, Vec2::new(10 % 4, 7 % 3));
+/// ```
+impl<T> Rem for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn rem(self, other: Vec2<T>) -> Self::Output {
+        Self {
+            x: self.x % other.x,
+            y: self.y % other.y,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(10, 7);
+/// let b = 4;
+/// let c = a % b;
+///
+/// assert_eq!(c, Vec2::new(10 % 4, 7 % 4));
+/// ```
+impl<T> Rem<T> for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn rem(self, other: T) -> Self::Output {
+        Self {
+            x: self.x % other,
+            y: self.y % other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::new(1, 2);
+/// let b: Vec<i32> = a.into_iter().collect();
+///
+/// assert_eq!(b, vec![1, 2]);
+/// ```
+impl<T> IntoIterator for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y].into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Item = &'a T;
+    type IntoIter = std::array::IntoIter<&'a T, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Item = &'a mut T;
+    type IntoIter = std::array::IntoIter<&'a mut T, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a: Vec2<i32> = (1..=2).collect();
+///
+/// assert_eq!(a, Vec2::new(1, 2));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the iterator does not yield exactly 2 items, use `TryFrom<&[T]>`
+/// for a fallible conversion.
+impl<T> FromIterator<T> for Vec2<T>
+where
+    T: Num + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        match collect_components(iter) {
+            Ok([x, y]) => Self { x, y },
+            Err(e) => panic!("cannot build a Vec2 from an iterator: {}", e),
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::try_from(&[1, 2][..]);
+///
+/// assert_eq!(a, Ok(Vec2::new(1, 2)));
+/// ```
+impl<T> TryFrom<&[T]> for Vec2<T>
+where
+    T: Num + Copy,
+{
+    type Error = ComponentCountError;
+
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        let [x, y] = collect_components(slice.iter().copied())?;
+        Ok(Self { x, y })
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = vec![Vec2::new(1, 2), Vec2::new(1, 2)];
+/// let b: Vec2<i32> = a.into_iter().sum();
+///
+/// assert_eq!(b, Vec2::new(2, 4));
+/// ```
+impl<T> Sum for Vec2<T>
+where
+    T: Num + Copy,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vec2::new(T::zero(), T::zero()), |acc, v| acc + v)
+    }
+}
+
+impl<'a, T> Sum<&'a Vec2<T>> for Vec2<T>
+where
+    T: Num + Copy,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Vec2::new(T::zero(), T::zero()), |acc, v| acc + *v)
+    }
+}
+
+/// Allows vectors in generic code bounded on `num::Zero`:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// use num::Zero;
+///
+/// let zero: Vec2<i32> = Zero::zero();
+/// assert!(zero.is_zero());
+/// assert_eq!(Vec2::new(1, 2) + zero, Vec2::new(1, 2));
+/// ```
+impl<T> Zero for Vec2<T>
+where
+    T: Num + Copy,
+{
+    fn zero() -> Self {
+        Vec2::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a = Vec2::from([1, 2]);
+/// let b: [i32; 2] = a.into();
+///
+/// assert_eq!(a, Vec2::new(1, 2));
+/// assert_eq!(b, [1, 2]);
+/// ```
+impl<T> From<[T; 2]> for Vec2<T>
+where
+    T: Num + Copy,
+{
+    fn from([x, y]: [T; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for [T; 2]
+where
+    T: Num + Copy,
+{
+    fn from(v: Vec2<T>) -> Self {
+        [v.x, v.y]
+    }
+}
+
+/// Formats the vector as `(x, y)`, which parses back via `FromStr`
+impl<T> Display for Vec2<T>
+where
+    T: Display + Num + Copy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec2;
+/// let a: Vec2<i32> = "(1, 2)".parse().unwrap();
+/// let b: Vec2<i32> = "[1 2]".parse().unwrap();
+///
+/// assert_eq!(a, Vec2::new(1, 2));
+/// assert_eq!(a, b);
+/// ```
+impl<T> FromStr for Vec2<T>
+where
+    T: FromStr + Num + Copy,
+{
+    type Err = ParseVecError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_components(s)?;
+        Ok(Self { x, y })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Vec2;
+    use crate::matrix::Mat2;
+    use crate::vector::ComponentCountError;
+    use crate::vector::ParseVecError;
+
+    #[test]
+    fn zero_vec2_test() {
+        assert_eq!(Vec2::zero(), Vec2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn quantize_test() {
+        let v = Vec2::new(2.5, -0.5);
+
+        assert_eq!(v.quantize(1.0), Vec2::new(2, -1));
+        assert_eq!(v.quantize(2.0), Vec2::new(1, -1));
+    }
+
+    #[test]
+    fn dot_product_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = Vec2::new(12, 10);
+
+        assert_eq!(lhs.dot(&rhs), 1024);
+    }
+
+    #[test]
+    fn cross_product_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = Vec2::new(12, 10);
+
+        assert_eq!(lhs.cross(&rhs), -448);
+    }
+
+    #[test]
+    fn cross_product_mul_trait_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = Vec2::new(12, 10);
+
+        assert_eq!(lhs * rhs, -448);
+    }
+
+    #[test]
+    fn add_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = Vec2::new(12, 10);
+
+        assert_eq!(lhs + rhs, Vec2::new(44, 74));
+    }
+
+    #[test]
+    fn sub_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = Vec2::new(12, 10);
+
+        assert_eq!(lhs - rhs, Vec2::new(20, 54));
+    }
+
+    #[test]
+    fn add_scalar_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = 10;
+
+        assert_eq!(lhs + rhs, Vec2::new(42, 74));
+    }
+
+    #[test]
+    fn sub_scalar_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = 10;
+
+        assert_eq!(lhs - rhs, Vec2::new(22, 54));
+    }
+
+    #[test]
+    fn mul_scalar_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = 10;
+
+        assert_eq!(lhs * rhs, Vec2::new(320, 640));
+    }
+
+    #[test]
+    fn div_scalar_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = 4;
+
+        assert_eq!(lhs / rhs, Vec2::new(8, 16));
+    }
+
+    #[test]
+    fn parse_test() {
+        let expected = Vec2::new(1, 2);
+
+        assert_eq!("1,2".parse(), Ok(expected));
+        assert_eq!("(1, 2)".parse(), Ok(expected));
+        assert_eq!("[1 2]".parse(), Ok(expected));
+        assert_eq!("  ( 1 , 2 )  ".parse(), Ok(expected));
+        assert_eq!("\t1,  2\n".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn parse_negative_and_scientific_test() {
+        let parsed: Vec2<f32> = "(-1.5, 2e3)".parse().unwrap();
+
+        assert_eq!(parsed, Vec2::new(-1.5, 2000.0));
+        assert_eq!("-1, -1".parse(), Ok(Vec2::new(-1, -1)));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        assert_eq!(
+            "1".parse::<Vec2<i32>>(),
+            Err(ParseVecError::WrongComponentCount {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            "1, 1, 1".parse::<Vec2<i32>>(),
+            Err(ParseVecError::WrongComponentCount {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            "".parse::<Vec2<i32>>(),
+            Err(ParseVecError::WrongComponentCount {
+                expected: 2,
+                found: 0
+            })
+        );
+        assert!(matches!(
+            "(1, a)".parse::<Vec2<i32>>(),
+            Err(ParseVecError::InvalidComponent(_))
+        ));
+        assert!(matches!(
+            "1,".parse::<Vec2<i32>>(),
+            Err(ParseVecError::InvalidComponent(_))
+        ));
+    }
+
+    #[test]
+    fn display_round_trip_test() {
+        let v = Vec2::new(0.1, -2.5);
+
+        assert_eq!(v.to_string(), "(0.1, -2.5)");
+        assert_eq!(v.to_string().parse(), Ok(v));
+    }
+
+    #[test]
+    fn length_test() {
+        assert_eq!(Vec2::new(3, 4).length_squared(), 25);
+        assert_eq!(Vec2::new(3.0, 4.0).length(), 5.0);
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(Vec2::new(0.0, 2.0).normalize(), Vec2::new(0.0, 1.0));
+        assert!((Vec2::new(3.0f32, 4.0).normalize().length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut v = Vec2::new(1, 2);
+
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!((&v).into_iter().count(), 2);
+        for c in v.iter_mut() {
+            *c *= 2;
+        }
+        assert_eq!(v, Vec2::new(2, 4));
+        assert_eq!(v.into_iter().max(), Some(4));
+    }
+
+    #[test]
+    fn from_iter_test() {
+        let v: Vec2<i32> = vec![1, 2].into_iter().collect();
+
+        assert_eq!(v, Vec2::new(1, 2));
+        assert_eq!(
+            v.into_iter().map(|c| c * 2).collect::<Vec2<i32>>(),
+            Vec2::new(2, 4)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_iter_wrong_count_test() {
+        let _: Vec2<i32> = (0..3).collect();
+    }
+
+    #[test]
+    fn try_from_slice_test() {
+        assert_eq!(Vec2::try_from(&[1, 2][..]), Ok(Vec2::new(1, 2)));
+        assert_eq!(
+            Vec2::<i32>::try_from(&[1, 2, 3, 4, 5][..]),
+            Err(ComponentCountError {
+                expected: 2,
+                found: 5
+            })
+        );
+        assert_eq!(
+            Vec2::<i32>::try_from(&[][..]),
+            Err(ComponentCountError {
+                expected: 2,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn sum_centroid_test() {
+        let points = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(2.0, 6.0),
+        ];
+        let centroid = points.iter().copied().sum::<Vec2<f32>>() / points.len() as f32;
+
+        assert_eq!(centroid, Vec2::new(2.0, 2.0));
+        assert_eq!(points.iter().sum::<Vec2<f32>>(), centroid * 3.0);
+        assert_eq!(
+            Vec::<Vec2<f32>>::new().into_iter().sum::<Vec2<f32>>(),
+            Vec2::zero()
+        );
+    }
+
+    #[test]
+    fn lerp_test() {
+        let a = Vec2::new(0.0, 10.0);
+        let b = Vec2::new(10.0, 20.0);
+
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 0.5), Vec2::new(5.0, 15.0));
+    }
+
+    #[test]
+    fn map_test() {
+        let v = Vec2::new(1, -2);
+
+        assert_eq!(v.map(|c| c as f32), Vec2::new(1.0, -2.0));
+        assert_eq!(
+            Vec2::new(4.0, 9.0).map(|c: f32| c.powf(2.0)),
+            Vec2::new(16.0, 81.0)
+        );
+        assert_eq!(v.map_with_index(|i, _| i as i32 * 10), Vec2::new(0, 10));
+    }
+
+    #[test]
+    fn zip_with_test() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(4, -5);
+
+        assert_eq!(a.zip_with(b, std::cmp::max), Vec2::new(4, -2));
+    }
+
+    #[test]
+    fn lexicographic_order_test() {
+        let mut v = vec![
+            Vec2::new(2, 1),
+            Vec2::new(1, 5),
+            Vec2::new(-3, 9),
+            Vec2::new(1, -5),
+        ];
+        v.sort();
+
+        assert_eq!(
+            v,
+            vec![
+                Vec2::new(-3, 9),
+                Vec2::new(1, -5),
+                Vec2::new(1, 5),
+                Vec2::new(2, 1)
+            ]
+        );
+        assert!(Vec2::new(0.0, 10.0) < Vec2::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn btree_set_dedupe_test() {
+        let set: std::collections::BTreeSet<_> = [
+            Vec2::new(1, 2),
+            Vec2::new(0, 3),
+            Vec2::new(1, 2),
+            Vec2::new(0, 3),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            vec![Vec2::new(0, 3), Vec2::new(1, 2)]
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic_test() {
+        let a: Vec2<u8> = Vec2::new(250, 1);
+
+        assert_eq!(a.checked_add(&Vec2::new(5, 5)), Some(Vec2::new(255, 6)));
+        assert_eq!(a.checked_add(&Vec2::new(6, 0)), None);
+        assert_eq!(a.checked_sub(&Vec2::new(0, 1)), Some(Vec2::new(250, 0)));
+        assert_eq!(a.checked_sub(&Vec2::new(0, 2)), None);
+        assert_eq!(Vec2::new(1, 2).checked_mul(2u8), Some(Vec2::new(2, 4)));
+        assert_eq!(a.checked_mul(2), None);
+        assert_eq!(a.checked_div(2), Some(Vec2::new(125, 0)));
+        assert_eq!(a.checked_div(0), None);
+        assert_eq!(Vec2::new(i8::MIN, 0).checked_div(-1), None);
+    }
+
+    #[test]
+    fn wrapping_arithmetic_test() {
+        let a: Vec2<u8> = Vec2::new(250, 1);
+
+        assert_eq!(
+            a.wrapping_add(&Vec2::new(10, u8::MAX)),
+            Vec2::new(250u8.wrapping_add(10), 1u8.wrapping_add(u8::MAX))
+        );
+        assert_eq!(a.wrapping_sub(&Vec2::new(0, 2)), Vec2::new(250, u8::MAX));
+        assert_eq!(a.wrapping_mul(2), Vec2::new(250u8.wrapping_mul(2), 2));
+    }
+
+    #[test]
+    fn saturating_arithmetic_test() {
+        let a: Vec2<u8> = Vec2::new(250, 1);
+
+        assert_eq!(a.saturating_add(&Vec2::new(10, 1)), Vec2::new(u8::MAX, 2));
+        assert_eq!(a.saturating_sub(&Vec2::new(0, 2)), Vec2::new(250, 0));
+        assert_eq!(a.saturating_mul(2), Vec2::new(u8::MAX, 2));
+        assert_eq!(
+            Vec2::new(-100i8, 100).saturating_mul(2),
+            Vec2::new(i8::MIN, i8::MAX)
+        );
+    }
+
+    #[test]
+    fn array_conversion_test() {
+        let v = Vec2::from([1, 2]);
+
+        assert_eq!(v, Vec2::new(1, 2));
+        assert_eq!(<[i32; 2]>::from(v), [1, 2]);
+    }
+
+    #[test]
+    fn rem_test() {
+        let lhs = Vec2::new(10, 7);
+        let rhs = Vec2::new(4, 3);
+
+        assert_eq!(lhs % rhs, Vec2::new(2, 1));
+    }
+
+    #[test]
+    fn rem_scalar_test() {
+        let lhs = Vec2::new(10, 7);
+
+        assert_eq!(lhs % 4, Vec2::new(2, 3));
+        assert_eq!(Vec2::new(5.5, -1.5) % 2.0, Vec2::new(1.5, -1.5));
+    }
+
+    #[test]
+    fn by_value_products_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = Vec2::new(12, 10);
+
+        assert_eq!(lhs.dot_v(rhs), lhs.dot(&rhs));
+        assert_eq!(lhs.cross_v(rhs), -448);
+    }
+
+    #[test]
+    fn perp_test() {
+        assert_eq!(Vec2::new(1, 0).perp(), Vec2::new(0, 1));
+        assert_eq!(Vec2::new(0, 1).perp(), Vec2::new(-1, 0));
+        assert_eq!(Vec2::new(3, -2).perp().dot(&Vec2::new(3, -2)), 0);
+    }
+
+    #[test]
+    fn perp_dot_test() {
+        let lhs = Vec2::new(32, 64);
+        let rhs = Vec2::new(12, 10);
+
+        assert_eq!(lhs.perp_dot(&rhs), lhs.cross(&rhs));
+        assert_eq!(lhs.perp().dot(&rhs), lhs.perp_dot(&rhs));
+    }
+
+    #[test]
+    fn min_max_test() {
+        let a = Vec2::new(1, 5);
+        let b = Vec2::new(2, 4);
+
+        assert_eq!(a.min(b), Vec2::new(1, 4));
+        assert_eq!(a.max(b), Vec2::new(2, 5));
+        assert_eq!(
+            Vec2::new(0.5, -1.0).max(Vec2::new(1.0, -2.0)),
+            Vec2::new(1.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn rotate_test() {
+        let r = Vec2::new(1.0f32, 0.0).rotate(std::f32::consts::FRAC_PI_2);
+
+        assert!((r - Vec2::new(0.0, 1.0)).length() < 1e-6);
+
+        let v = Vec2::new(3.0f32, -2.0);
+        let back = v.rotate(1.2).rotate(-1.2);
+        assert!((back - v).length() < 1e-5);
+        assert!((v.rotate(0.7).length() - v.length()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn rotate_quarter_turns_test() {
+        let v = Vec2::new(3, -2);
+
+        assert_eq!(Vec2::new(1, 0).rotate90(), Vec2::new(0, 1));
+        assert_eq!(v.rotate90().rotate90(), v.rotate180());
+        assert_eq!(v.rotate180(), Vec2::new(-3, 2));
+        assert_eq!(v.rotate270(), Vec2::new(-2, -3));
+        assert_eq!(v.rotate90().rotate270(), v);
+    }
+
+    #[test]
+    fn to_polar_test() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        assert_eq!(Vec2::new(1.0, 0.0).to_polar(), (1.0, 0.0));
+        assert_eq!(Vec2::new(0.0, 2.0).to_polar(), (2.0, FRAC_PI_2));
+        assert_eq!(Vec2::new(-1.0, 0.0).to_polar(), (1.0, PI));
+        assert_eq!(Vec2::new(0.0, -1.0).to_polar(), (1.0, -FRAC_PI_2));
+
+        let (r, a) = Vec2::new(1.0, 1.0).to_polar();
+        assert!((r - 2.0f64.sqrt()).abs() < 1e-12);
+        assert!((a - FRAC_PI_4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn polar_round_trip_test() {
+        for v in [
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(-1.0, 0.0),
+            Vec2::new(0.0, -1.0),
+            Vec2::new(3.0, -4.0),
+            Vec2::new(-2.5, 1.5),
+        ] {
+            let (r, a) = v.to_polar();
+            assert!((Vec2::from_polar(r, a) - v).length() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn rotate_fixed_test() {
+        let one = 1 << 16;
+        let v = Vec2::new(1000, -250);
+
+        assert_eq!(v.rotate_fixed(0, one), v);
+        assert_eq!(v.rotate_fixed(one, 0), Vec2::new(250, 1000));
+        assert_eq!(v.rotate_fixed(0, -one), Vec2::new(-1000, 250));
+
+        // deterministic, and within fixed-point precision of the float rotation
+        let angle = 0.7f64;
+        let sin = (angle.sin() * one as f64).round() as i32;
+        let cos = (angle.cos() * one as f64).round() as i32;
+        let r = v.rotate_fixed(sin, cos);
+        assert_eq!(r, v.rotate_fixed(sin, cos));
+
+        let fx = 1000.0 * angle.cos() + 250.0 * angle.sin();
+        let fy = 1000.0 * angle.sin() - 250.0 * angle.cos();
+        assert!((r.x as f64 - fx).abs() <= 1.0);
+        assert!((r.y as f64 - fy).abs() <= 1.0);
+    }
+
+    #[test]
+    fn rotate_fixed_overflow_test() {
+        let one = 1 << 16;
+        let max = Vec2::new(i32::MAX, 0);
+        assert_eq!(max.rotate_fixed(one, 0), Vec2::new(0, i32::MAX));
+        assert_eq!(max.rotate_fixed(0, -one), Vec2::new(-i32::MAX, 0));
+
+        // (2e9, 2e9) is ~2.83e9 long, which doesn't fit, so the result saturates
+        let eighth = (std::f64::consts::FRAC_PI_4.sin() * one as f64).round() as i32;
+        let v = Vec2::new(2_000_000_000, 2_000_000_000);
+        assert_eq!(v.rotate_fixed(eighth, eighth), Vec2::new(0, i32::MAX));
+        assert_eq!(
+            (v * -1).rotate_fixed(eighth, eighth),
+            Vec2::new(0, i32::MIN)
+        );
+    }
+
+    #[test]
+    fn midpoint_centroid_test() {
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(3.0, -6.0);
+        assert_eq!(a.midpoint(&b), Vec2::new(2.0, -2.0));
+
+        let triangle = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(0.0, 6.0),
+        ];
+        assert_eq!(Vec2::centroid(&triangle), Some(Vec2::new(1.0, 2.0)));
+        assert_eq!(Vec2::<f32>::centroid(&[]), None);
+    }
+
+    #[test]
+    fn smoothstep_test() {
+        let edge0 = Vec2::new(0.0, -1.0);
+        let edge1 = Vec2::new(1.0, 3.0);
+        let half = Vec2::new(0.5, 0.5);
+
+        for step in [Vec2::smoothstep, Vec2::smootherstep] {
+            assert_eq!(step(&edge0, &edge1, &edge0), Vec2::new(0.0, 0.0));
+            assert_eq!(step(&edge0, &edge1, &edge1), Vec2::new(1.0, 1.0));
+            assert_eq!(step(&edge0, &edge1, &Vec2::new(0.5, 1.0)), half);
+            // clamped outside the edges
+            assert_eq!(step(&edge0, &edge1, &(edge0 - 1.0)), Vec2::new(0.0, 0.0));
+            assert_eq!(step(&edge0, &edge1, &(edge1 + 1.0)), Vec2::new(1.0, 1.0));
+        }
+        assert_eq!(
+            Vec2::smoothstep(&edge0, &edge1, &edge0.lerp(&edge1, 0.25)),
+            Vec2::new(0.15625, 0.15625)
+        );
+    }
+
+    #[test]
+    fn polar_angle_grid_test() {
+        use std::f64::consts::PI;
+
+        for j in -8..8 {
+            let angle = PI * j as f64 / 8.0;
+            let (r, angle_out) = Vec2::from_polar(3.0, angle).to_polar();
+
+            assert!((r - 3.0).abs() < 1e-12);
+            assert!((angle_out - angle).abs() < 1e-12);
+        }
+        // the angle is degenerate at the origin
+        assert_eq!(Vec2::from_polar(0.0, 1.0).to_polar(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn remap_test() {
+        let (zero, one) = (Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+        let minus_one = Vec2::new(-1.0, -1.0);
+
+        assert_eq!(
+            Vec2::new(0.5, 0.5).remap(&zero, &one, &minus_one, &one),
+            zero
+        );
+        assert_eq!(zero.remap(&zero, &one, &minus_one, &one), minus_one);
+        assert_eq!(
+            Vec2::new(2.0, 2.0).remap(&zero, &one, &minus_one, &one),
+            Vec2::new(3.0, 3.0)
+        );
+        // screen to normalized device coordinates, flipping y
+        assert_eq!(
+            Vec2::new(200.0, 150.0).remap(
+                &zero,
+                &Vec2::new(800.0, 600.0),
+                &Vec2::new(-1.0, 1.0),
+                &Vec2::new(1.0, -1.0)
+            ),
+            Vec2::new(-0.5, 0.5)
+        );
+    }
+
+    #[test]
+    fn element_reductions_test() {
+        let v = Vec2::new(-3, 5);
+
+        assert_eq!(v.element_sum(), 2);
+        assert_eq!(v.element_product(), -15);
+        assert_eq!(v.min_element(), -3);
+        assert_eq!(v.max_element(), 5);
+    }
+
+    #[test]
+    fn element_index_test() {
+        assert_eq!(Vec2::new(-3, 5).min_element_index(), 0);
+        assert_eq!(Vec2::new(-3, 5).max_element_index(), 1);
+        // ties go to the lowest index
+        assert_eq!(Vec2::new(2.0, 2.0).min_element_index(), 0);
+        assert_eq!(Vec2::new(2.0, 2.0).max_element_index(), 0);
+    }
+
+    #[test]
+    fn signum_copysign_test() {
+        assert_eq!(Vec2::new(-7, 0).signum(), Vec2::new(-1, 0));
+        assert_eq!(Vec2::new(2.5, -0.0).signum(), Vec2::new(1.0, -1.0));
+        assert_eq!(
+            Vec2::new(3.0, -2.0).copysign(&Vec2::new(-1.0, 0.0)),
+            Vec2::new(-3.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn distance_metrics_test() {
+        let a = Vec2::new(1, 5);
+        let b = Vec2::new(4, -1);
+
+        assert_eq!(a.manhattan_distance(&b), 9);
+        assert_eq!(a.chebyshev_distance(&b), 6);
+
+        let v = Vec2::new(3.0, -4.0);
+        assert_eq!(v.lp_norm(2.0), v.length());
+        assert_eq!(v.lp_norm(1.0), 7.0);
+        assert_eq!(v.lp_norm(f64::INFINITY), 4.0);
+        assert_eq!(
+            Vec2::new(1.0, 1.0).lp_distance(&Vec2::new(4.0, 5.0), 2.0),
+            5.0
+        );
+    }
+
+    #[test]
+    fn pow_sqrt_test() {
+        assert_eq!(Vec2::new(4.0, 9.0).sqrt(), Vec2::new(2.0, 3.0));
+        assert_eq!(Vec2::new(2.0, -3.0).powi(2), Vec2::new(4.0, 9.0));
+        assert_eq!(Vec2::new(8.0, 27.0).powf(1.0 / 3.0), Vec2::new(2.0, 3.0));
+        assert!(Vec2::new(-1.0f32, 1.0).sqrt().x.is_nan());
+    }
+
+    #[test]
+    fn move_towards_test() {
+        let start = Vec2::new(0.0, 0.0);
+        let target = Vec2::new(3.0, 4.0);
+
+        assert!((start.move_towards(&target, 1.0) - Vec2::new(0.6, 0.8)).length() < 1e-12);
+        // overshooting lands exactly on the target
+        assert_eq!(start.move_towards(&target, 5.0), target);
+        assert_eq!(start.move_towards(&target, 100.0), target);
+        assert_eq!(target.move_towards(&target, 1.0), target);
+    }
+
+    #[test]
+    fn clamp_length_test() {
+        let v = Vec2::new(3.0, 4.0);
+
+        assert_eq!(v.clamp_length_max(2.5), Vec2::new(1.5, 2.0));
+        assert_eq!(v.clamp_length_max(10.0), v);
+        assert_eq!(v.clamp_length(10.0, 20.0), Vec2::new(6.0, 8.0));
+        assert_eq!(v.clamp_length(1.0, 20.0), v);
+        assert_eq!(v.clamp_length(1.0, 2.5).length(), 2.5);
+        // the zero vector stays zero rather than becoming NaN
+        assert_eq!(Vec2::zero().clamp_length_max(1.0), Vec2::zero());
+        assert_eq!(Vec2::zero().clamp_length(1.0, 2.0), Vec2::zero());
+    }
+
+    #[test]
+    fn recip_test() {
+        assert_eq!(Vec2::new(2.0, 4.0).recip(), Vec2::new(0.5, 0.25));
+        assert_eq!(
+            Vec2::new(0.0, -0.0).recip(),
+            Vec2::new(f32::INFINITY, f32::NEG_INFINITY)
+        );
+    }
+
+    #[test]
+    fn mul_add_test() {
+        let a = Vec2::new(2.0, -3.0);
+        let b = Vec2::new(4.0, 0.5);
+        let c = Vec2::new(1.0, 1.0);
+
+        assert_eq!(a.mul_add(&b, &c), Vec2::new(9.0, -0.5));
+    }
+
+    #[test]
+    fn outer_test() {
+        let a = Vec2::new(1, 2);
+        let b = Vec2::new(3, -4);
+        let m = a.outer(&b);
+
+        assert_eq!(m, Mat2::from_rows(Vec2::new(3, -4), Vec2::new(6, -8)));
+        assert_eq!(m.cols[0].x + m.cols[1].y, a.dot(&b));
+    }
+
+    #[test]
+    fn distance_test() {
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(3.0, 4.0);
+
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(b.distance(&a), 5.0);
+        assert_eq!(a.distance_squared(&b), 25.0);
+        assert_eq!(Vec2::new(1, 1).distance_squared(&Vec2::new(-1, 2)), 5);
+    }
+
+    #[test]
+    fn grid_distance_metrics_test() {
+        let origin = Vec2::new(0, 0);
+
+        // the 8 neighbours of a grid cell: 1 or 2 steps for a rook, always 1 for a king
+        for dx in -1i32..=1 {
+            for dy in -1i32..=1 {
+                let cell = Vec2::new(dx, dy);
+                assert_eq!(origin.manhattan_distance(&cell), dx.abs() + dy.abs());
+                assert_eq!(
+                    origin.chebyshev_distance(&cell),
+                    std::cmp::max(dx.abs(), dy.abs())
+                );
+            }
+        }
+        assert_eq!(Vec2::new(-2, 7).manhattan_distance(&Vec2::new(3, 1)), 11);
+        assert_eq!(Vec2::new(-2, 7).chebyshev_distance(&Vec2::new(3, 1)), 6);
+    }
+
+    #[test]
+    fn clamp_length_max_min_test() {
+        let v = Vec2::new(6.0, 8.0);
+
+        assert_eq!(v.clamp_length_max(5.0), Vec2::new(3.0, 4.0));
+        assert_eq!(v.clamp_length_max(5.0).length(), 5.0);
+        assert_eq!(v.clamp_length_max(20.0), v);
+        assert_eq!(v.clamp_length_min(20.0), Vec2::new(12.0, 16.0));
+        assert_eq!(v.clamp_length_min(5.0), v);
+        assert_eq!(Vec2::zero().clamp_length_min(1.0), Vec2::zero());
+    }
+
+    #[test]
+    fn move_towards_exact_step_test() {
+        let start = Vec2::new(1.0, 1.0);
+        let target = Vec2::new(1.0, 11.0);
+
+        let moved = start.move_towards(&target, 4.0);
+        assert_eq!(moved, Vec2::new(1.0, 5.0));
+        assert_eq!(start.distance(&moved), 4.0);
+    }
+
+    #[test]
+    fn compare_select_test() {
+        use crate::vector::BVec2;
+
+        let a = Vec2::new(1, 5);
+        let b = Vec2::new(3, 5);
+
+        assert_eq!(a.cmpeq(&b), BVec2::new(false, true));
+        assert_eq!(a.cmplt(&b), BVec2::new(true, false));
+        assert_eq!(a.cmple(&b), BVec2::new(true, true));
+        assert_eq!(a.cmpgt(&b), BVec2::new(false, false));
+        assert_eq!(a.cmpge(&b), BVec2::new(false, true));
+        assert_eq!(Vec2::select(a.cmplt(&b), a, b), a.min(b));
+    }
+
+    #[test]
+    fn cast_test() {
+        let v = Vec2::new(2.7_f32, -1.5);
+
+        assert_eq!(v.as_i32(), Vec2::new(2, -1));
+        assert_eq!(v.as_u32(), Vec2::new(2, 0));
+        assert_eq!(v.cast::<i32>(), Some(Vec2::new(2, -1)));
+        assert_eq!(v.cast::<u32>(), None);
+        assert_eq!(Vec2::new(f32::NAN, 0.0).cast::<i32>(), None);
+    }
+
+    #[test]
+    fn normalize_or_zero_test() {
+        let zero: Vec2<f32> = Vec2::new(0.0, 0.0);
+
+        assert_eq!(zero.normalize_or_zero(), zero);
+        assert_eq!(Vec2::new(f32::INFINITY, 1.0).normalize_or_zero(), zero);
+        assert!(
+            (Vec2::new(3.0_f32, 4.0).normalize_or_zero() - Vec2::new(0.6, 0.8)).length() < 1e-6
+        );
+        assert!(Vec2::new(3.0_f32, 4.0).normalize().is_normalized());
+        assert!(!Vec2::new(3.0_f32, 4.0).is_normalized());
+        assert!(!zero.is_normalized());
+    }
+
+    #[test]
+    fn bytes_round_trip_test() {
+        let v = Vec2::new(1.5_f32, -2.0);
+
+        assert_eq!(v.to_le_bytes(), [0, 0, 0xc0, 0x3f, 0, 0, 0, 0xc0]);
+        assert_eq!(v.to_be_bytes(), [0x3f, 0xc0, 0, 0, 0xc0, 0, 0, 0]);
+        assert_eq!(Vec2::<f32>::from_le_bytes(v.to_le_bytes()), v);
+        assert_eq!(Vec2::<f32>::from_be_bytes(v.to_be_bytes()), v);
+
+        let d = Vec2::new(1.0e-300_f64, f64::MAX);
+        assert_eq!(Vec2::<f64>::from_le_bytes(d.to_le_bytes()), d);
+        assert_eq!(Vec2::<f64>::from_be_bytes(d.to_be_bytes()), d);
+    }
+
+    #[test]
+    fn step_test() {
+        let edge = Vec2::new(0.5, -1.0);
+
+        assert_eq!(
+            Vec2::step(&edge, &Vec2::new(0.4, -1.0)),
+            Vec2::new(0.0, 1.0)
+        );
+        assert_eq!(
+            Vec2::smoothstep(&edge, &edge, &Vec2::new(0.4, -1.0)),
+            Vec2::new(0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn euclid_test() {
+        // the chunk containing a tile, and the tile's offset inside it
+        assert_eq!(Vec2::new(-1, 5).div_euclid(16), Vec2::new(-1, 0));
+        assert_eq!(Vec2::new(-1, 5).rem_euclid(16), Vec2::new(15, 5));
+        assert_eq!(Vec2::new(-1, 5) / 16, Vec2::new(0, 0));
+        assert_eq!(Vec2::new(-16, -17).div_euclid(16), Vec2::new(-1, -2));
+        assert_eq!(Vec2::new(-16, -17).rem_euclid(16), Vec2::new(0, 15));
+
+        assert_eq!(
+            Vec2::new(-7, 7).div_euclid_vec(Vec2::new(2, -2)),
+            Vec2::new(-4, -3)
+        );
+        assert_eq!(
+            Vec2::new(-7, 7).rem_euclid_vec(Vec2::new(2, -2)),
+            Vec2::new(1, 1)
+        );
+    }
+
+    #[test]
+    fn cross_extensions_test() {
+        use crate::vector::Vec3;
+
+        let lift = |v: Vec2<i32>| Vec3::new(v.x, v.y, 0);
+        let a = Vec2::new(3, -2);
+        let b = Vec2::new(1, 4);
+
+        assert_eq!(a.cross3(&b), lift(a).cross(&lift(b)));
+        assert_eq!(a.cross3(&b), Vec3::new(0, 0, 14));
+        assert_eq!(a.cross_scalar(5), Vec2::new(-10, -15));
+        let crossed = lift(a).cross(&Vec3::new(0, 0, 5));
+        assert_eq!(a.cross_scalar(5), Vec2::new(crossed.x, crossed.y));
+        // w x r for a spin of w about Z
+        let w = Vec3::new(0, 0, 2).cross(&lift(a));
+        assert_eq!(a.cross_scalar(-2), Vec2::new(w.x, w.y));
+    }
+
+    #[test]
+    fn face_forward_test() {
+        let reference = Vec2::new(0.0, 1.0);
+
+        assert_eq!(
+            Vec2::new(1.0, 2.0).face_forward(&reference),
+            Vec2::new(1.0, 2.0)
+        );
+        assert_eq!(
+            Vec2::new(1.0, -2.0).face_forward(&reference),
+            Vec2::new(-1.0, 2.0)
+        );
+        // perpendicular vectors are kept, and count as inside the hemisphere
+        assert_eq!(
+            Vec2::new(3.0, 0.0).face_forward(&reference),
+            Vec2::new(3.0, 0.0)
+        );
+        assert!(Vec2::new(3.0, 0.0).is_in_hemisphere(&reference));
+        assert!(!Vec2::new(3.0, -0.1).is_in_hemisphere(&reference));
     }
 }
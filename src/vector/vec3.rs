@@ -1,12 +1,30 @@
-use num::Num;
+use crate::matrix::{Mat3, Mat4};
+use crate::quaternion::Quat;
+use crate::scalar;
+use crate::vector::iter::{collect_components, ComponentCountError};
+use crate::vector::parse::{parse_components, ParseVecError};
+use crate::vector::{BVec3, Vec4};
+use num::{
+    traits::{
+        AsPrimitive, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Euclid, SaturatingAdd,
+        SaturatingMul, SaturatingSub, WrappingAdd, WrappingMul, WrappingSub,
+    },
+    Float, Num, NumCast, Signed, Zero,
+};
 use std::{
     fmt::Display,
-    ops::{Add, Div, Mul, Sub},
+    iter::{FromIterator, Sum},
+    ops::{Add, Div, Mul, Rem, Sub},
+    str::FromStr,
 };
 
 /// A generic 3D Vector implementation.
 /// Takes 3 generic numbers (all 3 must be same type).
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+///
+/// Vectors are ordered lexicographically (compare x, then y, then z), which gives a
+/// deterministic total order for sorting and `BTreeSet`s. This is not a
+/// geometric comparison: `a < b` says nothing about their lengths.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct Vec3<T>
 where
     T: Num + Clone + Copy,
@@ -42,271 +60,2541 @@ where
         }
     }
 
-    // TODO: Swizzle?
-}
+    /// Return the dot product of two 3D Vectors, taking both by value
+    ///
+    /// Equivalent to `a.dot(&b)`, but convenient for owned `Copy` vectors.
+    pub fn dot_v(self, rhs: Vec3<T>) -> T {
+        self.dot(&rhs)
+    }
 
-impl Vec3<f32> {
-    /// Create a 3D vector with all values initialised to 0.0
-    pub fn zero() -> Vec3<f32> {
-        Self {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-        }
+    /// Return the cross product of two 3D Vectors, taking both by value
+    ///
+    /// Equivalent to `a.cross(&b)`, but convenient for owned `Copy` vectors.
+    pub fn cross_v(self, rhs: Vec3<T>) -> Vec3<T> {
+        self.cross(&rhs)
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec3;
-/// let a = Vec3::new(2, 4, 8);
-/// let b = Vec3::new(16, 32, 64);
-/// let c = a + b;
-///
-/// assert_eq!(c, Vec3::new(a.x + b.x, a.y + b.y, a.z + b.z));
-/// ```
-impl<T> Add for Vec3<T>
-where
-    T: Num + Clone + Copy,
-{
-    type Output = Vec3<T>;
+    /// Return the scalar triple product of three 3D Vectors
+    ///
+    /// `a.dot(&b.cross(&c))`, the signed volume of the parallelepiped they span.
+    /// It is positive when `a`, `b`, `c` form a right-handed set and zero when
+    /// they are coplanar. A sixth of it is the signed volume of the tetrahedron
+    /// with edges `a`, `b`, `c` from a shared vertex.
+    pub fn scalar_triple(&self, b: &Vec3<T>, c: &Vec3<T>) -> T {
+        self.dot(&b.cross(c))
+    }
 
-    fn add(self, other: Vec3<T>) -> Self::Output {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-        }
+    /// Return the scalar triple product of three 3D Vectors
+    ///
+    /// Another name for `scalar_triple`: the sign gives the orientation of `a`,
+    /// `b`, `c` and the magnitude is the volume of their parallelepiped.
+    pub fn triple_product(&self, b: &Vec3<T>, c: &Vec3<T>) -> T {
+        self.scalar_triple(b, c)
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec3;
-/// let a = Vec3::new(4, 7, 8);
-/// let b = 2;
-/// let c = a + b;
-///
-/// assert_eq!(c, Vec3::new(4 + 2, 7 + 2, 8 + 2));
-/// ```
-impl<T> Add<T> for Vec3<T>
-where
-    T: Num + Clone + Copy,
-{
-    type Output = Vec3<T>;
+    /// Return the cross product Matrix `[v]x` of the 3D Vector
+    ///
+    /// The skew-symmetric Matrix for which `m * w == v.cross(&w)`.
+    pub fn skew_symmetric_matrix(&self) -> Mat3<T> {
+        let z = T::zero();
+        Mat3::from_rows(
+            Vec3::new(z, z - self.z, self.y),
+            Vec3::new(self.z, z, z - self.x),
+            Vec3::new(z - self.y, self.x, z),
+        )
+    }
 
-    fn add(self, other: T) -> Self::Output {
-        Self {
-            x: self.x + other,
-            y: self.y + other,
-            z: self.z + other,
-        }
+    /// Return the 3D Vector as a homogeneous point, `(x, y, z, 1)`
+    ///
+    /// Points are affected by the translation of a 4x4 Matrix.
+    pub fn to_homogeneous_point(&self) -> Vec4<T> {
+        Vec4::new(self.x, self.y, self.z, T::one())
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec3;
-/// let a = Vec3::new(2, 4, 8);
-/// let b = Vec3::new(16, 32, 64);
-/// let c = a - b;
-///
-/// assert_eq!(c, Vec3::new(a.x - b.x, a.y - b.y, a.z - b.z));
-/// ```
-impl<T> Sub for Vec3<T>
-where
-    T: Num + Clone + Copy,
-{
-    type Output = Vec3<T>;
+    /// Return the 3D Vector as a homogeneous direction, `(x, y, z, 0)`
+    ///
+    /// Directions ignore the translation of a 4x4 Matrix.
+    pub fn to_homogeneous_vector(&self) -> Vec4<T> {
+        Vec4::new(self.x, self.y, self.z, T::zero())
+    }
 
-    fn sub(self, other: Vec3<T>) -> Self::Output {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-        }
+    /// Return the squared length of the 3D Vector
+    ///
+    /// `v.dot(&v)`, which avoids the square root needed by `length`
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec3;
-/// let a = Vec3::new(4, 7, 8);
-/// let b = 2;
-/// let c = a - b;
-///
-/// assert_eq!(c, Vec3::new(4 - 2, 7 - 2, 8 - 2));
-/// ```
-impl<T> Sub<T> for Vec3<T>
-where
-    T: Num + Clone + Copy,
-{
-    type Output = Vec3<T>;
+    /// Return the squared distance between two 3D Vectors
+    ///
+    /// `(self - rhs).length_squared()`, which is cheap enough for comparing distances
+    pub fn distance_squared(&self, rhs: &Vec3<T>) -> T {
+        (*self - *rhs).length_squared()
+    }
 
-    fn sub(self, other: T) -> Self::Output {
-        Self {
-            x: self.x - other,
-            y: self.y - other,
-            z: self.z - other,
-        }
+    /// Linearly interpolate between two 3D Vectors
+    ///
+    /// `a + (b - a) * t`, so `t = 0` gives `a` and `t = 1` gives `b`
+    pub fn lerp(&self, rhs: &Vec3<T>, t: T) -> Vec3<T> {
+        *self + (*rhs - *self) * t
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec3;
-/// let a = Vec3::new(2, 4, 8);
-/// let b = Vec3::new(16, 32, 64);
-/// let c = a * b;
-///
-/// assert_eq!(c, a.cross(&b));
-/// ```
-impl<T> Mul for Vec3<T>
-where
-    T: Num + Clone + Copy,
-{
-    type Output = Vec3<T>;
+    /// Return the outer (tensor) product of two 3D Vectors
+    ///
+    /// `self * other^T`, so entry `(row i, column j)` is `self[i] * other[j]`.
+    /// Its trace is `self.dot(&other)`.
+    pub fn outer(&self, other: &Vec3<T>) -> Mat3<T> {
+        Mat3::new(*self * other.x, *self * other.y, *self * other.z)
+    }
 
-    fn mul(self, other: Vec3<T>) -> Self::Output {
-        self.cross(&other)
+    /// Apply `f` to each component, producing a new 3D Vector
+    ///
+    /// The output scalar may differ from the input, but must still be a number
+    /// (`U: Num + Copy`), so e.g. `Vec3<f32>` to `Vec3<i32>` works but `Vec3<bool>` does not.
+    pub fn map<U, F>(self, f: F) -> Vec3<U>
+    where
+        U: Num + Clone + Copy,
+        F: Fn(T) -> U,
+    {
+        Vec3::new(f(self.x), f(self.y), f(self.z))
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec3;
-/// let a = Vec3::new(4, 7, 8);
-/// let b = 2;
-/// let c = a * b;
-///
-/// assert_eq!(c, Vec3::new(4 * 2, 7 * 2, 8 * 2));
-/// ```
-impl<T> Mul<T> for Vec3<T>
-where
-    T: Num + Clone + Copy,
-{
-    type Output = Vec3<T>;
+    /// Like `map`, but `f` also receives the component index (x = 0, y = 1, z = 2)
+    pub fn map_with_index<U, F>(self, f: F) -> Vec3<U>
+    where
+        U: Num + Clone + Copy,
+        F: Fn(usize, T) -> U,
+    {
+        Vec3::new(f(0, self.x), f(1, self.y), f(2, self.z))
+    }
 
-    fn mul(self, other: T) -> Self::Output {
-        Self {
-            x: self.x * other,
-            y: self.y * other,
-            z: self.z * other,
-        }
+    /// Combine the matching components of two 3D Vectors with `f`
+    pub fn zip_with<F>(self, other: Vec3<T>, f: F) -> Vec3<T>
+    where
+        F: Fn(T, T) -> T,
+    {
+        Vec3::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
     }
-}
 
-/// Allows for the following syntax:
-/// ```rust
-/// # use lamar::vector::Vec3;
-/// let a = Vec3::new(4, 6, 12);
-/// let b = 2;
-/// let c = a / b;
-///
-/// assert_eq!(c, Vec3::new(4 / 2, 6 / 2, 12 / 2));
-/// ```
-impl<T> Div<T> for Vec3<T>
-where
-    T: Num + Clone + Copy,
-{
-    type Output = Vec3<T>;
+    /// Return the component-wise minimum of two 3D Vectors
+    ///
+    /// Takes both vectors by value, so this takes precedence over `Ord::min`
+    /// (which compares vectors lexicographically).
+    pub fn min(self, rhs: Vec3<T>) -> Vec3<T>
+    where
+        T: PartialOrd,
+    {
+        Vec3 {
+            x: if self.x < rhs.x { self.x } else { rhs.x },
+            y: if self.y < rhs.y { self.y } else { rhs.y },
+            z: if self.z < rhs.z { self.z } else { rhs.z },
+        }
+    }
 
-    fn div(self, other: T) -> Self::Output {
-        Self {
-            x: self.x / other,
-            y: self.y / other,
-            z: self.z / other,
+    /// Return the component-wise maximum of two 3D Vectors
+    ///
+    /// Takes both vectors by value, so this takes precedence over `Ord::max`
+    /// (which compares vectors lexicographically).
+    pub fn max(self, rhs: Vec3<T>) -> Vec3<T>
+    where
+        T: PartialOrd,
+    {
+        Vec3 {
+            x: if self.x > rhs.x { self.x } else { rhs.x },
+            y: if self.y > rhs.y { self.y } else { rhs.y },
+            z: if self.z > rhs.z { self.z } else { rhs.z },
         }
     }
-}
 
-impl<T> Display for Vec3<T>
-where
-    T: Display + Num + Clone + Copy,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "x: {}\ny: {}\nz: {}", self.x, self.y, self.z)
+    /// Return a mask of which components equal the matching component of `rhs`
+    pub fn cmpeq(&self, rhs: &Vec3<T>) -> BVec3 {
+        BVec3::new(self.x == rhs.x, self.y == rhs.y, self.z == rhs.z)
     }
-}
 
-#[cfg(test)]
-mod test {
-    use crate::vector::Vec3;
+    /// Return a mask of which components are less than the matching component of `rhs`
+    pub fn cmplt(&self, rhs: &Vec3<T>) -> BVec3
+    where
+        T: PartialOrd,
+    {
+        BVec3::new(self.x < rhs.x, self.y < rhs.y, self.z < rhs.z)
+    }
 
-    #[test]
-    fn zero_vec3_test() {
-        assert_eq!(Vec3::zero(), Vec3::new(0.0, 0.0, 0.0));
+    /// Return a mask of which components are less than or equal to the matching component of `rhs`
+    pub fn cmple(&self, rhs: &Vec3<T>) -> BVec3
+    where
+        T: PartialOrd,
+    {
+        BVec3::new(self.x <= rhs.x, self.y <= rhs.y, self.z <= rhs.z)
     }
 
-    #[test]
-    fn dot_product_test() {
-        let lhs = Vec3::new(2, 4, 8);
-        let rhs = Vec3::new(16, 32, 64);
+    /// Return a mask of which components are greater than the matching component of `rhs`
+    pub fn cmpgt(&self, rhs: &Vec3<T>) -> BVec3
+    where
+        T: PartialOrd,
+    {
+        BVec3::new(self.x > rhs.x, self.y > rhs.y, self.z > rhs.z)
+    }
 
-        assert_eq!(lhs.dot(&rhs), 672);
+    /// Return a mask of which components are greater than or equal to the matching component of `rhs`
+    pub fn cmpge(&self, rhs: &Vec3<T>) -> BVec3
+    where
+        T: PartialOrd,
+    {
+        BVec3::new(self.x >= rhs.x, self.y >= rhs.y, self.z >= rhs.z)
     }
 
-    #[test]
-    fn cross_product_test() {
-        let lhs = Vec3::new(5, 10, 15);
-        let rhs = Vec3::new(3, 1, 7);
+    /// Pick each component from `if_true` where `mask` is set, and from `if_false` otherwise
+    pub fn select(mask: BVec3, if_true: Vec3<T>, if_false: Vec3<T>) -> Self {
+        Self::new(
+            if mask.x { if_true.x } else { if_false.x },
+            if mask.y { if_true.y } else { if_false.y },
+            if mask.z { if_true.z } else { if_false.z },
+        )
+    }
 
-        assert_eq!(lhs.cross(&rhs), Vec3::new(55, 10, -25));
+    /// Convert each component to another numeric type, or `None` if any component is out of range
+    pub fn cast<U>(&self) -> Option<Vec3<U>>
+    where
+        T: NumCast,
+        U: Num + NumCast + Copy,
+    {
+        Some(Vec3::new(
+            U::from(self.x)?,
+            U::from(self.y)?,
+            U::from(self.z)?,
+        ))
     }
 
-    #[test]
-    fn cross_product_mul_trait_test() {
-        let lhs = Vec3::new(5, 10, 15);
-        let rhs = Vec3::new(3, 1, 7);
+    /// Convert each component to `f32` with `as` semantics, truncating or saturating where needed
+    pub fn as_f32(&self) -> Vec3<f32>
+    where
+        T: AsPrimitive<f32>,
+    {
+        Vec3::new(self.x.as_(), self.y.as_(), self.z.as_())
+    }
 
-        assert_eq!(lhs * rhs, Vec3::new(55, 10, -25));
+    /// Convert each component to `f64` with `as` semantics, truncating or saturating where needed
+    pub fn as_f64(&self) -> Vec3<f64>
+    where
+        T: AsPrimitive<f64>,
+    {
+        Vec3::new(self.x.as_(), self.y.as_(), self.z.as_())
     }
 
-    #[test]
-    fn add_test() {
-        let lhs = Vec3::new(2, 4, 8);
-        let rhs = Vec3::new(16, 32, 64);
+    /// Convert each component to `i32` with `as` semantics, truncating or saturating where needed
+    pub fn as_i32(&self) -> Vec3<i32>
+    where
+        T: AsPrimitive<i32>,
+    {
+        Vec3::new(self.x.as_(), self.y.as_(), self.z.as_())
+    }
 
-        assert_eq!(lhs + rhs, Vec3::new(18, 36, 72));
+    /// Convert each component to `u32` with `as` semantics, truncating or saturating where needed
+    pub fn as_u32(&self) -> Vec3<u32>
+    where
+        T: AsPrimitive<u32>,
+    {
+        Vec3::new(self.x.as_(), self.y.as_(), self.z.as_())
     }
 
-    #[test]
-    fn sub_test() {
-        let lhs = Vec3::new(2, 4, 8);
-        let rhs = Vec3::new(16, 32, 64);
+    /// Divide each component by `rhs`, rounding towards negative infinity for a positive `rhs`
+    ///
+    /// Unlike `/`, negative components land in the right cell of a grid of size `rhs`:
+    /// `-1` divided by `16` is `-1`, not `0`. Matches `i32::div_euclid` and `f32::div_euclid`.
+    pub fn div_euclid(self, rhs: T) -> Self
+    where
+        T: Euclid,
+    {
+        Vec3::new(
+            self.x.div_euclid(&rhs),
+            self.y.div_euclid(&rhs),
+            self.z.div_euclid(&rhs),
+        )
+    }
 
-        assert_eq!(lhs - rhs, Vec3::new(-14, -28, -56));
+    /// Return the remainder of each component divided by `rhs`, which is never negative
+    ///
+    /// Unlike `%`, `-1` modulo `16` is `15`. Matches `i32::rem_euclid` and `f32::rem_euclid`.
+    pub fn rem_euclid(self, rhs: T) -> Self
+    where
+        T: Euclid,
+    {
+        Vec3::new(
+            self.x.rem_euclid(&rhs),
+            self.y.rem_euclid(&rhs),
+            self.z.rem_euclid(&rhs),
+        )
     }
 
-    #[test]
-    fn add_scalar_test() {
-        let lhs = Vec3::new(32, 64, 128);
-        let rhs = 10;
+    /// Like `div_euclid`, dividing by the matching component of `rhs`
+    pub fn div_euclid_vec(self, rhs: Vec3<T>) -> Self
+    where
+        T: Euclid,
+    {
+        Vec3::new(
+            self.x.div_euclid(&rhs.x),
+            self.y.div_euclid(&rhs.y),
+            self.z.div_euclid(&rhs.z),
+        )
+    }
 
-        assert_eq!(lhs + rhs, Vec3::new(42, 74, 138));
+    /// Like `rem_euclid`, dividing by the matching component of `rhs`
+    pub fn rem_euclid_vec(self, rhs: Vec3<T>) -> Self
+    where
+        T: Euclid,
+    {
+        Vec3::new(
+            self.x.rem_euclid(&rhs.x),
+            self.y.rem_euclid(&rhs.y),
+            self.z.rem_euclid(&rhs.z),
+        )
     }
 
-    #[test]
-    fn sub_scalar_test() {
-        let lhs = Vec3::new(32, 64, 128);
-        let rhs = 10;
+    /// Return the sum of the components
+    ///
+    /// `x + y + z`
+    pub fn element_sum(&self) -> T {
+        self.x + self.y + self.z
+    }
 
-        assert_eq!(lhs - rhs, Vec3::new(22, 54, 118));
+    /// Return the product of the components
+    ///
+    /// `x * y * z`
+    pub fn element_product(&self) -> T {
+        self.x * self.y * self.z
     }
 
-    #[test]
-    fn mul_scalar_test() {
-        let lhs = Vec3::new(32, 64, 128);
-        let rhs = 10;
+    /// Return the smallest component
+    pub fn min_element(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        let mut m = self.x;
+        if self.y < m {
+            m = self.y;
+        }
+        if self.z < m {
+            m = self.z;
+        }
+        m
+    }
 
-        assert_eq!(lhs * rhs, Vec3::new(320, 640, 1280));
+    /// Return the largest component
+    pub fn max_element(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        let mut m = self.x;
+        if self.y > m {
+            m = self.y;
+        }
+        if self.z > m {
+            m = self.z;
+        }
+        m
     }
 
-    #[test]
-    fn div_scalar_test() {
-        let lhs = Vec3::new(32, 64, 128);
-        let rhs = 4;
+    /// Return the index of the smallest component (0 for x, 1 for y, ...)
+    ///
+    /// Ties go to the lowest index, so `(1, 0, 0)` gives 1.
+    pub fn min_element_index(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        self.iter()
+            .enumerate()
+            .fold(
+                (0, self.x),
+                |(index, m), (i, &c)| if c < m { (i, c) } else { (index, m) },
+            )
+            .0
+    }
 
-        assert_eq!(lhs / rhs, Vec3::new(8, 16, 32));
+    /// Return the index of the largest component (0 for x, 1 for y, ...)
+    ///
+    /// Ties go to the lowest index, so `(0, 1, 1)` gives 1.
+    pub fn max_element_index(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        self.iter()
+            .enumerate()
+            .fold(
+                (0, self.x),
+                |(index, m), (i, &c)| if c > m { (i, c) } else { (index, m) },
+            )
+            .0
+    }
+
+    /// Return the sign of each component
+    ///
+    /// Integer components give 0 for zero. Float components follow IEEE 754 and
+    /// give 1 for +0.0 and -1 for -0.0, and NaN stays NaN.
+    pub fn signum(&self) -> Self
+    where
+        T: Signed,
+    {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// Return the Manhattan (taxicab) distance between two 3D Vectors
+    ///
+    /// The sum of the absolute component differences.
+    pub fn manhattan_distance(&self, other: &Vec3<T>) -> T
+    where
+        T: Signed,
+    {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Return the Chebyshev (chessboard) distance between two 3D Vectors
+    ///
+    /// The largest absolute component difference.
+    pub fn chebyshev_distance(&self, other: &Vec3<T>) -> T
+    where
+        T: Signed + PartialOrd,
+    {
+        (*self - *other).map(|c| c.abs()).max_element()
+    }
+
+    /// Return an iterator over references to the components, in x, y, z order
+    pub fn iter(&self) -> std::array::IntoIter<&T, 3> {
+        [&self.x, &self.y, &self.z].into_iter()
+    }
+
+    /// Return an iterator over mutable references to the components, in x, y, z order
+    pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut T, 3> {
+        [&mut self.x, &mut self.y, &mut self.z].into_iter()
+    }
+}
+
+impl Vec3<f32> {
+    /// Create a 3D vector with all values initialised to 0.0
+    pub fn zero() -> Vec3<f32> {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }
+    }
+
+    /// Snap the 3D Vector onto an integer grid made of cells of size `cell_size`
+    ///
+    /// `floor(v / cell_size)`
+    pub fn quantize(&self, cell_size: f32) -> Vec3<i32> {
+        Vec3 {
+            x: (self.x / cell_size).floor() as i32,
+            y: (self.y / cell_size).floor() as i32,
+            z: (self.z / cell_size).floor() as i32,
+        }
+    }
+
+    /// Return the signed volume of the tetrahedron `a, b, c, d`
+    ///
+    /// `(1/6) * (b - a).dot(&(c - a).cross(&(d - a)))`, which is positive when
+    /// the edges `b - a`, `c - a` and `d - a` form a right-handed set.
+    pub fn signed_volume(a: &Vec3<f32>, b: &Vec3<f32>, c: &Vec3<f32>, d: &Vec3<f32>) -> f32 {
+        (*b - *a).dot(&(*c - *a).cross(&(*d - *a))) / 6.0
+    }
+
+    /// Return the (unsigned) volume of the tetrahedron `a, b, c, d`
+    pub fn tetrahedron_volume(a: &Vec3<f32>, b: &Vec3<f32>, c: &Vec3<f32>, d: &Vec3<f32>) -> f32 {
+        Self::signed_volume(a, b, c, d).abs()
+    }
+
+    /// Return the volume enclosed by a closed triangle mesh
+    ///
+    /// Sums the signed volumes of the tetrahedra formed by the origin and each
+    /// triangle, so the mesh must be closed with consistent outward facing
+    /// (counter-clockwise) winding. Inward winding gives a negative volume.
+    pub fn mesh_volume(vertices: &[Vec3<f32>], triangles: &[[usize; 3]]) -> f32 {
+        let origin = Vec3::zero();
+        triangles
+            .iter()
+            .map(|[a, b, c]| {
+                Self::signed_volume(&origin, &vertices[*a], &vertices[*b], &vertices[*c])
+            })
+            .sum()
+    }
+
+    /// Pack the 3D Vector for a std140 uniform block
+    ///
+    /// std140 aligns a `vec3` to 16 bytes, so the components are followed by a
+    /// zero padding float: `[x, y, z, 0.0]`.
+    pub fn to_std140(&self) -> [f32; 4] {
+        [self.x, self.y, self.z, 0.0]
+    }
+
+    /// Unpack a 3D Vector written with `to_std140`, ignoring the padding float
+    pub fn from_std140(data: [f32; 4]) -> Self {
+        Self::new(data[0], data[1], data[2])
+    }
+
+    /// Return the point of `points` farthest along `dir` (the support point)
+    ///
+    /// Ties keep the earliest point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    pub fn support(points: &[Vec3<f32>], dir: &Vec3<f32>) -> Vec3<f32> {
+        let mut best = *points.first().expect("support needs at least one point");
+        for p in &points[1..] {
+            if p.dot(dir) > best.dot(dir) {
+                best = *p;
+            }
+        }
+        best
+    }
+
+    /// Evaluate a spherical Gaussian lobe in the direction of this (unit) 3D Vector
+    ///
+    /// `amplitude * exp(sharpness * (self.dot(axis) - 1))`
+    ///
+    /// The lobe peaks at `amplitude` along `axis` (also expected to be unit length)
+    /// and falls off with the angle from it; higher `sharpness` gives a narrower lobe.
+    pub fn spherical_gaussian(
+        &self,
+        axis: &Vec3<f32>,
+        sharpness: f32,
+        amplitude: Vec3<f32>,
+    ) -> Vec3<f32> {
+        amplitude * (sharpness * (self.dot(axis) - 1.0)).exp()
+    }
+
+    /// Importance sample a microfacet half vector around `normal` from the GGX distribution
+    ///
+    /// `u` and `v` are uniform random numbers in `[0, 1)`, and `roughness` is the
+    /// perceptual roughness (squared to get GGX alpha, as in UE4). `normal` must be
+    /// unit length. The returned unit vector is always in the hemisphere around
+    /// `normal`, concentrated near it for low roughness.
+    pub fn importance_sample_ggx(u: f32, v: f32, roughness: f32, normal: &Vec3<f32>) -> Vec3<f32> {
+        let alpha = roughness * roughness;
+        let phi = std::f32::consts::TAU * u;
+        let cos_theta = ((1.0 - v) / (1.0 + (alpha * alpha - 1.0) * v)).sqrt();
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+
+        let (tangent, bitangent) = normal.any_orthonormal_pair();
+        (tangent * (sin_theta * phi.cos())
+            + bitangent * (sin_theta * phi.sin())
+            + *normal * cos_theta)
+            .normalize()
+    }
+
+    /// Return the components packed as little-endian bytes, in `x, y, z` order
+    pub fn to_le_bytes(&self) -> [u8; 12] {
+        let mut bytes = [0; 12];
+        for (chunk, c) in bytes.chunks_exact_mut(4).zip([self.x, self.y, self.z]) {
+            chunk.copy_from_slice(&c.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 3D Vector from components packed as little-endian bytes, in `x, y, z` order
+    pub fn from_le_bytes(bytes: [u8; 12]) -> Self {
+        let c = |i: usize| f32::from_le_bytes(bytes[i * 4..(i + 1) * 4].try_into().unwrap());
+        Self::new(c(0), c(1), c(2))
+    }
+
+    /// Return the components packed as big-endian bytes, in `x, y, z` order
+    pub fn to_be_bytes(&self) -> [u8; 12] {
+        let mut bytes = [0; 12];
+        for (chunk, c) in bytes.chunks_exact_mut(4).zip([self.x, self.y, self.z]) {
+            chunk.copy_from_slice(&c.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 3D Vector from components packed as big-endian bytes, in `x, y, z` order
+    pub fn from_be_bytes(bytes: [u8; 12]) -> Self {
+        let c = |i: usize| f32::from_be_bytes(bytes[i * 4..(i + 1) * 4].try_into().unwrap());
+        Self::new(c(0), c(1), c(2))
+    }
+}
+
+impl Vec3<f64> {
+    /// Return the components packed as little-endian bytes, in `x, y, z` order
+    pub fn to_le_bytes(&self) -> [u8; 24] {
+        let mut bytes = [0; 24];
+        for (chunk, c) in bytes.chunks_exact_mut(8).zip([self.x, self.y, self.z]) {
+            chunk.copy_from_slice(&c.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 3D Vector from components packed as little-endian bytes, in `x, y, z` order
+    pub fn from_le_bytes(bytes: [u8; 24]) -> Self {
+        let c = |i: usize| f64::from_le_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap());
+        Self::new(c(0), c(1), c(2))
+    }
+
+    /// Return the components packed as big-endian bytes, in `x, y, z` order
+    pub fn to_be_bytes(&self) -> [u8; 24] {
+        let mut bytes = [0; 24];
+        for (chunk, c) in bytes.chunks_exact_mut(8).zip([self.x, self.y, self.z]) {
+            chunk.copy_from_slice(&c.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 3D Vector from components packed as big-endian bytes, in `x, y, z` order
+    pub fn from_be_bytes(bytes: [u8; 24]) -> Self {
+        let c = |i: usize| f64::from_be_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap());
+        Self::new(c(0), c(1), c(2))
+    }
+}
+
+impl<T> Vec3<T>
+where
+    T: Float,
+{
+    /// Return the length (magnitude) of the 3D Vector
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Return the distance between two 3D Vectors
+    ///
+    /// `(self - rhs).length()`
+    pub fn distance(&self, rhs: &Vec3<T>) -> T {
+        (*self - *rhs).length()
+    }
+
+    /// Return a unit length copy of the 3D Vector
+    ///
+    /// A zero length vector has no direction, so the result will be NaN.
+    pub fn normalize(&self) -> Self {
+        *self / self.length()
+    }
+
+    /// Return the 3D Vector flipped if needed so it points to the same side as `reference`
+    ///
+    /// Returns `self` when `self.dot(&reference) >= 0`, and `-self` otherwise. A
+    /// vector exactly perpendicular to `reference` is kept as it is.
+    ///
+    /// GLSL's `faceforward(N, I, Nref)` instead takes an incident direction `I`
+    /// pointing *towards* the surface, and returns `N` when `dot(Nref, I) < 0`.
+    /// So `n.face_forward(&r)` is `faceforward(n, -r, n)`, except that GLSL negates
+    /// the perpendicular case.
+    pub fn face_forward(&self, reference: &Vec3<T>) -> Self {
+        if self.is_in_hemisphere(reference) {
+            *self
+        } else {
+            *self * -T::one()
+        }
+    }
+
+    /// Return whether the 3D Vector lies in the closed hemisphere around `normal`
+    ///
+    /// `self.dot(&normal) >= 0`, so vectors on the boundary count as inside, and
+    /// `face_forward` leaves exactly these vectors unchanged.
+    pub fn is_in_hemisphere(&self, normal: &Vec3<T>) -> bool {
+        self.dot(normal) >= T::zero()
+    }
+
+    /// Return the surface normal transformed by the model Matrix `m`, normalized
+    ///
+    /// Normals are transformed by `m.normal_matrix()` rather than `m` itself, so they
+    /// stay perpendicular to the surface under non-uniform scale. Returns `None` if
+    /// `m` is singular.
+    pub fn transform_normal(&self, m: &Mat4<T>) -> Option<Self> {
+        Some((m.normal_matrix()? * *self).normalize())
+    }
+
+    /// Return a unit length copy of the 3D Vector, or the zero vector when it has
+    /// no usable direction (a zero, infinite or NaN length)
+    pub fn normalize_or_zero(&self) -> Self {
+        let inv = self.length().recip();
+        if inv.is_finite() && inv > T::zero() {
+            *self * inv
+        } else {
+            Self::new(T::zero(), T::zero(), T::zero())
+        }
+    }
+
+    /// Return whether the 3D Vector has unit length
+    ///
+    /// The squared length must be within `sqrt(epsilon)` of 1, which allows for
+    /// the rounding error of a `normalize`.
+    pub fn is_normalized(&self) -> bool {
+        (self.length_squared() - T::one()).abs() <= T::epsilon().sqrt()
+    }
+
+    /// Return the point halfway between two 3D Vectors
+    ///
+    /// `(self + rhs) / 2`
+    pub fn midpoint(&self, rhs: &Vec3<T>) -> Self {
+        (*self + *rhs) / (T::one() + T::one())
+    }
+
+    /// Return the average of a slice of 3D Vectors, or `None` if it is empty
+    pub fn centroid(points: &[Vec3<T>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let sum: Vec3<T> = points.iter().sum();
+        Some(sum / T::from(points.len())?)
+    }
+
+    /// Component-wise step: 0 where `x` is below `edge`, and 1 otherwise
+    pub fn step(edge: &Vec3<T>, x: &Vec3<T>) -> Self {
+        Self::new(
+            scalar::step(edge.x, x.x),
+            scalar::step(edge.y, x.y),
+            scalar::step(edge.z, x.z),
+        )
+    }
+
+    /// Component-wise Hermite smoothing of `x` between `edge0` and `edge1`
+    ///
+    /// `t = clamp((x - edge0) / (edge1 - edge0), 0, 1)`, then `3t² - 2t³`
+    ///
+    /// Components with equal edges become a hard `step` instead of dividing by zero.
+    pub fn smoothstep(edge0: &Vec3<T>, edge1: &Vec3<T>, x: &Vec3<T>) -> Self {
+        Self::new(
+            scalar::smoothstep(edge0.x, edge1.x, x.x),
+            scalar::smoothstep(edge0.y, edge1.y, x.y),
+            scalar::smoothstep(edge0.z, edge1.z, x.z),
+        )
+    }
+
+    /// Like `smoothstep`, but with zero first and second derivatives at the edges
+    ///
+    /// `t = clamp((x - edge0) / (edge1 - edge0), 0, 1)`, then `6t⁵ - 15t⁴ + 10t³`
+    pub fn smootherstep(edge0: &Vec3<T>, edge1: &Vec3<T>, x: &Vec3<T>) -> Self {
+        Self::new(
+            scalar::smootherstep(edge0.x, edge1.x, x.x),
+            scalar::smootherstep(edge0.y, edge1.y, x.y),
+            scalar::smootherstep(edge0.z, edge1.z, x.z),
+        )
+    }
+
+    /// Linearly rescale each component from `in_min..in_max` to `out_min..out_max`
+    ///
+    /// `out_min + (v - in_min) * (out_max - out_min) / (in_max - in_min)`
+    ///
+    /// Values outside the input range extrapolate rather than clamp. A component
+    /// with `in_min == in_max` divides by zero.
+    pub fn remap(
+        &self,
+        in_min: &Vec3<T>,
+        in_max: &Vec3<T>,
+        out_min: &Vec3<T>,
+        out_max: &Vec3<T>,
+    ) -> Self {
+        Self::new(
+            scalar::remap(self.x, in_min.x, in_max.x, out_min.x, out_max.x),
+            scalar::remap(self.y, in_min.y, in_max.y, out_min.y, out_max.y),
+            scalar::remap(self.z, in_min.z, in_max.z, out_min.z, out_max.z),
+        )
+    }
+
+    /// Return the magnitude of each component with the sign of the matching component of `sign`
+    ///
+    /// The sign bit is copied directly, so a `sign` of -0.0 makes the result negative.
+    pub fn copysign(&self, sign: &Vec3<T>) -> Self {
+        Self::new(
+            self.x.copysign(sign.x),
+            self.y.copysign(sign.y),
+            self.z.copysign(sign.z),
+        )
+    }
+
+    /// Return the Lp norm of the 3D Vector
+    ///
+    /// `(|x|^p + |y|^p + ...)^(1/p)`. `p = 1` is the Manhattan length, `p = 2` the
+    /// Euclidean `length`, and `p = infinity` the largest absolute component.
+    pub fn lp_norm(&self, p: T) -> T {
+        let abs = self.map(|c| c.abs());
+        if p.is_infinite() {
+            return abs.max_element();
+        }
+        abs.map(|c| c.powf(p)).element_sum().powf(p.recip())
+    }
+
+    /// Return the Lp distance between two 3D Vectors
+    ///
+    /// `(self - other).lp_norm(p)`
+    pub fn lp_distance(&self, other: &Vec3<T>, p: T) -> T {
+        (*self - *other).lp_norm(p)
+    }
+
+    /// Raise each component to the float power `exp`
+    pub fn powf(&self, exp: T) -> Self {
+        self.map(|c| c.powf(exp))
+    }
+
+    /// Raise each component to the integer power `exp`
+    pub fn powi(&self, exp: i32) -> Self {
+        self.map(|c| c.powi(exp))
+    }
+
+    /// Return the square root of each component
+    ///
+    /// Negative components give NaN.
+    pub fn sqrt(&self) -> Self {
+        self.map(|c| c.sqrt())
+    }
+
+    /// Return the reciprocal `1 / c` of each component
+    ///
+    /// Zero components give infinity (with the sign of the zero).
+    pub fn recip(&self) -> Self {
+        self.map(|c| c.recip())
+    }
+
+    /// Return `self * mul + add` component-wise, as fused multiply-adds
+    ///
+    /// Each component is computed with a single rounding, which is more accurate
+    /// (and often faster) than a separate multiply and add.
+    pub fn mul_add(&self, mul: &Vec3<T>, add: &Vec3<T>) -> Self {
+        Self::new(
+            self.x.mul_add(mul.x, add.x),
+            self.y.mul_add(mul.y, add.y),
+            self.z.mul_add(mul.z, add.z),
+        )
+    }
+
+    /// Move towards `target` by at most `max_delta`, without overshooting
+    ///
+    /// When `target` is within `max_delta` it is returned exactly.
+    pub fn move_towards(&self, target: &Vec3<T>, max_delta: T) -> Self {
+        let delta = *target - *self;
+        let distance = delta.length();
+        if distance <= max_delta || distance == T::zero() {
+            return *target;
+        }
+        *self + delta * (max_delta / distance)
+    }
+
+    /// Return the 3D Vector rescaled so its length is within `min..=max`
+    ///
+    /// The zero vector has no direction to grow along, so it is returned unchanged.
+    pub fn clamp_length(&self, min: T, max: T) -> Self {
+        let length = self.length();
+        if length == T::zero() {
+            *self
+        } else if length > max {
+            *self * (max / length)
+        } else if length < min {
+            *self * (min / length)
+        } else {
+            *self
+        }
+    }
+
+    /// Return the 3D Vector shortened to a length of at most `max`
+    ///
+    /// Shorter vectors (including the zero vector) are returned unchanged.
+    pub fn clamp_length_max(&self, max: T) -> Self {
+        self.clamp_length(T::zero(), max)
+    }
+
+    /// Return the 3D Vector lengthened to a length of at least `min`
+    ///
+    /// The zero vector has no direction to grow along, so it is returned unchanged.
+    pub fn clamp_length_min(&self, min: T) -> Self {
+        self.clamp_length(min, T::infinity())
+    }
+
+    /// Create a 3D Vector from spherical coordinates
+    ///
+    /// Uses a Y-up convention, with angles in radians:
+    /// - `theta` is the polar angle from the +Y axis, in `[0, pi]`
+    /// - `phi` is the azimuth around +Y, measured from +X towards +Z
+    ///
+    /// `(r * sin(theta) * cos(phi), r * cos(theta), r * sin(theta) * sin(phi))`
+    pub fn from_spherical(radius: T, theta: T, phi: T) -> Self {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        Self {
+            x: radius * sin_theta * cos_phi,
+            y: radius * cos_theta,
+            z: radius * sin_theta * sin_phi,
+        }
+    }
+
+    /// Return the spherical coordinates `(radius, theta, phi)` of the 3D Vector
+    ///
+    /// See `from_spherical` for the convention. `phi` is within `[-pi, pi]`. On (or
+    /// within rounding error of) the Y axis the azimuth is undefined and `phi` is 0,
+    /// and the zero vector gives all zeros.
+    pub fn to_spherical(&self) -> (T, T, T) {
+        let radius = self.length();
+        if radius == T::zero() {
+            return (T::zero(), T::zero(), T::zero());
+        }
+        let theta = (self.y / radius).max(-T::one()).min(T::one()).acos();
+        // treat rounding noise next to the poles as being on the axis
+        if self.x.hypot(self.z) <= T::epsilon() * radius {
+            return (radius, theta, T::zero());
+        }
+        (radius, theta, self.z.atan2(self.x))
+    }
+
+    /// Create a 3D Vector from cylindrical coordinates
+    ///
+    /// Uses the same Y-up convention as `from_spherical`: `radius` is the distance
+    /// from the Y axis, `angle` is the azimuth in radians from +X towards +Z, and
+    /// `y` is the height along the axis.
+    ///
+    /// `(r * cos(angle), y, r * sin(angle))`
+    pub fn from_cylindrical(radius: T, angle: T, y: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            x: radius * cos,
+            y,
+            z: radius * sin,
+        }
+    }
+
+    /// Return the cylindrical coordinates `(radius, angle, y)` of the 3D Vector
+    ///
+    /// See `from_cylindrical` for the convention. `angle` is within `[-pi, pi]`,
+    /// and is 0 on the Y axis where it is undefined.
+    pub fn to_cylindrical(&self) -> (T, T, T) {
+        let radius = self.x.hypot(self.z);
+        (radius, self.z.atan2(self.x), self.y)
+    }
+
+    /// Return two unit vectors that form an orthonormal basis with this (unit) 3D Vector
+    ///
+    /// Uses the branchless construction from Duff et al., "Building an Orthonormal
+    /// Basis, Revisited" (2017), which stays accurate near both poles. The result
+    /// `(t, b)` is right-handed: `t.cross(&b) == self`.
+    pub fn any_orthonormal_pair(&self) -> (Vec3<T>, Vec3<T>) {
+        let sign = T::one().copysign(self.z);
+        let a = -T::one() / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Vec3::new(
+                T::one() + sign * self.x * self.x * a,
+                sign * b,
+                -sign * self.x,
+            ),
+            Vec3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    /// Return the shortest rotation taking this direction onto `other`
+    ///
+    /// Shorthand for `Quat::from_rotation_arc(self, other)`, e.g. for aligning an
+    /// object's up axis to a surface normal.
+    pub fn rotation_to(&self, other: &Vec3<T>) -> Quat<T> {
+        Quat::from_rotation_arc(*self, *other)
+    }
+}
+
+/// Overflow aware arithmetic for integer 3D Vectors.
+///
+/// Each method applies the matching scalar operation from `num` to every component.
+impl<T> Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    /// Add two 3D Vectors, returning `None` if any component overflows
+    pub fn checked_add(&self, rhs: &Vec3<T>) -> Option<Vec3<T>>
+    where
+        T: CheckedAdd,
+    {
+        Some(Vec3 {
+            x: self.x.checked_add(&rhs.x)?,
+            y: self.y.checked_add(&rhs.y)?,
+            z: self.z.checked_add(&rhs.z)?,
+        })
+    }
+
+    /// Subtract two 3D Vectors, returning `None` if any component overflows
+    pub fn checked_sub(&self, rhs: &Vec3<T>) -> Option<Vec3<T>>
+    where
+        T: CheckedSub,
+    {
+        Some(Vec3 {
+            x: self.x.checked_sub(&rhs.x)?,
+            y: self.y.checked_sub(&rhs.y)?,
+            z: self.z.checked_sub(&rhs.z)?,
+        })
+    }
+
+    /// Multiply a 3D Vector by a scalar, returning `None` if any component overflows
+    pub fn checked_mul(&self, rhs: T) -> Option<Vec3<T>>
+    where
+        T: CheckedMul,
+    {
+        Some(Vec3 {
+            x: self.x.checked_mul(&rhs)?,
+            y: self.y.checked_mul(&rhs)?,
+            z: self.z.checked_mul(&rhs)?,
+        })
+    }
+
+    /// Divide a 3D Vector by a scalar, returning `None` if `rhs` is zero or a
+    /// component overflows
+    pub fn checked_div(&self, rhs: T) -> Option<Vec3<T>>
+    where
+        T: CheckedDiv,
+    {
+        Some(Vec3 {
+            x: self.x.checked_div(&rhs)?,
+            y: self.y.checked_div(&rhs)?,
+            z: self.z.checked_div(&rhs)?,
+        })
+    }
+
+    /// Add two 3D Vectors, wrapping around at the bounds of the type
+    pub fn wrapping_add(&self, rhs: &Vec3<T>) -> Vec3<T>
+    where
+        T: WrappingAdd,
+    {
+        Vec3 {
+            x: self.x.wrapping_add(&rhs.x),
+            y: self.y.wrapping_add(&rhs.y),
+            z: self.z.wrapping_add(&rhs.z),
+        }
+    }
+
+    /// Subtract two 3D Vectors, wrapping around at the bounds of the type
+    pub fn wrapping_sub(&self, rhs: &Vec3<T>) -> Vec3<T>
+    where
+        T: WrappingSub,
+    {
+        Vec3 {
+            x: self.x.wrapping_sub(&rhs.x),
+            y: self.y.wrapping_sub(&rhs.y),
+            z: self.z.wrapping_sub(&rhs.z),
+        }
+    }
+
+    /// Multiply a 3D Vector by a scalar, wrapping around at the bounds of the type
+    pub fn wrapping_mul(&self, rhs: T) -> Vec3<T>
+    where
+        T: WrappingMul,
+    {
+        Vec3 {
+            x: self.x.wrapping_mul(&rhs),
+            y: self.y.wrapping_mul(&rhs),
+            z: self.z.wrapping_mul(&rhs),
+        }
+    }
+
+    /// Add two 3D Vectors, clamping each component to the bounds of the type
+    pub fn saturating_add(&self, rhs: &Vec3<T>) -> Vec3<T>
+    where
+        T: SaturatingAdd,
+    {
+        Vec3 {
+            x: self.x.saturating_add(&rhs.x),
+            y: self.y.saturating_add(&rhs.y),
+            z: self.z.saturating_add(&rhs.z),
+        }
+    }
+
+    /// Subtract two 3D Vectors, clamping each component to the bounds of the type
+    pub fn saturating_sub(&self, rhs: &Vec3<T>) -> Vec3<T>
+    where
+        T: SaturatingSub,
+    {
+        Vec3 {
+            x: self.x.saturating_sub(&rhs.x),
+            y: self.y.saturating_sub(&rhs.y),
+            z: self.z.saturating_sub(&rhs.z),
+        }
+    }
+
+    /// Multiply a 3D Vector by a scalar, clamping each component to the bounds
+    /// of the type
+    pub fn saturating_mul(&self, rhs: T) -> Vec3<T>
+    where
+        T: SaturatingMul,
+    {
+        Vec3 {
+            x: self.x.saturating_mul(&rhs),
+            y: self.y.saturating_mul(&rhs),
+            z: self.z.saturating_mul(&rhs),
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(2, 4, 8);
+/// let b = Vec3::new(16, 32, 64);
+/// let c = a + b;
+///
+/// assert_eq!(c, Vec3::new(a.x + b.x, a.y + b.y, a.z + b.z));
+/// ```
+impl<T> Add for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn add(self, other: Vec3<T>) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(4, 7, 8);
+/// let b = 2;
+/// let c = a + b;
+///
+/// assert_eq!(c, Vec3::new(4 + 2, 7 + 2, 8 + 2));
+/// ```
+impl<T> Add<T> for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn add(self, other: T) -> Self::Output {
+        Self {
+            x: self.x + other,
+            y: self.y + other,
+            z: self.z + other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(2, 4, 8);
+/// let b = Vec3::new(16, 32, 64);
+/// let c = a - b;
+///
+/// assert_eq!(c, Vec3::new(a.x - b.x, a.y - b.y, a.z - b.z));
+/// ```
+impl<T> Sub for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn sub(self, other: Vec3<T>) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(4, 7, 8);
+/// let b = 2;
+/// let c = a - b;
+///
+/// assert_eq!(c, Vec3::new(4 - 2, 7 - 2, 8 - 2));
+/// ```
+impl<T> Sub<T> for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn sub(self, other: T) -> Self::Output {
+        Self {
+            x: self.x - other,
+            y: self.y - other,
+            z: self.z - other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(2, 4, 8);
+/// let b = Vec3::new(16, 32, 64);
+/// let c = a * b;
+///
+/// assert_eq!(c, a.cross(&b));
+/// ```
+impl<T> Mul for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn mul(self, other: Vec3<T>) -> Self::Output {
+        self.cross(&other)
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(4, 7, 8);
+/// let b = 2;
+/// let c = a * b;
+///
+/// assert_eq!(c, Vec3::new(4 * 2, 7 * 2, 8 * 2));
+/// ```
+impl<T> Mul<T> for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn mul(self, other: T) -> Self::Output {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(4, 6, 12);
+/// let b = 2;
+/// let c = a / b;
+///
+/// assert_eq!(c, Vec3::new(4 / 2, 6 / 2, 12 / 2));
+/// ```
+impl<T> Div<T> for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn div(self, other: T) -> Self::Output {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(10, 7, -9);
+/// let b = Vec3::new(4, 3, 4);
+/// let c = a % b;
+///
+/// assert_eq!(c, Vec3::new(10 % 4, 7 % 3, -9 % 4));
+/// ```
+impl<T> Rem for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn rem(self, other: Vec3<T>) -> Self::Output {
+        Self {
+            x: self.x % other.x,
+            y: self.y % other.y,
+            z: self.z % other.z,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(10, 7, -9);
+/// let b = 4;
+/// let c = a % b;
+///
+/// assert_eq!(c, Vec3::new(10 % 4, 7 % 4, -9 % 4));
+/// ```
+impl<T> Rem<T> for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn rem(self, other: T) -> Self::Output {
+        Self {
+            x: self.x % other,
+            y: self.y % other,
+            z: self.z % other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::new(1, 2, 3);
+/// let b: Vec<i32> = a.into_iter().collect();
+///
+/// assert_eq!(b, vec![1, 2, 3]);
+/// ```
+impl<T> IntoIterator for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, 3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y, self.z].into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Item = &'a T;
+    type IntoIter = std::array::IntoIter<&'a T, 3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Item = &'a mut T;
+    type IntoIter = std::array::IntoIter<&'a mut T, 3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a: Vec3<i32> = (1..4).collect();
+///
+/// assert_eq!(a, Vec3::new(1, 2, 3));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the iterator does not yield exactly 3 items, use `TryFrom<&[T]>`
+/// for a fallible conversion.
+impl<T> FromIterator<T> for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        match collect_components(iter) {
+            Ok([x, y, z]) => Self { x, y, z },
+            Err(e) => panic!("cannot build a Vec3 from an iterator: {}", e),
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::try_from(&[1, 2, 3][..]);
+///
+/// assert_eq!(a, Ok(Vec3::new(1, 2, 3)));
+/// ```
+impl<T> TryFrom<&[T]> for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Error = ComponentCountError;
+
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        let [x, y, z] = collect_components(slice.iter().copied())?;
+        Ok(Self { x, y, z })
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = vec![Vec3::new(1, 2, 3), Vec3::new(1, 2, 3)];
+/// let b: Vec3<i32> = a.into_iter().sum();
+///
+/// assert_eq!(b, Vec3::new(2, 4, 6));
+/// ```
+impl<T> Sum for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vec3::new(T::zero(), T::zero(), T::zero()), |acc, v| acc + v)
+    }
+}
+
+impl<'a, T> Sum<&'a Vec3<T>> for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Vec3::new(T::zero(), T::zero(), T::zero()), |acc, v| {
+            acc + *v
+        })
+    }
+}
+
+/// Allows vectors in generic code bounded on `num::Zero`:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// use num::Zero;
+///
+/// let zero: Vec3<i32> = Zero::zero();
+/// assert!(zero.is_zero());
+/// assert_eq!(Vec3::new(1, 2, 3) + zero, Vec3::new(1, 2, 3));
+/// ```
+impl<T> Zero for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn zero() -> Self {
+        Vec3::new(T::zero(), T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero() && self.z.is_zero()
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a = Vec3::from([1, 2, 3]);
+/// let b: [i32; 3] = a.into();
+///
+/// assert_eq!(a, Vec3::new(1, 2, 3));
+/// assert_eq!(b, [1, 2, 3]);
+/// ```
+impl<T> From<[T; 3]> for Vec3<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Vec3<T>> for [T; 3]
+where
+    T: Num + Clone + Copy,
+{
+    fn from(v: Vec3<T>) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+/// Formats the vector as `(x, y, z)`, which parses back via `FromStr`
+impl<T> Display for Vec3<T>
+where
+    T: Display + Num + Clone + Copy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec3;
+/// let a: Vec3<i32> = "(1, 2, 3)".parse().unwrap();
+/// let b: Vec3<i32> = "[1 2 3]".parse().unwrap();
+///
+/// assert_eq!(a, Vec3::new(1, 2, 3));
+/// assert_eq!(a, b);
+/// ```
+impl<T> FromStr for Vec3<T>
+where
+    T: FromStr + Num + Clone + Copy,
+{
+    type Err = ParseVecError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_components(s)?;
+        Ok(Self { x, y, z })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::ComponentCountError;
+    use crate::vector::ParseVecError;
+    use crate::vector::Vec3;
+
+    #[test]
+    fn zero_vec3_test() {
+        assert_eq!(Vec3::zero(), Vec3::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn quantize_test() {
+        let v = Vec3::new(2.5, -0.5, 4.0);
+
+        assert_eq!(v.quantize(1.0), Vec3::new(2, -1, 4));
+        assert_eq!(v.quantize(2.0), Vec3::new(1, -1, 2));
+    }
+
+    #[test]
+    fn dot_product_test() {
+        let lhs = Vec3::new(2, 4, 8);
+        let rhs = Vec3::new(16, 32, 64);
+
+        assert_eq!(lhs.dot(&rhs), 672);
+    }
+
+    #[test]
+    fn cross_product_test() {
+        let lhs = Vec3::new(5, 10, 15);
+        let rhs = Vec3::new(3, 1, 7);
+
+        assert_eq!(lhs.cross(&rhs), Vec3::new(55, 10, -25));
+    }
+
+    #[test]
+    fn cross_product_mul_trait_test() {
+        let lhs = Vec3::new(5, 10, 15);
+        let rhs = Vec3::new(3, 1, 7);
+
+        assert_eq!(lhs * rhs, Vec3::new(55, 10, -25));
+    }
+
+    #[test]
+    fn add_test() {
+        let lhs = Vec3::new(2, 4, 8);
+        let rhs = Vec3::new(16, 32, 64);
+
+        assert_eq!(lhs + rhs, Vec3::new(18, 36, 72));
+    }
+
+    #[test]
+    fn sub_test() {
+        let lhs = Vec3::new(2, 4, 8);
+        let rhs = Vec3::new(16, 32, 64);
+
+        assert_eq!(lhs - rhs, Vec3::new(-14, -28, -56));
+    }
+
+    #[test]
+    fn add_scalar_test() {
+        let lhs = Vec3::new(32, 64, 128);
+        let rhs = 10;
+
+        assert_eq!(lhs + rhs, Vec3::new(42, 74, 138));
+    }
+
+    #[test]
+    fn sub_scalar_test() {
+        let lhs = Vec3::new(32, 64, 128);
+        let rhs = 10;
+
+        assert_eq!(lhs - rhs, Vec3::new(22, 54, 118));
+    }
+
+    #[test]
+    fn mul_scalar_test() {
+        let lhs = Vec3::new(32, 64, 128);
+        let rhs = 10;
+
+        assert_eq!(lhs * rhs, Vec3::new(320, 640, 1280));
+    }
+
+    #[test]
+    fn div_scalar_test() {
+        let lhs = Vec3::new(32, 64, 128);
+        let rhs = 4;
+
+        assert_eq!(lhs / rhs, Vec3::new(8, 16, 32));
+    }
+
+    #[test]
+    fn parse_test() {
+        let expected = Vec3::new(1, 2, 3);
+
+        assert_eq!("1,2,3".parse(), Ok(expected));
+        assert_eq!("(1, 2, 3)".parse(), Ok(expected));
+        assert_eq!("[1 2 3]".parse(), Ok(expected));
+        assert_eq!("  ( 1 , 2 , 3 )  ".parse(), Ok(expected));
+        assert_eq!("\t1,  2,  3\n".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn parse_negative_and_scientific_test() {
+        let parsed: Vec3<f32> = "(-1.5, 2e3, -4.5E-2)".parse().unwrap();
+
+        assert_eq!(parsed, Vec3::new(-1.5, 2000.0, -0.045));
+        assert_eq!("-1, -1, -1".parse(), Ok(Vec3::new(-1, -1, -1)));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        assert_eq!(
+            "1, 1".parse::<Vec3<i32>>(),
+            Err(ParseVecError::WrongComponentCount {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            "1, 1, 1, 1".parse::<Vec3<i32>>(),
+            Err(ParseVecError::WrongComponentCount {
+                expected: 3,
+                found: 4
+            })
+        );
+        assert_eq!(
+            "".parse::<Vec3<i32>>(),
+            Err(ParseVecError::WrongComponentCount {
+                expected: 3,
+                found: 0
+            })
+        );
+        assert!(matches!(
+            "(1, 1, a)".parse::<Vec3<i32>>(),
+            Err(ParseVecError::InvalidComponent(_))
+        ));
+        assert!(matches!(
+            "1, 1,".parse::<Vec3<i32>>(),
+            Err(ParseVecError::InvalidComponent(_))
+        ));
+    }
+
+    #[test]
+    fn display_round_trip_test() {
+        let v = Vec3::new(0.1, -2.5, 1e-7);
+
+        assert_eq!(v.to_string(), "(0.1, -2.5, 0.0000001)");
+        assert_eq!(v.to_string().parse(), Ok(v));
+    }
+
+    #[test]
+    fn length_test() {
+        assert_eq!(Vec3::new(3, 4, 0).length_squared(), 25);
+        assert_eq!(Vec3::new(3.0, 4.0, 0.0).length(), 5.0);
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(
+            Vec3::new(0.0, 2.0, 0.0).normalize(),
+            Vec3::new(0.0, 1.0, 0.0)
+        );
+        assert!((Vec3::new(3.0f32, 4.0, 0.0).normalize().length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut v = Vec3::new(1, 2, 3);
+
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!((&v).into_iter().count(), 3);
+        for c in v.iter_mut() {
+            *c *= 2;
+        }
+        assert_eq!(v, Vec3::new(2, 4, 6));
+        assert_eq!(v.into_iter().max(), Some(6));
+    }
+
+    #[test]
+    fn from_iter_test() {
+        let v: Vec3<i32> = vec![1, 2, 3].into_iter().collect();
+
+        assert_eq!(v, Vec3::new(1, 2, 3));
+        assert_eq!(
+            v.into_iter().map(|c| c * 2).collect::<Vec3<i32>>(),
+            Vec3::new(2, 4, 6)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_iter_wrong_count_test() {
+        let _: Vec3<i32> = (0..4).collect();
+    }
+
+    #[test]
+    fn try_from_slice_test() {
+        assert_eq!(Vec3::try_from(&[1, 2, 3][..]), Ok(Vec3::new(1, 2, 3)));
+        assert_eq!(
+            Vec3::<i32>::try_from(&[1, 2, 3, 4, 5][..]),
+            Err(ComponentCountError {
+                expected: 3,
+                found: 5
+            })
+        );
+        assert_eq!(
+            Vec3::<i32>::try_from(&[][..]),
+            Err(ComponentCountError {
+                expected: 3,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn sum_centroid_test() {
+        let points = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(4.0, 0.0, 3.0),
+            Vec3::new(2.0, 6.0, 0.0),
+        ];
+        let centroid = points.iter().copied().sum::<Vec3<f32>>() / points.len() as f32;
+
+        assert_eq!(centroid, Vec3::new(2.0, 2.0, 1.0));
+        assert_eq!(points.iter().sum::<Vec3<f32>>(), centroid * 3.0);
+        assert_eq!(
+            Vec::<Vec3<f32>>::new().into_iter().sum::<Vec3<f32>>(),
+            Vec3::zero()
+        );
+    }
+
+    #[test]
+    fn lerp_test() {
+        let a = Vec3::new(0.0, 10.0, -4.0);
+        let b = Vec3::new(10.0, 20.0, 4.0);
+
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 0.5), Vec3::new(5.0, 15.0, 0.0));
+    }
+
+    #[test]
+    fn map_test() {
+        let v = Vec3::new(1, -2, 3);
+
+        assert_eq!(v.map(|c| c as f32), Vec3::new(1.0, -2.0, 3.0));
+        assert_eq!(
+            Vec3::new(4.0, 9.0, 0.25).map(|c: f32| c.powf(2.0)),
+            Vec3::new(16.0, 81.0, 0.0625)
+        );
+        assert_eq!(v.map_with_index(|i, _| i as i32 * 10), Vec3::new(0, 10, 20));
+    }
+
+    #[test]
+    fn zip_with_test() {
+        let a = Vec3::new(1, -2, 3);
+        let b = Vec3::new(4, -5, 1);
+
+        assert_eq!(a.zip_with(b, std::cmp::max), Vec3::new(4, -2, 3));
+    }
+
+    #[test]
+    fn lexicographic_order_test() {
+        assert!(Vec3::new(1, 2, 3) < Vec3::new(1, 2, 4));
+        assert!(Vec3::new(1, 3, 0) > Vec3::new(1, 2, 9));
+        assert_eq!(
+            std::cmp::max(Vec3::new(1, 2, 3), Vec3::new(0, 9, 9)),
+            Vec3::new(1, 2, 3)
+        );
+    }
+
+    fn unit_cube() -> [Vec3<f32>; 8] {
+        // vertex i has x, y, z taken from bits 0, 1, 2 of i
+        std::array::from_fn(|i| {
+            Vec3::new((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32)
+        })
+    }
+
+    #[test]
+    fn tetrahedron_volume_test() {
+        let o = Vec3::new(0.0, 0.0, 0.0);
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+        let z = Vec3::new(0.0, 0.0, 1.0);
+
+        assert!((Vec3::signed_volume(&o, &x, &y, &z) - 1.0 / 6.0).abs() < 1e-7);
+        assert!((Vec3::signed_volume(&o, &y, &x, &z) + 1.0 / 6.0).abs() < 1e-7);
+        assert!((Vec3::tetrahedron_volume(&o, &y, &x, &z) - 1.0 / 6.0).abs() < 1e-7);
+    }
+
+    #[test]
+    fn cube_tetrahedra_volume_test() {
+        let v = unit_cube();
+        // a corner tetrahedron at each of 4 alternating corners plus the central one
+        let tets = [
+            [0, 1, 2, 4],
+            [3, 1, 2, 7],
+            [5, 1, 4, 7],
+            [6, 2, 4, 7],
+            [1, 2, 4, 7],
+        ];
+        let total: f32 = tets
+            .iter()
+            .map(|[a, b, c, d]| Vec3::tetrahedron_volume(&v[*a], &v[*b], &v[*c], &v[*d]))
+            .sum();
+
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn mesh_volume_test() {
+        let v = unit_cube().map(|p| p + Vec3::new(2.0, -1.0, 5.0));
+        // two outward facing triangles per face: -z, +z, -y, +y, -x, +x
+        let triangles = [
+            [0, 2, 3],
+            [0, 3, 1],
+            [4, 5, 7],
+            [4, 7, 6],
+            [0, 1, 5],
+            [0, 5, 4],
+            [2, 6, 7],
+            [2, 7, 3],
+            [0, 4, 6],
+            [0, 6, 2],
+            [1, 3, 7],
+            [1, 7, 5],
+        ];
+
+        assert!((Vec3::mesh_volume(&v, &triangles) - 1.0).abs() < 1e-5);
+
+        let flipped: Vec<[usize; 3]> = triangles.iter().map(|[a, b, c]| [*a, *c, *b]).collect();
+        assert!((Vec3::mesh_volume(&v, &flipped) + 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn checked_arithmetic_test() {
+        let a: Vec3<u8> = Vec3::new(250, 1, 2);
+
+        assert_eq!(
+            a.checked_add(&Vec3::new(5, 5, 5)),
+            Some(Vec3::new(255, 6, 7))
+        );
+        assert_eq!(a.checked_add(&Vec3::new(6, 0, 0)), None);
+        assert_eq!(
+            a.checked_sub(&Vec3::new(0, 1, 2)),
+            Some(Vec3::new(250, 0, 0))
+        );
+        assert_eq!(a.checked_sub(&Vec3::new(0, 2, 0)), None);
+        assert_eq!(
+            Vec3::new(1, 2, 3).checked_mul(2u8),
+            Some(Vec3::new(2, 4, 6))
+        );
+        assert_eq!(a.checked_mul(2), None);
+        assert_eq!(a.checked_div(2), Some(Vec3::new(125, 0, 1)));
+        assert_eq!(a.checked_div(0), None);
+        assert_eq!(Vec3::new(i8::MIN, 0, 0).checked_div(-1), None);
+    }
+
+    #[test]
+    fn wrapping_arithmetic_test() {
+        let a: Vec3<u8> = Vec3::new(250, 1, 0);
+
+        assert_eq!(
+            a.wrapping_add(&Vec3::new(10, u8::MAX, 0)),
+            Vec3::new(250u8.wrapping_add(10), 1u8.wrapping_add(u8::MAX), 0)
+        );
+        assert_eq!(
+            a.wrapping_sub(&Vec3::new(0, 2, 1)),
+            Vec3::new(250, u8::MAX, u8::MAX)
+        );
+        assert_eq!(a.wrapping_mul(2), Vec3::new(250u8.wrapping_mul(2), 2, 0));
+    }
+
+    #[test]
+    fn saturating_arithmetic_test() {
+        let a: Vec3<u8> = Vec3::new(250, 1, 0);
+
+        assert_eq!(
+            a.saturating_add(&Vec3::new(10, 1, 0)),
+            Vec3::new(u8::MAX, 2, 0)
+        );
+        assert_eq!(a.saturating_sub(&Vec3::new(0, 2, 1)), Vec3::new(250, 0, 0));
+        assert_eq!(a.saturating_mul(2), Vec3::new(u8::MAX, 2, 0));
+        assert_eq!(
+            Vec3::new(-100i8, 100, 0).saturating_mul(2),
+            Vec3::new(i8::MIN, i8::MAX, 0)
+        );
+    }
+
+    #[test]
+    fn array_conversion_test() {
+        let v = Vec3::from([1, 2, 3]);
+
+        assert_eq!(v, Vec3::new(1, 2, 3));
+        assert_eq!(<[i32; 3]>::from(v), [1, 2, 3]);
+    }
+
+    #[test]
+    fn std140_round_trip_test() {
+        let v = Vec3::new(1.5, -2.0, 3.25);
+        let packed = v.to_std140();
+
+        assert_eq!(packed, [1.5, -2.0, 3.25, 0.0]);
+        assert_eq!(Vec3::from_std140(packed), v);
+        assert_eq!(Vec3::from_std140([1.5, -2.0, 3.25, 9.0]), v);
+    }
+
+    #[test]
+    fn rem_test() {
+        let lhs = Vec3::new(10, 7, 8);
+        let rhs = Vec3::new(4, 3, 5);
+
+        assert_eq!(lhs % rhs, Vec3::new(2, 1, 3));
+    }
+
+    #[test]
+    fn rem_scalar_test() {
+        let lhs = Vec3::new(10, 7, 8);
+
+        assert_eq!(lhs % 4, Vec3::new(2, 3, 0));
+        assert_eq!(Vec3::new(5.5, -1.5, 2.0) % 2.0, Vec3::new(1.5, -1.5, 0.0));
+    }
+
+    #[test]
+    fn by_value_products_test() {
+        let lhs = Vec3::new(1, 2, 3);
+        let rhs = Vec3::new(4, 5, 6);
+
+        assert_eq!(lhs.dot_v(rhs), 32);
+        assert_eq!(lhs.cross_v(rhs), lhs.cross(&rhs));
+    }
+
+    #[test]
+    fn min_max_test() {
+        let a = Vec3::new(1, 5, -3);
+        let b = Vec3::new(2, 4, -3);
+
+        assert_eq!(a.min(b), Vec3::new(1, 4, -3));
+        assert_eq!(a.max(b), Vec3::new(2, 5, -3));
+        assert_eq!(
+            Vec3::new(0.5, -1.0, 2.0).max(Vec3::new(1.0, -2.0, 2.0)),
+            Vec3::new(1.0, -1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn support_test() {
+        let points = unit_cube();
+
+        assert_eq!(
+            Vec3::support(&points, &Vec3::new(1.0, 1.0, 1.0)),
+            Vec3::new(1.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            Vec3::support(&points, &Vec3::new(-1.0, 0.1, -1.0)),
+            Vec3::new(0.0, 1.0, 0.0)
+        );
+        // ties keep the first point
+        assert_eq!(
+            Vec3::support(&points, &Vec3::new(0.0, 0.0, -1.0)),
+            points[0]
+        );
+    }
+
+    #[test]
+    fn to_spherical_axes_test() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        assert_eq!(Vec3::new(0.0, 2.0, 0.0).to_spherical(), (2.0, 0.0, 0.0));
+        assert_eq!(Vec3::new(0.0, -1.0, 0.0).to_spherical(), (1.0, PI, 0.0));
+        assert_eq!(
+            Vec3::new(1.0, 0.0, 0.0).to_spherical(),
+            (1.0, FRAC_PI_2, 0.0)
+        );
+        assert_eq!(
+            Vec3::new(0.0, 0.0, 1.0).to_spherical(),
+            (1.0, FRAC_PI_2, FRAC_PI_2)
+        );
+        assert_eq!(
+            Vec3::new(-1.0, 0.0, 0.0).to_spherical(),
+            (1.0, FRAC_PI_2, PI)
+        );
+        assert_eq!(
+            Vec3::new(0.0, 0.0, -1.0).to_spherical(),
+            (1.0, FRAC_PI_2, -FRAC_PI_2)
+        );
+        assert_eq!(Vec3::new(0.0, 0.0, 0.0).to_spherical(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn spherical_round_trip_test() {
+        for v in [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, -3.0, 0.0),
+            Vec3::new(1.0, 2.0, -3.0),
+            Vec3::new(-0.5, -0.25, 4.0),
+        ] {
+            let (r, theta, phi) = v.to_spherical();
+            assert!((Vec3::from_spherical(r, theta, phi) - v).length() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn midpoint_centroid_test() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(3.0, -6.0, 5.0);
+        assert_eq!(a.midpoint(&b), Vec3::new(2.0, -2.0, 4.0));
+
+        let triangle = [
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(3.0, 0.0, 1.0),
+            Vec3::new(0.0, 6.0, 1.0),
+        ];
+        assert_eq!(Vec3::centroid(&triangle), Some(Vec3::new(1.0, 2.0, 1.0)));
+        assert_eq!(Vec3::<f64>::centroid(&[]), None);
+    }
+
+    #[test]
+    fn smoothstep_test() {
+        let edge0 = Vec3::new(0.0, -1.0, 2.0);
+        let edge1 = Vec3::new(1.0, 3.0, 4.0);
+        let half = Vec3::new(0.5, 0.5, 0.5);
+
+        for step in [Vec3::smoothstep, Vec3::smootherstep] {
+            assert_eq!(step(&edge0, &edge1, &edge0), Vec3::new(0.0, 0.0, 0.0));
+            assert_eq!(step(&edge0, &edge1, &edge1), Vec3::new(1.0, 1.0, 1.0));
+            assert_eq!(step(&edge0, &edge1, &Vec3::new(0.5, 1.0, 3.0)), half);
+            // clamped outside the edges
+            assert_eq!(
+                step(&edge0, &edge1, &(edge0 - 1.0)),
+                Vec3::new(0.0, 0.0, 0.0)
+            );
+            assert_eq!(
+                step(&edge0, &edge1, &(edge1 + 1.0)),
+                Vec3::new(1.0, 1.0, 1.0)
+            );
+        }
+        assert_eq!(
+            Vec3::smoothstep(&edge0, &edge1, &edge0.lerp(&edge1, 0.25)),
+            Vec3::new(0.15625, 0.15625, 0.15625)
+        );
+    }
+
+    #[test]
+    fn spherical_angle_grid_test() {
+        use std::f64::consts::PI;
+
+        for i in 0..=8 {
+            let theta = PI * i as f64 / 8.0;
+            for j in -8..8 {
+                let phi = PI * j as f64 / 8.0;
+                let v = Vec3::from_spherical(2.0, theta, phi);
+                let (r, theta_out, phi_out) = v.to_spherical();
+
+                assert!((r - 2.0).abs() < 1e-12);
+                assert!((theta_out - theta).abs() < 1e-12);
+                assert!((Vec3::from_spherical(r, theta_out, phi_out) - v).length() < 1e-12);
+                if i == 0 || i == 8 {
+                    // the azimuth is degenerate at the poles
+                    assert_eq!(phi_out, 0.0);
+                } else {
+                    assert!((phi_out - phi).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cylindrical_test() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        assert_eq!(Vec3::new(2.0, 5.0, 0.0).to_cylindrical(), (2.0, 0.0, 5.0));
+        assert_eq!(
+            Vec3::new(0.0, -1.0, 3.0).to_cylindrical(),
+            (3.0, FRAC_PI_2, -1.0)
+        );
+        assert_eq!(Vec3::new(0.0, 4.0, 0.0).to_cylindrical(), (0.0, 0.0, 4.0));
+
+        for j in -8..8 {
+            let angle = PI * j as f64 / 8.0;
+            for y in [-2.0, 0.0, 3.5] {
+                let v = Vec3::from_cylindrical(1.5, angle, y);
+                let (r, angle_out, y_out) = v.to_cylindrical();
+
+                assert!((r - 1.5).abs() < 1e-12);
+                assert!((angle_out - angle).abs() < 1e-12);
+                assert_eq!(y_out, y);
+            }
+        }
+    }
+
+    #[test]
+    fn remap_test() {
+        let (zero, one) = (Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+        let minus_one = Vec3::new(-1.0, -1.0, -1.0);
+
+        assert_eq!(
+            Vec3::new(0.5, 0.5, 0.5).remap(&zero, &one, &minus_one, &one),
+            zero
+        );
+        assert_eq!(zero.remap(&zero, &one, &minus_one, &one), minus_one);
+        assert_eq!(
+            Vec3::new(2.0, 2.0, 2.0).remap(&zero, &one, &minus_one, &one),
+            Vec3::new(3.0, 3.0, 3.0)
+        );
+        // screen to normalized device coordinates, flipping y
+        assert_eq!(
+            Vec3::new(200.0, 150.0, 0.0).remap(
+                &zero,
+                &Vec3::new(800.0, 600.0, 1.0),
+                &Vec3::new(-1.0, 1.0, -1.0),
+                &Vec3::new(1.0, -1.0, 1.0)
+            ),
+            Vec3::new(-0.5, 0.5, -1.0)
+        );
+    }
+
+    #[test]
+    fn element_reductions_test() {
+        let v = Vec3::new(4, -2, 7);
+
+        assert_eq!(v.element_sum(), 9);
+        assert_eq!(v.element_product(), -56);
+        assert_eq!(v.min_element(), -2);
+        assert_eq!(v.max_element(), 7);
+        // volume of a box from its size
+        assert_eq!(Vec3::new(2, 3, 4).element_product(), 24);
+    }
+
+    #[test]
+    fn element_index_test() {
+        let v = Vec3::new(4, -2, 7);
+        assert_eq!(v.min_element_index(), 1);
+        assert_eq!(v.max_element_index(), 2);
+
+        // ties go to the lowest index
+        assert_eq!(Vec3::new(1, 0, 0).min_element_index(), 1);
+        assert_eq!(Vec3::new(0, 1, 1).max_element_index(), 1);
+        assert_eq!(Vec3::new(-5.0, 3.0, -5.0).min_element_index(), 0);
+
+        // longest axis of a box, for choosing where to split it
+        let size = Vec3::new(1.0, 8.0, 3.0f32);
+        assert_eq!(size.max_element_index(), 1);
+    }
+
+    #[test]
+    fn signum_copysign_test() {
+        assert_eq!(Vec3::new(5, -3, 0).signum(), Vec3::new(1, -1, 0));
+        assert_eq!(
+            Vec3::new(0.25, -4.0, 0.0).signum(),
+            Vec3::new(1.0, -1.0, 1.0)
+        );
+        assert_eq!(Vec3::new(-0.0f32, 0.0, 1.0).signum().x, -1.0);
+
+        let magnitude = Vec3::new(1.0, -2.0, 3.0);
+        let sign = Vec3::new(-5.0, 5.0, -0.0);
+        assert_eq!(magnitude.copysign(&sign), Vec3::new(-1.0, 2.0, -3.0));
+    }
+
+    #[test]
+    fn distance_metrics_test() {
+        let a = Vec3::new(0, 0, 0);
+        let b = Vec3::new(3, -4, 2);
+
+        assert_eq!(a.manhattan_distance(&b), 9);
+        assert_eq!(b.manhattan_distance(&a), 9);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(b.manhattan_distance(&b), 0);
+
+        let v = Vec3::new(1.0f64, -2.0, 2.0);
+        assert!((v.lp_norm(2.0) - v.length()).abs() < 1e-12);
+        assert!((v.lp_norm(3.0) - 17.0f64.cbrt()).abs() < 1e-12);
+        assert_eq!(v.lp_norm(f64::INFINITY), 2.0);
+        assert_eq!(v.lp_distance(&v, 2.0), 0.0);
+    }
+
+    #[test]
+    fn spherical_gaussian_test() {
+        let axis = Vec3::new(0.0, 1.0, 0.0);
+        let amplitude = Vec3::new(1.0, 0.5, 0.25);
+
+        assert_eq!(axis.spherical_gaussian(&axis, 8.0, amplitude), amplitude);
+
+        let mut previous = amplitude;
+        for degrees in [15.0f32, 45.0, 90.0, 180.0] {
+            let dir = Vec3::from_spherical(1.0, degrees.to_radians(), 0.0);
+            let value = dir.spherical_gaussian(&axis, 8.0, amplitude);
+
+            assert!(value.x < previous.x && value.y < previous.y && value.z < previous.z);
+            previous = value;
+        }
+        // opposite the axis is exp(-2 * sharpness)
+        assert!((previous.x - (-16.0f32).exp()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn pow_sqrt_test() {
+        assert_eq!(Vec3::new(4.0, 9.0, 16.0).sqrt(), Vec3::new(2.0, 3.0, 4.0));
+        assert_eq!(
+            Vec3::new(2.0, -3.0, 0.5).powi(3),
+            Vec3::new(8.0, -27.0, 0.125)
+        );
+
+        // gamma correction round trip
+        let linear = Vec3::new(0.25f32, 0.5, 1.0);
+        let encoded = linear.powf(1.0 / 2.2);
+        assert!((encoded.powf(2.2) - linear).length() < 1e-6);
+    }
+
+    #[test]
+    fn move_towards_test() {
+        let start = Vec3::new(1.0, 1.0, 1.0);
+        let target = Vec3::new(1.0, 1.0, 5.0);
+
+        assert_eq!(start.move_towards(&target, 1.5), Vec3::new(1.0, 1.0, 2.5));
+        // overshooting lands exactly on the target
+        assert_eq!(start.move_towards(&target, 4.0), target);
+        assert_eq!(start.move_towards(&target, 4.5), target);
+        assert_eq!(target.move_towards(&target, 0.0), target);
+    }
+
+    #[test]
+    fn clamp_length_test() {
+        let v = Vec3::new(2.0, 3.0, 6.0);
+
+        assert_eq!(v.clamp_length_max(3.5), Vec3::new(1.0, 1.5, 3.0));
+        assert_eq!(v.clamp_length_max(7.0), v);
+        assert_eq!(v.clamp_length(14.0, 20.0), Vec3::new(4.0, 6.0, 12.0));
+        // the zero vector stays zero rather than becoming NaN
+        assert_eq!(Vec3::zero().clamp_length_max(1.0), Vec3::zero());
+        assert_eq!(Vec3::zero().clamp_length(1.0, 2.0), Vec3::zero());
+    }
+
+    #[test]
+    fn recip_test() {
+        assert_eq!(
+            Vec3::new(2.0, -4.0, 0.1).recip(),
+            Vec3::new(0.5, -0.25, 10.0)
+        );
+        assert_eq!(Vec3::new(1.0, 0.0, 1.0).recip().y, f64::INFINITY);
+    }
+
+    #[test]
+    fn scalar_triple_test() {
+        let x = Vec3::new(1, 0, 0);
+        let y = Vec3::new(0, 1, 0);
+        let z = Vec3::new(0, 0, 1);
+
+        assert_eq!(x.scalar_triple(&y, &z), 1);
+        assert_eq!(y.scalar_triple(&x, &z), -1);
+        assert_eq!(
+            Vec3::new(2, 0, 0).scalar_triple(&Vec3::new(1, 3, 0), &Vec3::new(5, 5, 4)),
+            24
+        );
+        // coplanar
+        assert_eq!(x.scalar_triple(&y, &Vec3::new(3, -2, 0)), 0);
+
+        // signed tetrahedron volume, flipping with the winding
+        let (a, b, c) = (
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(0.0, 0.0, 3.0),
+        );
+        assert_eq!(a.scalar_triple(&b, &c) / 6.0, 1.0);
+        assert_eq!(a.scalar_triple(&c, &b) / 6.0, -1.0);
+    }
+
+    #[test]
+    fn triple_product_test() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+        let z = Vec3::new(0.0, 0.0, 1.0);
+
+        assert_eq!(x.triple_product(&y, &z), 1.0);
+        // coplanar
+        assert_eq!(x.triple_product(&y, &Vec3::new(3.0, -2.0, 0.0)), 0.0);
+        assert_eq!(x.triple_product(&z, &y), x.scalar_triple(&z, &y));
+    }
+
+    #[test]
+    fn any_orthonormal_pair_test() {
+        // the poles, and either side of them, are where naive constructions break down
+        let mut directions = vec![
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(1e-7, 0.0, -1.0).normalize(),
+            Vec3::new(0.0, -1e-7, 1.0).normalize(),
+        ];
+        for i in 0..=12 {
+            for j in 0..16 {
+                let theta = std::f64::consts::PI * i as f64 / 12.0;
+                let phi = std::f64::consts::TAU * j as f64 / 16.0;
+                directions.push(Vec3::from_spherical(1.0, theta, phi));
+            }
+        }
+
+        for n in directions {
+            let (t, b) = n.any_orthonormal_pair();
+
+            assert!((t.length() - 1.0).abs() < 1e-12);
+            assert!((b.length() - 1.0).abs() < 1e-12);
+            assert!(t.dot(&b).abs() < 1e-12);
+            assert!(t.dot(&n).abs() < 1e-12);
+            assert!(b.dot(&n).abs() < 1e-12);
+            assert!((t.cross(&b) - n).length() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn mul_add_test() {
+        let a = Vec3::new(2.0, -3.0, 0.5);
+        let b = Vec3::new(4.0, 0.5, 8.0);
+        let c = Vec3::new(1.0, 1.0, -4.0);
+
+        assert_eq!(a.mul_add(&b, &c), Vec3::new(9.0, -0.5, 0.0));
+
+        // one rounding instead of two: (1 + e)(1 - e) - 1 is exactly -e², which
+        // the naive product rounds away
+        let e = f64::EPSILON;
+        let x = Vec3::new(1.0 + e, 1.0, 1.0);
+        let y = Vec3::new(1.0 - e, 1.0, 1.0);
+        let minus_one = Vec3::new(-1.0, -1.0, -1.0);
+        assert_eq!(x.mul_add(&y, &minus_one).x, -e * e);
+        assert_eq!((1.0 + e) * (1.0 - e) - 1.0, 0.0);
+    }
+
+    #[test]
+    fn outer_test() {
+        let a = Vec3::new(1, 2, 3);
+        let b = Vec3::new(4, -5, 6);
+        let m = a.outer(&b);
+
+        assert_eq!(m.row(1), Vec3::new(8, -10, 12));
+        assert_eq!(m.cols[2], Vec3::new(6, 12, 18));
+        assert_eq!(m.cols[0].x + m.cols[1].y + m.cols[2].z, a.dot(&b));
+        // (a b^T) c == a (b . c)
+        let c = Vec3::new(2, 0, -1);
+        assert_eq!(m * c, a * b.dot(&c));
+    }
+
+    #[test]
+    fn skew_symmetric_matrix_test() {
+        let v = Vec3::new(1.5, -2.0, 0.5);
+        let m = v.skew_symmetric_matrix();
+
+        for w in [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(3.0, 4.0, -2.0),
+        ] {
+            assert_eq!(m * w, v.cross(&w));
+        }
+        // skew-symmetric: the transpose negates it
+        assert_eq!(m.transpose(), (v * -1.0).skew_symmetric_matrix());
+    }
+
+    #[test]
+    fn distance_test() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(3.0, 5.0, 9.0);
+
+        assert_eq!(a.distance(&b), 7.0);
+        assert_eq!(a.distance_squared(&b), 49.0);
+        assert_eq!(a.distance(&a), 0.0);
+        assert_eq!(Vec3::new(0, 0, 0).distance_squared(&Vec3::new(1, 2, 2)), 9);
+    }
+
+    #[test]
+    fn importance_sample_ggx_test() {
+        let normal = Vec3::new(1.0, 2.0, -2.0).normalize();
+        let mean_cos = |roughness: f32| {
+            let mut total = 0.0;
+            for i in 0..32 {
+                for j in 0..32 {
+                    let (u, v) = ((i as f32 + 0.5) / 32.0, (j as f32 + 0.5) / 32.0);
+                    let h = Vec3::importance_sample_ggx(u, v, roughness, &normal);
+
+                    assert!((h.length() - 1.0).abs() < 1e-5);
+                    assert!(h.dot(&normal) >= 0.0);
+                    total += h.dot(&normal);
+                }
+            }
+            total / 1024.0
+        };
+
+        let smooth = mean_cos(0.1);
+        let rough = mean_cos(0.9);
+        assert!(smooth > 0.99);
+        assert!(rough < 0.9);
+        assert!(mean_cos(0.5) < smooth && mean_cos(0.5) > rough);
+    }
+
+    #[test]
+    fn clamp_length_max_min_test() {
+        let v = Vec3::new(2.0, 3.0, 6.0);
+
+        assert_eq!(v.clamp_length_max(3.5).length(), 3.5);
+        assert_eq!(v.clamp_length_max(7.0), v);
+        assert_eq!(v.clamp_length_min(14.0), Vec3::new(4.0, 6.0, 12.0));
+        assert_eq!(Vec3::zero().clamp_length_max(1.0), Vec3::zero());
+        assert_eq!(Vec3::zero().clamp_length_min(1.0), Vec3::zero());
+    }
+
+    #[test]
+    fn compare_select_test() {
+        use crate::vector::BVec3;
+
+        let a = Vec3::new(1.0, 5.0, -2.0);
+        let b = Vec3::new(3.0, 5.0, -4.0);
+
+        assert_eq!(a.cmpeq(&b), BVec3::new(false, true, false));
+        assert_eq!(a.cmplt(&b), BVec3::new(true, false, false));
+        assert_eq!(a.cmple(&b), BVec3::new(true, true, false));
+        assert_eq!(a.cmpgt(&b), BVec3::new(false, false, true));
+        assert_eq!(a.cmpge(&b), BVec3::new(false, true, true));
+        assert!(a.cmpge(&Vec3::new(-5.0, -5.0, -5.0)).all());
+
+        // component-wise min and max from a comparison and a select
+        assert_eq!(Vec3::select(a.cmplt(&b), a, b), a.min(b));
+        assert_eq!(Vec3::select(a.cmpgt(&b), a, b), a.max(b));
+    }
+
+    #[test]
+    fn cast_test() {
+        let v = Vec3::new(1, -2, 3);
+
+        assert_eq!(v.as_f32(), Vec3::new(1.0_f32, -2.0, 3.0));
+        assert_eq!(v.as_f64(), Vec3::new(1.0_f64, -2.0, 3.0));
+        assert_eq!(v.cast::<f32>(), Some(Vec3::new(1.0_f32, -2.0, 3.0)));
+        assert_eq!(v.cast::<u8>(), None);
+    }
+
+    #[test]
+    fn normalize_or_zero_test() {
+        let zero: Vec3<f32> = Vec3::new(0.0, 0.0, 0.0);
+
+        assert_eq!(zero.normalize_or_zero(), zero);
+        assert_eq!(Vec3::new(f32::NAN, 1.0, 0.0).normalize_or_zero(), zero);
+        assert!(
+            (Vec3::new(0.0_f32, 3.0, 4.0).normalize_or_zero() - Vec3::new(0.0, 0.6, 0.8)).length()
+                < 1e-6
+        );
+        assert!(Vec3::new(0.0_f32, 3.0, 4.0).normalize().is_normalized());
+        assert!(!Vec3::new(0.0_f32, 3.0, 4.0).is_normalized());
+        assert!(!zero.is_normalized());
+    }
+
+    #[test]
+    fn bytes_round_trip_test() {
+        let v = Vec3::new(1.5_f32, -0.0, f32::INFINITY);
+        let le = v.to_le_bytes();
+
+        assert_eq!(&le[..4], &1.5_f32.to_le_bytes());
+        assert_eq!(Vec3::<f32>::from_le_bytes(le), v);
+        assert_eq!(Vec3::<f32>::from_be_bytes(v.to_be_bytes()), v);
+        // the sign of zero survives
+        assert!(Vec3::<f32>::from_le_bytes(le).y.is_sign_negative());
+
+        let d = Vec3::new(std::f64::consts::PI, -1.0e-300, 42.0);
+        assert_eq!(Vec3::<f64>::from_le_bytes(d.to_le_bytes()), d);
+        assert_eq!(Vec3::<f64>::from_be_bytes(d.to_be_bytes()), d);
+        assert_eq!(&d.to_be_bytes()[16..], &42.0_f64.to_be_bytes());
+    }
+
+    #[test]
+    fn step_test() {
+        let edge = Vec3::new(0.5, -1.0, 2.0);
+        let x = Vec3::new(0.4, -1.0, 3.0);
+
+        assert_eq!(Vec3::step(&edge, &x), Vec3::new(0.0, 1.0, 1.0));
+        // equal edges don't divide by zero
+        assert_eq!(Vec3::smoothstep(&edge, &edge, &x), Vec3::new(0.0, 1.0, 1.0));
+        assert_eq!(
+            Vec3::smootherstep(&edge, &edge, &x),
+            Vec3::new(0.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn homogeneous_test() {
+        use crate::matrix::Mat4;
+        use crate::vector::Vec4;
+
+        let p = Vec3::new(1.0, -2.0, 3.0);
+        let m = Mat4::translation(Vec3::new(10.0, 0.0, 0.0));
+
+        assert_eq!(p.to_homogeneous_point(), Vec4::new(1.0, -2.0, 3.0, 1.0));
+        assert_eq!(p.to_homogeneous_vector(), Vec4::new(1.0, -2.0, 3.0, 0.0));
+        assert_eq!(p.to_homogeneous_point().to_cartesian(), Some(p));
+        // directions aren't translated, points are
+        assert_eq!((m * p.to_homogeneous_vector()).xyz(), p);
+        assert_eq!(
+            (m * p.to_homogeneous_point()).to_cartesian(),
+            Some(Vec3::new(11.0, -2.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn euclid_test() {
+        let v = Vec3::new(-1, 17, -33);
+
+        assert_eq!(v.div_euclid(16), Vec3::new(-1, 1, -3));
+        assert_eq!(v.rem_euclid(16), Vec3::new(15, 1, 15));
+        assert_eq!(v.div_euclid(16) * 16 + v.rem_euclid(16), v);
+
+        let f = Vec3::new(-0.5_f32, 2.5, -3.0);
+        assert_eq!(f.rem_euclid(2.0), Vec3::new(1.5, 0.5, 1.0));
+        assert_eq!(f.div_euclid(2.0), Vec3::new(-1.0, 1.0, -2.0));
+        assert_eq!((-0.5_f32).rem_euclid(2.0), f.rem_euclid(2.0).x);
+    }
+
+    #[test]
+    fn face_forward_test() {
+        let normal = Vec3::new(0.0, 0.0, 1.0);
+        let v = Vec3::new(0.3, -0.4, -0.5);
+
+        assert_eq!(v.face_forward(&normal), Vec3::new(-0.3, 0.4, 0.5));
+        assert_eq!(v.face_forward(&normal).face_forward(&normal), v * -1.0);
+        assert!(!v.is_in_hemisphere(&normal));
+        assert!(v.face_forward(&normal).is_in_hemisphere(&normal));
+
+        // perpendicular: deterministic, and unchanged
+        let flat = Vec3::new(1.0, 2.0, 0.0);
+        assert_eq!(flat.face_forward(&normal), flat);
+        assert_eq!(flat.face_forward(&(normal * -1.0)), flat);
+        assert!(flat.is_in_hemisphere(&normal));
+
+        // flipping a shading normal towards the viewer, like GLSL faceforward(n, dir, n)
+        let ray_dir = Vec3::new(0.0, 0.0, 1.0);
+        assert_eq!(normal.face_forward(&(ray_dir * -1.0)), normal * -1.0);
+    }
+
+    #[test]
+    fn transform_normal_test() {
+        use crate::matrix::Mat4;
+        use crate::vector::Vec4;
+
+        // a 45 degree slope, stretched along X
+        let m = Mat4::scale(Vec3::new(2.0_f64, 1.0, 1.0));
+        let tangent = Vec3::new(1.0, 1.0, 0.0);
+        let normal = Vec3::new(-1.0, 1.0, 0.0).normalize();
+
+        let transformed_tangent = (m * Vec4::new(tangent.x, tangent.y, tangent.z, 0.0)).xyz();
+        let transformed = normal.transform_normal(&m).unwrap();
+
+        assert!(transformed.dot(&transformed_tangent).abs() < 1e-12);
+        assert!((transformed.length() - 1.0).abs() < 1e-12);
+        assert!((transformed - Vec3::new(-1.0, 2.0, 0.0).normalize()).length() < 1e-12);
+        // transforming by the Matrix itself would tilt the normal off the surface
+        let naive = (m * Vec4::new(normal.x, normal.y, normal.z, 0.0)).xyz();
+        assert!(naive.dot(&transformed_tangent).abs() > 0.1);
+
+        assert_eq!(
+            normal.transform_normal(&Mat4::scale(Vec3::new(0.0, 1.0, 1.0))),
+            None
+        );
+    }
+
+    #[test]
+    fn num_zero_test() {
+        use num::Zero;
+
+        let v = Vec3::new(1.5, -2.0, 0.25);
+        let zero: Vec3<f64> = Zero::zero();
+
+        assert!(zero.is_zero());
+        assert!(!Vec3::new(0, 0, 1).is_zero());
+        assert_eq!(v + zero, v);
+        assert_eq!(zero + v, v);
+        // the inherent f32 constructor agrees with the trait
+        assert_eq!(Vec3::zero(), <Vec3<f32> as Zero>::zero());
     }
 }
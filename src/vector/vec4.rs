@@ -1,12 +1,29 @@
-use num::Num;
+use crate::matrix::Mat4;
+use crate::scalar;
+use crate::vector::iter::{collect_components, ComponentCountError};
+use crate::vector::parse::{parse_components, ParseVecError};
+use crate::vector::{BVec4, Vec3};
+use num::{
+    traits::{
+        AsPrimitive, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Euclid, SaturatingAdd,
+        SaturatingMul, SaturatingSub, WrappingAdd, WrappingMul, WrappingSub,
+    },
+    Float, Num, NumCast, Signed, Zero,
+};
 use std::{
     fmt::Display,
-    ops::{Add, Div, Mul, Sub},
+    iter::{FromIterator, Sum},
+    ops::{Add, Div, Mul, Rem, Sub},
+    str::FromStr,
 };
 
 /// A generic 4D Vector implementation.
 /// Takes 4 generic numbers (all 4 must be same type).
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+///
+/// Vectors are ordered lexicographically (compare x, then y, then z, then w), which gives a
+/// deterministic total order for sorting and `BTreeSet`s. This is not a
+/// geometric comparison: `a < b` says nothing about their lengths.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct Vec4<T>
 where
     T: Num + Clone + Copy,
@@ -36,7 +53,434 @@ where
     // 4D Vectors cannot have a cross product
     // https://math.stackexchange.com/questions/2317604/cross-product-of-4d-vectors
 
-    // TODO: Swizzle?
+    /// Return the dot product of two 4D Vectors, taking both by value
+    ///
+    /// Equivalent to `a.dot(&b)`, but convenient for owned `Copy` vectors.
+    pub fn dot_v(self, rhs: Vec4<T>) -> T {
+        self.dot(&rhs)
+    }
+
+    /// Return the squared length of the 4D Vector
+    ///
+    /// `v.dot(&v)`, which avoids the square root needed by `length`
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Return the squared distance between two 4D Vectors
+    ///
+    /// `(self - rhs).length_squared()`, which is cheap enough for comparing distances
+    pub fn distance_squared(&self, rhs: &Vec4<T>) -> T {
+        (*self - *rhs).length_squared()
+    }
+
+    /// Linearly interpolate between two 4D Vectors
+    ///
+    /// `a + (b - a) * t`, so `t = 0` gives `a` and `t = 1` gives `b`
+    pub fn lerp(&self, rhs: &Vec4<T>, t: T) -> Vec4<T> {
+        *self + (*rhs - *self) * t
+    }
+
+    /// Return the outer (tensor) product of two 4D Vectors
+    ///
+    /// `self * other^T`, so entry `(row i, column j)` is `self[i] * other[j]`.
+    /// Its trace is `self.dot(&other)`.
+    pub fn outer(&self, other: &Vec4<T>) -> Mat4<T> {
+        Mat4::new(
+            *self * other.x,
+            *self * other.y,
+            *self * other.z,
+            *self * other.w,
+        )
+    }
+
+    /// Apply `f` to each component, producing a new 4D Vector
+    ///
+    /// The output scalar may differ from the input, but must still be a number
+    /// (`U: Num + Copy`), so e.g. `Vec4<f32>` to `Vec4<i32>` works but `Vec4<bool>` does not.
+    pub fn map<U, F>(self, f: F) -> Vec4<U>
+    where
+        U: Num + Clone + Copy,
+        F: Fn(T) -> U,
+    {
+        Vec4::new(f(self.x), f(self.y), f(self.z), f(self.w))
+    }
+
+    /// Like `map`, but `f` also receives the component index (x = 0, y = 1, z = 2, w = 3)
+    pub fn map_with_index<U, F>(self, f: F) -> Vec4<U>
+    where
+        U: Num + Clone + Copy,
+        F: Fn(usize, T) -> U,
+    {
+        Vec4::new(f(0, self.x), f(1, self.y), f(2, self.z), f(3, self.w))
+    }
+
+    /// Combine the matching components of two 4D Vectors with `f`
+    pub fn zip_with<F>(self, other: Vec4<T>, f: F) -> Vec4<T>
+    where
+        F: Fn(T, T) -> T,
+    {
+        Vec4::new(
+            f(self.x, other.x),
+            f(self.y, other.y),
+            f(self.z, other.z),
+            f(self.w, other.w),
+        )
+    }
+
+    /// Return the component-wise minimum of two 4D Vectors
+    ///
+    /// Takes both vectors by value, so this takes precedence over `Ord::min`
+    /// (which compares vectors lexicographically).
+    pub fn min(self, rhs: Vec4<T>) -> Vec4<T>
+    where
+        T: PartialOrd,
+    {
+        Vec4 {
+            x: if self.x < rhs.x { self.x } else { rhs.x },
+            y: if self.y < rhs.y { self.y } else { rhs.y },
+            z: if self.z < rhs.z { self.z } else { rhs.z },
+            w: if self.w < rhs.w { self.w } else { rhs.w },
+        }
+    }
+
+    /// Return the component-wise maximum of two 4D Vectors
+    ///
+    /// Takes both vectors by value, so this takes precedence over `Ord::max`
+    /// (which compares vectors lexicographically).
+    pub fn max(self, rhs: Vec4<T>) -> Vec4<T>
+    where
+        T: PartialOrd,
+    {
+        Vec4 {
+            x: if self.x > rhs.x { self.x } else { rhs.x },
+            y: if self.y > rhs.y { self.y } else { rhs.y },
+            z: if self.z > rhs.z { self.z } else { rhs.z },
+            w: if self.w > rhs.w { self.w } else { rhs.w },
+        }
+    }
+
+    /// Return the `x, y, z` components as a 3D Vector, dropping `w`
+    pub fn xyz(&self) -> Vec3<T> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// Return the 3D point of a homogeneous coordinate, dividing through by `w`
+    ///
+    /// Returns `None` when `w` is zero, as the coordinate is then a direction (or
+    /// a point at infinity) with no cartesian position.
+    pub fn to_cartesian(&self) -> Option<Vec3<T>> {
+        if self.w == T::zero() {
+            return None;
+        }
+        Some(self.xyz() / self.w)
+    }
+
+    /// Return a mask of which components equal the matching component of `rhs`
+    pub fn cmpeq(&self, rhs: &Vec4<T>) -> BVec4 {
+        BVec4::new(
+            self.x == rhs.x,
+            self.y == rhs.y,
+            self.z == rhs.z,
+            self.w == rhs.w,
+        )
+    }
+
+    /// Return a mask of which components are less than the matching component of `rhs`
+    pub fn cmplt(&self, rhs: &Vec4<T>) -> BVec4
+    where
+        T: PartialOrd,
+    {
+        BVec4::new(
+            self.x < rhs.x,
+            self.y < rhs.y,
+            self.z < rhs.z,
+            self.w < rhs.w,
+        )
+    }
+
+    /// Return a mask of which components are less than or equal to the matching component of `rhs`
+    pub fn cmple(&self, rhs: &Vec4<T>) -> BVec4
+    where
+        T: PartialOrd,
+    {
+        BVec4::new(
+            self.x <= rhs.x,
+            self.y <= rhs.y,
+            self.z <= rhs.z,
+            self.w <= rhs.w,
+        )
+    }
+
+    /// Return a mask of which components are greater than the matching component of `rhs`
+    pub fn cmpgt(&self, rhs: &Vec4<T>) -> BVec4
+    where
+        T: PartialOrd,
+    {
+        BVec4::new(
+            self.x > rhs.x,
+            self.y > rhs.y,
+            self.z > rhs.z,
+            self.w > rhs.w,
+        )
+    }
+
+    /// Return a mask of which components are greater than or equal to the matching component of `rhs`
+    pub fn cmpge(&self, rhs: &Vec4<T>) -> BVec4
+    where
+        T: PartialOrd,
+    {
+        BVec4::new(
+            self.x >= rhs.x,
+            self.y >= rhs.y,
+            self.z >= rhs.z,
+            self.w >= rhs.w,
+        )
+    }
+
+    /// Pick each component from `if_true` where `mask` is set, and from `if_false` otherwise
+    pub fn select(mask: BVec4, if_true: Vec4<T>, if_false: Vec4<T>) -> Self {
+        Self::new(
+            if mask.x { if_true.x } else { if_false.x },
+            if mask.y { if_true.y } else { if_false.y },
+            if mask.z { if_true.z } else { if_false.z },
+            if mask.w { if_true.w } else { if_false.w },
+        )
+    }
+
+    /// Convert each component to another numeric type, or `None` if any component is out of range
+    pub fn cast<U>(&self) -> Option<Vec4<U>>
+    where
+        T: NumCast,
+        U: Num + NumCast + Copy,
+    {
+        Some(Vec4::new(
+            U::from(self.x)?,
+            U::from(self.y)?,
+            U::from(self.z)?,
+            U::from(self.w)?,
+        ))
+    }
+
+    /// Convert each component to `f32` with `as` semantics, truncating or saturating where needed
+    pub fn as_f32(&self) -> Vec4<f32>
+    where
+        T: AsPrimitive<f32>,
+    {
+        Vec4::new(self.x.as_(), self.y.as_(), self.z.as_(), self.w.as_())
+    }
+
+    /// Convert each component to `f64` with `as` semantics, truncating or saturating where needed
+    pub fn as_f64(&self) -> Vec4<f64>
+    where
+        T: AsPrimitive<f64>,
+    {
+        Vec4::new(self.x.as_(), self.y.as_(), self.z.as_(), self.w.as_())
+    }
+
+    /// Convert each component to `i32` with `as` semantics, truncating or saturating where needed
+    pub fn as_i32(&self) -> Vec4<i32>
+    where
+        T: AsPrimitive<i32>,
+    {
+        Vec4::new(self.x.as_(), self.y.as_(), self.z.as_(), self.w.as_())
+    }
+
+    /// Convert each component to `u32` with `as` semantics, truncating or saturating where needed
+    pub fn as_u32(&self) -> Vec4<u32>
+    where
+        T: AsPrimitive<u32>,
+    {
+        Vec4::new(self.x.as_(), self.y.as_(), self.z.as_(), self.w.as_())
+    }
+
+    /// Divide each component by `rhs`, rounding towards negative infinity for a positive `rhs`
+    ///
+    /// Unlike `/`, negative components land in the right cell of a grid of size `rhs`:
+    /// `-1` divided by `16` is `-1`, not `0`. Matches `i32::div_euclid` and `f32::div_euclid`.
+    pub fn div_euclid(self, rhs: T) -> Self
+    where
+        T: Euclid,
+    {
+        Vec4::new(
+            self.x.div_euclid(&rhs),
+            self.y.div_euclid(&rhs),
+            self.z.div_euclid(&rhs),
+            self.w.div_euclid(&rhs),
+        )
+    }
+
+    /// Return the remainder of each component divided by `rhs`, which is never negative
+    ///
+    /// Unlike `%`, `-1` modulo `16` is `15`. Matches `i32::rem_euclid` and `f32::rem_euclid`.
+    pub fn rem_euclid(self, rhs: T) -> Self
+    where
+        T: Euclid,
+    {
+        Vec4::new(
+            self.x.rem_euclid(&rhs),
+            self.y.rem_euclid(&rhs),
+            self.z.rem_euclid(&rhs),
+            self.w.rem_euclid(&rhs),
+        )
+    }
+
+    /// Like `div_euclid`, dividing by the matching component of `rhs`
+    pub fn div_euclid_vec(self, rhs: Vec4<T>) -> Self
+    where
+        T: Euclid,
+    {
+        Vec4::new(
+            self.x.div_euclid(&rhs.x),
+            self.y.div_euclid(&rhs.y),
+            self.z.div_euclid(&rhs.z),
+            self.w.div_euclid(&rhs.w),
+        )
+    }
+
+    /// Like `rem_euclid`, dividing by the matching component of `rhs`
+    pub fn rem_euclid_vec(self, rhs: Vec4<T>) -> Self
+    where
+        T: Euclid,
+    {
+        Vec4::new(
+            self.x.rem_euclid(&rhs.x),
+            self.y.rem_euclid(&rhs.y),
+            self.z.rem_euclid(&rhs.z),
+            self.w.rem_euclid(&rhs.w),
+        )
+    }
+
+    /// Return the sum of the components
+    ///
+    /// `x + y + z + w`
+    pub fn element_sum(&self) -> T {
+        self.x + self.y + self.z + self.w
+    }
+
+    /// Return the product of the components
+    ///
+    /// `x * y * z * w`
+    pub fn element_product(&self) -> T {
+        self.x * self.y * self.z * self.w
+    }
+
+    /// Return the smallest component
+    pub fn min_element(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        let mut m = self.x;
+        if self.y < m {
+            m = self.y;
+        }
+        if self.z < m {
+            m = self.z;
+        }
+        if self.w < m {
+            m = self.w;
+        }
+        m
+    }
+
+    /// Return the largest component
+    pub fn max_element(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        let mut m = self.x;
+        if self.y > m {
+            m = self.y;
+        }
+        if self.z > m {
+            m = self.z;
+        }
+        if self.w > m {
+            m = self.w;
+        }
+        m
+    }
+
+    /// Return the index of the smallest component (0 for x, 1 for y, ...)
+    ///
+    /// Ties go to the lowest index, so `(1, 0, 0, 1)` gives 1.
+    pub fn min_element_index(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        self.iter()
+            .enumerate()
+            .fold(
+                (0, self.x),
+                |(index, m), (i, &c)| if c < m { (i, c) } else { (index, m) },
+            )
+            .0
+    }
+
+    /// Return the index of the largest component (0 for x, 1 for y, ...)
+    ///
+    /// Ties go to the lowest index, so `(0, 1, 1, 0)` gives 1.
+    pub fn max_element_index(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        self.iter()
+            .enumerate()
+            .fold(
+                (0, self.x),
+                |(index, m), (i, &c)| if c > m { (i, c) } else { (index, m) },
+            )
+            .0
+    }
+
+    /// Return the sign of each component
+    ///
+    /// Integer components give 0 for zero. Float components follow IEEE 754 and
+    /// give 1 for +0.0 and -1 for -0.0, and NaN stays NaN.
+    pub fn signum(&self) -> Self
+    where
+        T: Signed,
+    {
+        Self::new(
+            self.x.signum(),
+            self.y.signum(),
+            self.z.signum(),
+            self.w.signum(),
+        )
+    }
+
+    /// Return the Manhattan (taxicab) distance between two 4D Vectors
+    ///
+    /// The sum of the absolute component differences.
+    pub fn manhattan_distance(&self, other: &Vec4<T>) -> T
+    where
+        T: Signed,
+    {
+        (self.x - other.x).abs()
+            + (self.y - other.y).abs()
+            + (self.z - other.z).abs()
+            + (self.w - other.w).abs()
+    }
+
+    /// Return the Chebyshev (chessboard) distance between two 4D Vectors
+    ///
+    /// The largest absolute component difference.
+    pub fn chebyshev_distance(&self, other: &Vec4<T>) -> T
+    where
+        T: Signed + PartialOrd,
+    {
+        (*self - *other).map(|c| c.abs()).max_element()
+    }
+
+    /// Return an iterator over references to the components, in x, y, z, w order
+    pub fn iter(&self) -> std::array::IntoIter<&T, 4> {
+        [&self.x, &self.y, &self.z, &self.w].into_iter()
+    }
+
+    /// Return an iterator over mutable references to the components, in x, y, z, w order
+    pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut T, 4> {
+        [&mut self.x, &mut self.y, &mut self.z, &mut self.w].into_iter()
+    }
 }
 
 impl Vec4<f32> {
@@ -49,6 +493,446 @@ impl Vec4<f32> {
             w: 0.0,
         }
     }
+
+    /// Return the components packed as little-endian bytes, in `x, y, z, w` order
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (chunk, c) in bytes
+            .chunks_exact_mut(4)
+            .zip([self.x, self.y, self.z, self.w])
+        {
+            chunk.copy_from_slice(&c.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 4D Vector from components packed as little-endian bytes, in `x, y, z, w` order
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        let c = |i: usize| f32::from_le_bytes(bytes[i * 4..(i + 1) * 4].try_into().unwrap());
+        Self::new(c(0), c(1), c(2), c(3))
+    }
+
+    /// Return the components packed as big-endian bytes, in `x, y, z, w` order
+    pub fn to_be_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (chunk, c) in bytes
+            .chunks_exact_mut(4)
+            .zip([self.x, self.y, self.z, self.w])
+        {
+            chunk.copy_from_slice(&c.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 4D Vector from components packed as big-endian bytes, in `x, y, z, w` order
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let c = |i: usize| f32::from_be_bytes(bytes[i * 4..(i + 1) * 4].try_into().unwrap());
+        Self::new(c(0), c(1), c(2), c(3))
+    }
+}
+
+impl Vec4<f64> {
+    /// Return the components packed as little-endian bytes, in `x, y, z, w` order
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (chunk, c) in bytes
+            .chunks_exact_mut(8)
+            .zip([self.x, self.y, self.z, self.w])
+        {
+            chunk.copy_from_slice(&c.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 4D Vector from components packed as little-endian bytes, in `x, y, z, w` order
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let c = |i: usize| f64::from_le_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap());
+        Self::new(c(0), c(1), c(2), c(3))
+    }
+
+    /// Return the components packed as big-endian bytes, in `x, y, z, w` order
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (chunk, c) in bytes
+            .chunks_exact_mut(8)
+            .zip([self.x, self.y, self.z, self.w])
+        {
+            chunk.copy_from_slice(&c.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Create a 4D Vector from components packed as big-endian bytes, in `x, y, z, w` order
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        let c = |i: usize| f64::from_be_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap());
+        Self::new(c(0), c(1), c(2), c(3))
+    }
+}
+
+impl<T> Vec4<T>
+where
+    T: Float,
+{
+    /// Return the length (magnitude) of the 4D Vector
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Return the distance between two 4D Vectors
+    ///
+    /// `(self - rhs).length()`
+    pub fn distance(&self, rhs: &Vec4<T>) -> T {
+        (*self - *rhs).length()
+    }
+
+    /// Return a unit length copy of the 4D Vector
+    ///
+    /// A zero length vector has no direction, so the result will be NaN.
+    pub fn normalize(&self) -> Self {
+        *self / self.length()
+    }
+
+    /// Return a unit length copy of the 4D Vector, or the zero vector when it has
+    /// no usable direction (a zero, infinite or NaN length)
+    pub fn normalize_or_zero(&self) -> Self {
+        let inv = self.length().recip();
+        if inv.is_finite() && inv > T::zero() {
+            *self * inv
+        } else {
+            Self::new(T::zero(), T::zero(), T::zero(), T::zero())
+        }
+    }
+
+    /// Return whether the 4D Vector has unit length
+    ///
+    /// The squared length must be within `sqrt(epsilon)` of 1, which allows for
+    /// the rounding error of a `normalize`.
+    pub fn is_normalized(&self) -> bool {
+        (self.length_squared() - T::one()).abs() <= T::epsilon().sqrt()
+    }
+
+    /// Return the point halfway between two 4D Vectors
+    ///
+    /// `(self + rhs) / 2`
+    pub fn midpoint(&self, rhs: &Vec4<T>) -> Self {
+        (*self + *rhs) / (T::one() + T::one())
+    }
+
+    /// Return the average of a slice of 4D Vectors, or `None` if it is empty
+    pub fn centroid(points: &[Vec4<T>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let sum: Vec4<T> = points.iter().sum();
+        Some(sum / T::from(points.len())?)
+    }
+
+    /// Component-wise step: 0 where `x` is below `edge`, and 1 otherwise
+    pub fn step(edge: &Vec4<T>, x: &Vec4<T>) -> Self {
+        Self::new(
+            scalar::step(edge.x, x.x),
+            scalar::step(edge.y, x.y),
+            scalar::step(edge.z, x.z),
+            scalar::step(edge.w, x.w),
+        )
+    }
+
+    /// Component-wise Hermite smoothing of `x` between `edge0` and `edge1`
+    ///
+    /// `t = clamp((x - edge0) / (edge1 - edge0), 0, 1)`, then `3t² - 2t³`
+    ///
+    /// Components with equal edges become a hard `step` instead of dividing by zero.
+    pub fn smoothstep(edge0: &Vec4<T>, edge1: &Vec4<T>, x: &Vec4<T>) -> Self {
+        Self::new(
+            scalar::smoothstep(edge0.x, edge1.x, x.x),
+            scalar::smoothstep(edge0.y, edge1.y, x.y),
+            scalar::smoothstep(edge0.z, edge1.z, x.z),
+            scalar::smoothstep(edge0.w, edge1.w, x.w),
+        )
+    }
+
+    /// Like `smoothstep`, but with zero first and second derivatives at the edges
+    ///
+    /// `t = clamp((x - edge0) / (edge1 - edge0), 0, 1)`, then `6t⁵ - 15t⁴ + 10t³`
+    pub fn smootherstep(edge0: &Vec4<T>, edge1: &Vec4<T>, x: &Vec4<T>) -> Self {
+        Self::new(
+            scalar::smootherstep(edge0.x, edge1.x, x.x),
+            scalar::smootherstep(edge0.y, edge1.y, x.y),
+            scalar::smootherstep(edge0.z, edge1.z, x.z),
+            scalar::smootherstep(edge0.w, edge1.w, x.w),
+        )
+    }
+
+    /// Linearly rescale each component from `in_min..in_max` to `out_min..out_max`
+    ///
+    /// `out_min + (v - in_min) * (out_max - out_min) / (in_max - in_min)`
+    ///
+    /// Values outside the input range extrapolate rather than clamp. A component
+    /// with `in_min == in_max` divides by zero.
+    pub fn remap(
+        &self,
+        in_min: &Vec4<T>,
+        in_max: &Vec4<T>,
+        out_min: &Vec4<T>,
+        out_max: &Vec4<T>,
+    ) -> Self {
+        Self::new(
+            scalar::remap(self.x, in_min.x, in_max.x, out_min.x, out_max.x),
+            scalar::remap(self.y, in_min.y, in_max.y, out_min.y, out_max.y),
+            scalar::remap(self.z, in_min.z, in_max.z, out_min.z, out_max.z),
+            scalar::remap(self.w, in_min.w, in_max.w, out_min.w, out_max.w),
+        )
+    }
+
+    /// Return the magnitude of each component with the sign of the matching component of `sign`
+    ///
+    /// The sign bit is copied directly, so a `sign` of -0.0 makes the result negative.
+    pub fn copysign(&self, sign: &Vec4<T>) -> Self {
+        Self::new(
+            self.x.copysign(sign.x),
+            self.y.copysign(sign.y),
+            self.z.copysign(sign.z),
+            self.w.copysign(sign.w),
+        )
+    }
+
+    /// Return the Lp norm of the 4D Vector
+    ///
+    /// `(|x|^p + |y|^p + ...)^(1/p)`. `p = 1` is the Manhattan length, `p = 2` the
+    /// Euclidean `length`, and `p = infinity` the largest absolute component.
+    pub fn lp_norm(&self, p: T) -> T {
+        let abs = self.map(|c| c.abs());
+        if p.is_infinite() {
+            return abs.max_element();
+        }
+        abs.map(|c| c.powf(p)).element_sum().powf(p.recip())
+    }
+
+    /// Return the Lp distance between two 4D Vectors
+    ///
+    /// `(self - other).lp_norm(p)`
+    pub fn lp_distance(&self, other: &Vec4<T>, p: T) -> T {
+        (*self - *other).lp_norm(p)
+    }
+
+    /// Raise each component to the float power `exp`
+    pub fn powf(&self, exp: T) -> Self {
+        self.map(|c| c.powf(exp))
+    }
+
+    /// Raise each component to the integer power `exp`
+    pub fn powi(&self, exp: i32) -> Self {
+        self.map(|c| c.powi(exp))
+    }
+
+    /// Return the square root of each component
+    ///
+    /// Negative components give NaN.
+    pub fn sqrt(&self) -> Self {
+        self.map(|c| c.sqrt())
+    }
+
+    /// Return the reciprocal `1 / c` of each component
+    ///
+    /// Zero components give infinity (with the sign of the zero).
+    pub fn recip(&self) -> Self {
+        self.map(|c| c.recip())
+    }
+
+    /// Return `self * mul + add` component-wise, as fused multiply-adds
+    ///
+    /// Each component is computed with a single rounding, which is more accurate
+    /// (and often faster) than a separate multiply and add.
+    pub fn mul_add(&self, mul: &Vec4<T>, add: &Vec4<T>) -> Self {
+        Self::new(
+            self.x.mul_add(mul.x, add.x),
+            self.y.mul_add(mul.y, add.y),
+            self.z.mul_add(mul.z, add.z),
+            self.w.mul_add(mul.w, add.w),
+        )
+    }
+
+    /// Move towards `target` by at most `max_delta`, without overshooting
+    ///
+    /// When `target` is within `max_delta` it is returned exactly.
+    pub fn move_towards(&self, target: &Vec4<T>, max_delta: T) -> Self {
+        let delta = *target - *self;
+        let distance = delta.length();
+        if distance <= max_delta || distance == T::zero() {
+            return *target;
+        }
+        *self + delta * (max_delta / distance)
+    }
+
+    /// Return the 4D Vector rescaled so its length is within `min..=max`
+    ///
+    /// The zero vector has no direction to grow along, so it is returned unchanged.
+    pub fn clamp_length(&self, min: T, max: T) -> Self {
+        let length = self.length();
+        if length == T::zero() {
+            *self
+        } else if length > max {
+            *self * (max / length)
+        } else if length < min {
+            *self * (min / length)
+        } else {
+            *self
+        }
+    }
+
+    /// Return the 4D Vector shortened to a length of at most `max`
+    ///
+    /// Shorter vectors (including the zero vector) are returned unchanged.
+    pub fn clamp_length_max(&self, max: T) -> Self {
+        self.clamp_length(T::zero(), max)
+    }
+
+    /// Return the 4D Vector lengthened to a length of at least `min`
+    ///
+    /// The zero vector has no direction to grow along, so it is returned unchanged.
+    pub fn clamp_length_min(&self, min: T) -> Self {
+        self.clamp_length(min, T::infinity())
+    }
+}
+
+/// Overflow aware arithmetic for integer 4D Vectors.
+///
+/// Each method applies the matching scalar operation from `num` to every component.
+impl<T> Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    /// Add two 4D Vectors, returning `None` if any component overflows
+    pub fn checked_add(&self, rhs: &Vec4<T>) -> Option<Vec4<T>>
+    where
+        T: CheckedAdd,
+    {
+        Some(Vec4 {
+            x: self.x.checked_add(&rhs.x)?,
+            y: self.y.checked_add(&rhs.y)?,
+            z: self.z.checked_add(&rhs.z)?,
+            w: self.w.checked_add(&rhs.w)?,
+        })
+    }
+
+    /// Subtract two 4D Vectors, returning `None` if any component overflows
+    pub fn checked_sub(&self, rhs: &Vec4<T>) -> Option<Vec4<T>>
+    where
+        T: CheckedSub,
+    {
+        Some(Vec4 {
+            x: self.x.checked_sub(&rhs.x)?,
+            y: self.y.checked_sub(&rhs.y)?,
+            z: self.z.checked_sub(&rhs.z)?,
+            w: self.w.checked_sub(&rhs.w)?,
+        })
+    }
+
+    /// Multiply a 4D Vector by a scalar, returning `None` if any component overflows
+    pub fn checked_mul(&self, rhs: T) -> Option<Vec4<T>>
+    where
+        T: CheckedMul,
+    {
+        Some(Vec4 {
+            x: self.x.checked_mul(&rhs)?,
+            y: self.y.checked_mul(&rhs)?,
+            z: self.z.checked_mul(&rhs)?,
+            w: self.w.checked_mul(&rhs)?,
+        })
+    }
+
+    /// Divide a 4D Vector by a scalar, returning `None` if `rhs` is zero or a
+    /// component overflows
+    pub fn checked_div(&self, rhs: T) -> Option<Vec4<T>>
+    where
+        T: CheckedDiv,
+    {
+        Some(Vec4 {
+            x: self.x.checked_div(&rhs)?,
+            y: self.y.checked_div(&rhs)?,
+            z: self.z.checked_div(&rhs)?,
+            w: self.w.checked_div(&rhs)?,
+        })
+    }
+
+    /// Add two 4D Vectors, wrapping around at the bounds of the type
+    pub fn wrapping_add(&self, rhs: &Vec4<T>) -> Vec4<T>
+    where
+        T: WrappingAdd,
+    {
+        Vec4 {
+            x: self.x.wrapping_add(&rhs.x),
+            y: self.y.wrapping_add(&rhs.y),
+            z: self.z.wrapping_add(&rhs.z),
+            w: self.w.wrapping_add(&rhs.w),
+        }
+    }
+
+    /// Subtract two 4D Vectors, wrapping around at the bounds of the type
+    pub fn wrapping_sub(&self, rhs: &Vec4<T>) -> Vec4<T>
+    where
+        T: WrappingSub,
+    {
+        Vec4 {
+            x: self.x.wrapping_sub(&rhs.x),
+            y: self.y.wrapping_sub(&rhs.y),
+            z: self.z.wrapping_sub(&rhs.z),
+            w: self.w.wrapping_sub(&rhs.w),
+        }
+    }
+
+    /// Multiply a 4D Vector by a scalar, wrapping around at the bounds of the type
+    pub fn wrapping_mul(&self, rhs: T) -> Vec4<T>
+    where
+        T: WrappingMul,
+    {
+        Vec4 {
+            x: self.x.wrapping_mul(&rhs),
+            y: self.y.wrapping_mul(&rhs),
+            z: self.z.wrapping_mul(&rhs),
+            w: self.w.wrapping_mul(&rhs),
+        }
+    }
+
+    /// Add two 4D Vectors, clamping each component to the bounds of the type
+    pub fn saturating_add(&self, rhs: &Vec4<T>) -> Vec4<T>
+    where
+        T: SaturatingAdd,
+    {
+        Vec4 {
+            x: self.x.saturating_add(&rhs.x),
+            y: self.y.saturating_add(&rhs.y),
+            z: self.z.saturating_add(&rhs.z),
+            w: self.w.saturating_add(&rhs.w),
+        }
+    }
+
+    /// Subtract two 4D Vectors, clamping each component to the bounds of the type
+    pub fn saturating_sub(&self, rhs: &Vec4<T>) -> Vec4<T>
+    where
+        T: SaturatingSub,
+    {
+        Vec4 {
+            x: self.x.saturating_sub(&rhs.x),
+            y: self.y.saturating_sub(&rhs.y),
+            z: self.z.saturating_sub(&rhs.z),
+            w: self.w.saturating_sub(&rhs.w),
+        }
+    }
+
+    /// Multiply a 4D Vector by a scalar, clamping each component to the bounds
+    /// of the type
+    pub fn saturating_mul(&self, rhs: T) -> Vec4<T>
+    where
+        T: SaturatingMul,
+    {
+        Vec4 {
+            x: self.x.saturating_mul(&rhs),
+            y: self.y.saturating_mul(&rhs),
+            z: self.z.saturating_mul(&rhs),
+            w: self.w.saturating_mul(&rhs),
+        }
+    }
 }
 
 /// Allows for the following syntax:
@@ -201,21 +1085,259 @@ where
     }
 }
 
-impl<T> Display for Vec4<T>
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec4;
+/// let a = Vec4::new(10, 7, -9, 12);
+/// let b = Vec4::new(4, 3, 4, 5);
+/// let c = a % b;
+///
+/// assert_eq!(c, Vec4::new(10 % 4, 7 % 3, -9 % 4, 12 % 5));
+/// ```
+impl<T> Rem for Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec4<T>;
+
+    fn rem(self, other: Vec4<T>) -> Self::Output {
+        Self {
+            x: self.x % other.x,
+            y: self.y % other.y,
+            z: self.z % other.z,
+            w: self.w % other.w,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec4;
+/// let a = Vec4::new(10, 7, -9, 12);
+/// let b = 4;
+/// let c = a % b;
+///
+/// assert_eq!(c, Vec4::new(10 % 4, 7 % 4, -9 % 4, 12 % 4));
+/// ```
+impl<T> Rem<T> for Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Output = Vec4<T>;
+
+    fn rem(self, other: T) -> Self::Output {
+        Self {
+            x: self.x % other,
+            y: self.y % other,
+            z: self.z % other,
+            w: self.w % other,
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec4;
+/// let a = Vec4::new(1, 2, 3, 4);
+/// let b: Vec<i32> = a.into_iter().collect();
+///
+/// assert_eq!(b, vec![1, 2, 3, 4]);
+/// ```
+impl<T> IntoIterator for Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, 4>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y, self.z, self.w].into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Item = &'a T;
+    type IntoIter = std::array::IntoIter<&'a T, 4>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Item = &'a mut T;
+    type IntoIter = std::array::IntoIter<&'a mut T, 4>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec4;
+/// let a: Vec4<i32> = (1..5).collect();
+///
+/// assert_eq!(a, Vec4::new(1, 2, 3, 4));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the iterator does not yield exactly 4 items, use `TryFrom<&[T]>`
+/// for a fallible conversion.
+impl<T> FromIterator<T> for Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        match collect_components(iter) {
+            Ok([x, y, z, w]) => Self { x, y, z, w },
+            Err(e) => panic!("cannot build a Vec4 from an iterator: {}", e),
+        }
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec4;
+/// let a = Vec4::try_from(&[1, 2, 3, 4][..]);
+///
+/// assert_eq!(a, Ok(Vec4::new(1, 2, 3, 4)));
+/// ```
+impl<T> TryFrom<&[T]> for Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    type Error = ComponentCountError;
+
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        let [x, y, z, w] = collect_components(slice.iter().copied())?;
+        Ok(Self { x, y, z, w })
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec4;
+/// let a = vec![Vec4::new(1, 2, 3, 4), Vec4::new(1, 2, 3, 4)];
+/// let b: Vec4<i32> = a.into_iter().sum();
+///
+/// assert_eq!(b, Vec4::new(2, 4, 6, 8));
+/// ```
+impl<T> Sum for Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(
+            Vec4::new(T::zero(), T::zero(), T::zero(), T::zero()),
+            |acc, v| acc + v,
+        )
+    }
+}
+
+impl<'a, T> Sum<&'a Vec4<T>> for Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(
+            Vec4::new(T::zero(), T::zero(), T::zero(), T::zero()),
+            |acc, v| acc + *v,
+        )
+    }
+}
+
+/// Allows vectors in generic code bounded on `num::Zero`:
+/// ```rust
+/// # use lamar::vector::Vec4;
+/// use num::Zero;
+///
+/// let zero: Vec4<i32> = Zero::zero();
+/// assert!(zero.is_zero());
+/// assert_eq!(Vec4::new(1, 2, 3, 4) + zero, Vec4::new(1, 2, 3, 4));
+/// ```
+impl<T> Zero for Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn zero() -> Self {
+        Vec4::new(T::zero(), T::zero(), T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero() && self.z.is_zero() && self.w.is_zero()
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec4;
+/// let a = Vec4::from([1, 2, 3, 4]);
+/// let b: [i32; 4] = a.into();
+///
+/// assert_eq!(a, Vec4::new(1, 2, 3, 4));
+/// assert_eq!(b, [1, 2, 3, 4]);
+/// ```
+impl<T> From<[T; 4]> for Vec4<T>
+where
+    T: Num + Clone + Copy,
+{
+    fn from([x, y, z, w]: [T; 4]) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+impl<T> From<Vec4<T>> for [T; 4]
+where
+    T: Num + Clone + Copy,
+{
+    fn from(v: Vec4<T>) -> Self {
+        [v.x, v.y, v.z, v.w]
+    }
+}
+
+/// Formats the vector as `(x, y, z, w)`, which parses back via `FromStr`
+impl<T> Display for Vec4<T>
 where
     T: Display + Num + Clone + Copy,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "x: {}\ny: {}\nz: {}\nw: {}",
-            self.x, self.y, self.z, self.w
-        )
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+/// Allows for the following syntax:
+/// ```rust
+/// # use lamar::vector::Vec4;
+/// let a: Vec4<i32> = "(1, 2, 3, 4)".parse().unwrap();
+/// let b: Vec4<i32> = "[1 2 3 4]".parse().unwrap();
+///
+/// assert_eq!(a, Vec4::new(1, 2, 3, 4));
+/// assert_eq!(a, b);
+/// ```
+impl<T> FromStr for Vec4<T>
+where
+    T: FromStr + Num + Clone + Copy,
+{
+    type Err = ParseVecError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z, w] = parse_components(s)?;
+        Ok(Self { x, y, z, w })
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::vector::ComponentCountError;
+    use crate::vector::ParseVecError;
     use crate::vector::Vec4;
 
     #[test]
@@ -278,4 +1400,593 @@ mod test {
 
         assert_eq!(lhs / rhs, Vec4::new(8, 16, 32, 3));
     }
+
+    #[test]
+    fn parse_test() {
+        let expected = Vec4::new(1, 2, 3, 4);
+
+        assert_eq!("1,2,3,4".parse(), Ok(expected));
+        assert_eq!("(1, 2, 3, 4)".parse(), Ok(expected));
+        assert_eq!("[1 2 3 4]".parse(), Ok(expected));
+        assert_eq!("  ( 1 , 2 , 3 , 4 )  ".parse(), Ok(expected));
+        assert_eq!("\t1,  2,  3,  4\n".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn parse_negative_and_scientific_test() {
+        let parsed: Vec4<f32> = "(-1.5, 2e3, -4.5E-2, 0)".parse().unwrap();
+
+        assert_eq!(parsed, Vec4::new(-1.5, 2000.0, -0.045, 0.0));
+        assert_eq!("-1, -1, -1, -1".parse(), Ok(Vec4::new(-1, -1, -1, -1)));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        assert_eq!(
+            "1, 1, 1".parse::<Vec4<i32>>(),
+            Err(ParseVecError::WrongComponentCount {
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_eq!(
+            "1, 1, 1, 1, 1".parse::<Vec4<i32>>(),
+            Err(ParseVecError::WrongComponentCount {
+                expected: 4,
+                found: 5
+            })
+        );
+        assert_eq!(
+            "".parse::<Vec4<i32>>(),
+            Err(ParseVecError::WrongComponentCount {
+                expected: 4,
+                found: 0
+            })
+        );
+        assert!(matches!(
+            "(1, 1, 1, a)".parse::<Vec4<i32>>(),
+            Err(ParseVecError::InvalidComponent(_))
+        ));
+        assert!(matches!(
+            "1, 1, 1,".parse::<Vec4<i32>>(),
+            Err(ParseVecError::InvalidComponent(_))
+        ));
+    }
+
+    #[test]
+    fn display_round_trip_test() {
+        let v = Vec4::new(0.1, -2.5, 1e-7, 123456.78);
+
+        assert_eq!(v.to_string(), "(0.1, -2.5, 0.0000001, 123456.78)");
+        assert_eq!(v.to_string().parse(), Ok(v));
+    }
+
+    #[test]
+    fn length_test() {
+        assert_eq!(Vec4::new(3, 4, 0, 0).length_squared(), 25);
+        assert_eq!(Vec4::new(3.0, 4.0, 0.0, 0.0).length(), 5.0);
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(
+            Vec4::new(0.0, 2.0, 0.0, 0.0).normalize(),
+            Vec4::new(0.0, 1.0, 0.0, 0.0)
+        );
+        assert!((Vec4::new(3.0f32, 4.0, 0.0, 0.0).normalize().length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut v = Vec4::new(1, 2, 3, 4);
+
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!((&v).into_iter().count(), 4);
+        for c in v.iter_mut() {
+            *c *= 2;
+        }
+        assert_eq!(v, Vec4::new(2, 4, 6, 8));
+        assert_eq!(v.into_iter().max(), Some(8));
+    }
+
+    #[test]
+    fn from_iter_test() {
+        let v: Vec4<i32> = vec![1, 2, 3, 4].into_iter().collect();
+
+        assert_eq!(v, Vec4::new(1, 2, 3, 4));
+        assert_eq!(
+            v.into_iter().map(|c| c * 2).collect::<Vec4<i32>>(),
+            Vec4::new(2, 4, 6, 8)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_iter_wrong_count_test() {
+        let _: Vec4<i32> = (0..5).collect();
+    }
+
+    #[test]
+    fn try_from_slice_test() {
+        assert_eq!(Vec4::try_from(&[1, 2, 3, 4][..]), Ok(Vec4::new(1, 2, 3, 4)));
+        assert_eq!(
+            Vec4::<i32>::try_from(&[1, 2, 3, 4, 5][..]),
+            Err(ComponentCountError {
+                expected: 4,
+                found: 5
+            })
+        );
+        assert_eq!(
+            Vec4::<i32>::try_from(&[][..]),
+            Err(ComponentCountError {
+                expected: 4,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn sum_centroid_test() {
+        let points = [
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+            Vec4::new(4.0, 0.0, 3.0, 1.0),
+            Vec4::new(2.0, 6.0, 0.0, 1.0),
+        ];
+        let centroid = points.iter().copied().sum::<Vec4<f32>>() / points.len() as f32;
+
+        assert_eq!(centroid, Vec4::new(2.0, 2.0, 1.0, 1.0));
+        assert_eq!(points.iter().sum::<Vec4<f32>>(), centroid * 3.0);
+        assert_eq!(
+            Vec::<Vec4<f32>>::new().into_iter().sum::<Vec4<f32>>(),
+            Vec4::zero()
+        );
+    }
+
+    #[test]
+    fn lerp_test() {
+        let a = Vec4::new(0.0, 10.0, -4.0, 2.0);
+        let b = Vec4::new(10.0, 20.0, 4.0, 2.0);
+
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 0.5), Vec4::new(5.0, 15.0, 0.0, 2.0));
+    }
+
+    #[test]
+    fn map_test() {
+        let v = Vec4::new(1, -2, 3, -4);
+
+        assert_eq!(v.map(|c| c as f32), Vec4::new(1.0, -2.0, 3.0, -4.0));
+        assert_eq!(
+            Vec4::new(4.0, 9.0, 0.25, 1.0).map(|c: f32| c.powf(2.0)),
+            Vec4::new(16.0, 81.0, 0.0625, 1.0)
+        );
+        assert_eq!(
+            v.map_with_index(|i, _| i as i32 * 10),
+            Vec4::new(0, 10, 20, 30)
+        );
+    }
+
+    #[test]
+    fn zip_with_test() {
+        let a = Vec4::new(1, -2, 3, -4);
+        let b = Vec4::new(4, -5, 1, 0);
+
+        assert_eq!(a.zip_with(b, std::cmp::max), Vec4::new(4, -2, 3, 0));
+    }
+
+    #[test]
+    fn lexicographic_order_test() {
+        assert!(Vec4::new(1, 2, 3, 4) < Vec4::new(1, 2, 3, 5));
+        assert!(Vec4::new(2, 0, 0, 0) > Vec4::new(1, 9, 9, 9));
+    }
+
+    #[test]
+    fn checked_arithmetic_test() {
+        let a: Vec4<u8> = Vec4::new(250, 1, 2, 3);
+
+        assert_eq!(
+            a.checked_add(&Vec4::new(5, 5, 5, 5)),
+            Some(Vec4::new(255, 6, 7, 8))
+        );
+        assert_eq!(a.checked_add(&Vec4::new(6, 0, 0, 0)), None);
+        assert_eq!(
+            a.checked_sub(&Vec4::new(0, 1, 2, 3)),
+            Some(Vec4::new(250, 0, 0, 0))
+        );
+        assert_eq!(a.checked_sub(&Vec4::new(0, 2, 0, 0)), None);
+        assert_eq!(
+            Vec4::new(1, 2, 3, 4).checked_mul(2u8),
+            Some(Vec4::new(2, 4, 6, 8))
+        );
+        assert_eq!(a.checked_mul(2), None);
+        assert_eq!(a.checked_div(2), Some(Vec4::new(125, 0, 1, 1)));
+        assert_eq!(a.checked_div(0), None);
+        assert_eq!(Vec4::new(i8::MIN, 0, 0, 0).checked_div(-1), None);
+    }
+
+    #[test]
+    fn wrapping_arithmetic_test() {
+        let a: Vec4<u8> = Vec4::new(250, 1, 0, 128);
+
+        assert_eq!(
+            a.wrapping_add(&Vec4::new(10, u8::MAX, 0, 128)),
+            Vec4::new(250u8.wrapping_add(10), 1u8.wrapping_add(u8::MAX), 0, 0)
+        );
+        assert_eq!(
+            a.wrapping_sub(&Vec4::new(0, 2, 1, 0)),
+            Vec4::new(250, u8::MAX, u8::MAX, 128)
+        );
+        assert_eq!(a.wrapping_mul(2), Vec4::new(250u8.wrapping_mul(2), 2, 0, 0));
+    }
+
+    #[test]
+    fn saturating_arithmetic_test() {
+        let a: Vec4<u8> = Vec4::new(250, 1, 0, 128);
+
+        assert_eq!(
+            a.saturating_add(&Vec4::new(10, 1, 0, 200)),
+            Vec4::new(u8::MAX, 2, 0, u8::MAX)
+        );
+        assert_eq!(
+            a.saturating_sub(&Vec4::new(0, 2, 1, 0)),
+            Vec4::new(250, 0, 0, 128)
+        );
+        assert_eq!(a.saturating_mul(2), Vec4::new(u8::MAX, 2, 0, u8::MAX));
+        assert_eq!(
+            Vec4::new(-100i8, 100, 0, 0).saturating_mul(2),
+            Vec4::new(i8::MIN, i8::MAX, 0, 0)
+        );
+    }
+
+    #[test]
+    fn array_conversion_test() {
+        let v = Vec4::from([1, 2, 3, 4]);
+
+        assert_eq!(v, Vec4::new(1, 2, 3, 4));
+        assert_eq!(<[i32; 4]>::from(v), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn rem_test() {
+        let lhs = Vec4::new(10, 7, 8, 9);
+        let rhs = Vec4::new(4, 3, 5, 9);
+
+        assert_eq!(lhs % rhs, Vec4::new(2, 1, 3, 0));
+    }
+
+    #[test]
+    fn rem_scalar_test() {
+        let lhs = Vec4::new(10, 7, 8, 9);
+
+        assert_eq!(lhs % 4, Vec4::new(2, 3, 0, 1));
+        assert_eq!(
+            Vec4::new(5.5, -1.5, 2.0, 4.0) % 2.0,
+            Vec4::new(1.5, -1.5, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn by_value_dot_test() {
+        let lhs = Vec4::new(1, 2, 3, 4);
+        let rhs = Vec4::new(5, 6, 7, 8);
+
+        assert_eq!(lhs.dot_v(rhs), 70);
+    }
+
+    #[test]
+    fn min_max_test() {
+        let a = Vec4::new(1, 5, -3, 7);
+        let b = Vec4::new(2, 4, -3, 0);
+
+        assert_eq!(a.min(b), Vec4::new(1, 4, -3, 0));
+        assert_eq!(a.max(b), Vec4::new(2, 5, -3, 7));
+        assert_eq!(
+            Vec4::new(0.5, -1.0, 2.0, 3.0).max(Vec4::new(1.0, -2.0, 2.0, 1.0)),
+            Vec4::new(1.0, -1.0, 2.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn midpoint_centroid_test() {
+        let a = Vec4::new(1.0, 2.0, 3.0, 0.0);
+        let b = Vec4::new(3.0, -6.0, 5.0, 1.0);
+        assert_eq!(a.midpoint(&b), Vec4::new(2.0, -2.0, 4.0, 0.5));
+
+        let points = [
+            Vec4::new(0.0, 0.0, 1.0, 1.0),
+            Vec4::new(3.0, 0.0, 1.0, 1.0),
+            Vec4::new(0.0, 6.0, 1.0, 1.0),
+        ];
+        assert_eq!(Vec4::centroid(&points), Some(Vec4::new(1.0, 2.0, 1.0, 1.0)));
+        assert_eq!(Vec4::<f32>::centroid(&[]), None);
+    }
+
+    #[test]
+    fn smoothstep_test() {
+        let edge0 = Vec4::new(0.0, -1.0, 2.0, 10.0);
+        let edge1 = Vec4::new(1.0, 3.0, 4.0, 20.0);
+        let half = Vec4::new(0.5, 0.5, 0.5, 0.5);
+
+        for step in [Vec4::smoothstep, Vec4::smootherstep] {
+            assert_eq!(step(&edge0, &edge1, &edge0), Vec4::new(0.0, 0.0, 0.0, 0.0));
+            assert_eq!(step(&edge0, &edge1, &edge1), Vec4::new(1.0, 1.0, 1.0, 1.0));
+            assert_eq!(step(&edge0, &edge1, &Vec4::new(0.5, 1.0, 3.0, 15.0)), half);
+            // clamped outside the edges
+            assert_eq!(
+                step(&edge0, &edge1, &(edge0 - 1.0)),
+                Vec4::new(0.0, 0.0, 0.0, 0.0)
+            );
+            assert_eq!(
+                step(&edge0, &edge1, &(edge1 + 1.0)),
+                Vec4::new(1.0, 1.0, 1.0, 1.0)
+            );
+        }
+        assert_eq!(
+            Vec4::smoothstep(&edge0, &edge1, &edge0.lerp(&edge1, 0.25)),
+            Vec4::new(0.15625, 0.15625, 0.15625, 0.15625)
+        );
+    }
+
+    #[test]
+    fn remap_test() {
+        let (zero, one) = (Vec4::new(0.0, 0.0, 0.0, 0.0), Vec4::new(1.0, 1.0, 1.0, 1.0));
+        let minus_one = Vec4::new(-1.0, -1.0, -1.0, -1.0);
+
+        assert_eq!(
+            Vec4::new(0.5, 0.5, 0.5, 0.5).remap(&zero, &one, &minus_one, &one),
+            zero
+        );
+        assert_eq!(zero.remap(&zero, &one, &minus_one, &one), minus_one);
+        assert_eq!(
+            Vec4::new(2.0, 2.0, 2.0, 2.0).remap(&zero, &one, &minus_one, &one),
+            Vec4::new(3.0, 3.0, 3.0, 3.0)
+        );
+        // screen to normalized device coordinates, flipping y
+        assert_eq!(
+            Vec4::new(200.0, 150.0, 0.0, 0.0).remap(
+                &zero,
+                &Vec4::new(800.0, 600.0, 1.0, 1.0),
+                &Vec4::new(-1.0, 1.0, -1.0, -1.0),
+                &Vec4::new(1.0, -1.0, 1.0, 1.0)
+            ),
+            Vec4::new(-0.5, 0.5, -1.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn element_reductions_test() {
+        let v = Vec4::new(1.5f32, -2.0, 4.0, 0.5);
+
+        assert_eq!(v.element_sum(), 4.0);
+        assert_eq!(v.element_product(), -6.0);
+        assert_eq!(v.min_element(), -2.0);
+        assert_eq!(v.max_element(), 4.0);
+    }
+
+    #[test]
+    fn element_index_test() {
+        let v = Vec4::new(1.5f32, -2.0, 4.0, -2.0);
+
+        assert_eq!(v.min_element_index(), 1);
+        assert_eq!(v.max_element_index(), 2);
+        assert_eq!(Vec4::new(-1, -1, -1, -1).max_element_index(), 0);
+        assert_eq!(Vec4::new(3, 9, 1, 9).max_element_index(), 1);
+        assert_eq!(Vec4::new(1, 0, 0, 1).min_element_index(), 1);
+        assert_eq!(Vec4::new(0, 1, 1, 0).max_element_index(), 1);
+    }
+
+    #[test]
+    fn signum_copysign_test() {
+        assert_eq!(Vec4::new(5, -3, 0, -1).signum(), Vec4::new(1, -1, 0, -1));
+        assert_eq!(
+            Vec4::new(0.5, -0.5, 0.0, -0.0).signum(),
+            Vec4::new(1.0, -1.0, 1.0, -1.0)
+        );
+        assert_eq!(
+            Vec4::new(1.0, 1.0, -1.0, -1.0).copysign(&Vec4::new(1.0, -1.0, 1.0, -1.0)),
+            Vec4::new(1.0, -1.0, 1.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn distance_metrics_test() {
+        let a = Vec4::new(1, 2, 3, 4);
+        let b = Vec4::new(-1, 2, 8, 0);
+
+        assert_eq!(a.manhattan_distance(&b), 11);
+        assert_eq!(a.chebyshev_distance(&b), 5);
+
+        let v = Vec4::new(1.0f32, 1.0, 1.0, 1.0);
+        assert_eq!(v.lp_norm(2.0), v.length());
+        assert_eq!(v.lp_norm(1.0), 4.0);
+    }
+
+    #[test]
+    fn pow_sqrt_test() {
+        assert_eq!(
+            Vec4::new(4.0, 9.0, 16.0, 0.0).sqrt(),
+            Vec4::new(2.0, 3.0, 4.0, 0.0)
+        );
+        assert_eq!(
+            Vec4::new(1.0, 2.0, 3.0, -1.0).powi(2),
+            Vec4::new(1.0, 4.0, 9.0, 1.0)
+        );
+        assert_eq!(
+            Vec4::new(4.0, 1.0, 0.0, 16.0).powf(0.5),
+            Vec4::new(2.0, 1.0, 0.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn recip_test() {
+        assert_eq!(
+            Vec4::new(2.0, 4.0, -1.0, 0.5).recip(),
+            Vec4::new(0.5, 0.25, -1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn mul_add_test() {
+        let a = Vec4::new(2.0, -3.0, 0.5, 1.0);
+        let b = Vec4::new(4.0, 0.5, 8.0, 0.0);
+        let c = Vec4::new(1.0, 1.0, -4.0, 2.0);
+
+        assert_eq!(a.mul_add(&b, &c), Vec4::new(9.0, -0.5, 0.0, 2.0));
+    }
+
+    #[test]
+    fn outer_test() {
+        let a = Vec4::new(1, 2, 3, 4);
+        let b = Vec4::new(1, 0, -1, 2);
+        let m = a.outer(&b);
+
+        assert_eq!(m.row(3), Vec4::new(4, 0, -4, 8));
+        assert_eq!(
+            m.cols[0].x + m.cols[1].y + m.cols[2].z + m.cols[3].w,
+            a.dot(&b)
+        );
+    }
+
+    #[test]
+    fn distance_test() {
+        let a = Vec4::new(1.0, 1.0, 1.0, 1.0);
+        let b = Vec4::new(2.0, 2.0, 2.0, 2.0);
+
+        assert_eq!(a.distance(&b), 2.0);
+        assert_eq!(a.distance_squared(&b), 4.0);
+        assert_eq!(
+            Vec4::new(1u32, 2, 3, 4).distance_squared(&Vec4::new(1, 2, 3, 4)),
+            0
+        );
+    }
+
+    #[test]
+    fn clamp_length_test() {
+        let v = Vec4::new(2.0, 2.0, 2.0, 2.0);
+        let zero = Vec4::new(0.0, 0.0, 0.0, 0.0);
+
+        assert_eq!(v.clamp_length_max(2.0), Vec4::new(1.0, 1.0, 1.0, 1.0));
+        assert_eq!(v.clamp_length_max(2.0).length(), 2.0);
+        assert_eq!(v.clamp_length_max(8.0), v);
+        assert_eq!(v.clamp_length_min(8.0), Vec4::new(4.0, 4.0, 4.0, 4.0));
+        assert_eq!(v.clamp_length(1.0, 5.0), v);
+        assert_eq!(zero.clamp_length_min(1.0), zero);
+        assert_eq!(zero.clamp_length(1.0, 2.0), zero);
+    }
+
+    #[test]
+    fn move_towards_test() {
+        let start = Vec4::new(0.0, 0.0, 0.0, 1.0);
+        let target = Vec4::new(2.0, 2.0, 2.0, 3.0);
+
+        // farther than max_delta moves by exactly max_delta
+        let moved = start.move_towards(&target, 2.0);
+        assert_eq!(moved, Vec4::new(1.0, 1.0, 1.0, 2.0));
+        assert_eq!(start.distance(&moved), 2.0);
+        // closer than max_delta snaps to the target
+        assert_eq!(start.move_towards(&target, 4.0), target);
+        assert_eq!(moved.move_towards(&target, 2.5), target);
+    }
+
+    #[test]
+    fn compare_select_test() {
+        use crate::vector::BVec4;
+
+        let a = Vec4::new(1, 5, -2, 0);
+        let b = Vec4::new(3, 5, -4, 0);
+
+        assert_eq!(a.cmpeq(&b), BVec4::new(false, true, false, true));
+        assert_eq!(a.cmplt(&b), BVec4::new(true, false, false, false));
+        assert_eq!(a.cmple(&b), BVec4::new(true, true, false, true));
+        assert_eq!(a.cmpgt(&b), BVec4::new(false, false, true, false));
+        assert_eq!(a.cmpge(&b), BVec4::new(false, true, true, true));
+        assert_eq!(Vec4::select(a.cmplt(&b), a, b), a.min(b));
+    }
+
+    #[test]
+    fn cast_test() {
+        let v = Vec4::new(1_u8, 2, 3, 255);
+
+        assert_eq!(v.as_i32(), Vec4::new(1, 2, 3, 255));
+        assert_eq!(v.cast::<i8>(), None);
+        assert_eq!(v.cast::<i16>(), Some(Vec4::new(1, 2, 3, 255)));
+    }
+
+    #[test]
+    fn normalize_or_zero_test() {
+        let zero: Vec4<f32> = Vec4::new(0.0, 0.0, 0.0, 0.0);
+
+        assert_eq!(zero.normalize_or_zero(), zero);
+        assert_eq!(
+            Vec4::new(1.0, f32::INFINITY, 0.0, 0.0).normalize_or_zero(),
+            zero
+        );
+        assert!(
+            (Vec4::new(0.0_f32, 3.0, 0.0, 4.0).normalize_or_zero() - Vec4::new(0.0, 0.6, 0.0, 0.8))
+                .length()
+                < 1e-6
+        );
+        assert!(Vec4::new(0.0_f32, 3.0, 0.0, 4.0)
+            .normalize()
+            .is_normalized());
+        assert!(!Vec4::new(0.0_f32, 3.0, 0.0, 4.0).is_normalized());
+        assert!(!zero.is_normalized());
+    }
+
+    #[test]
+    fn bytes_round_trip_test() {
+        let v = Vec4::new(1.5_f32, -2.0, 0.1, f32::MIN_POSITIVE);
+
+        assert_eq!(&v.to_le_bytes()[12..], &f32::MIN_POSITIVE.to_le_bytes());
+        assert_eq!(Vec4::<f32>::from_le_bytes(v.to_le_bytes()), v);
+        assert_eq!(Vec4::<f32>::from_be_bytes(v.to_be_bytes()), v);
+        assert_ne!(v.to_le_bytes(), v.to_be_bytes());
+
+        let d = Vec4::new(0.1_f64, -0.2, 1.0e300, -7.0);
+        assert_eq!(Vec4::<f64>::from_le_bytes(d.to_le_bytes()), d);
+        assert_eq!(Vec4::<f64>::from_be_bytes(d.to_be_bytes()), d);
+    }
+
+    #[test]
+    fn step_test() {
+        let edge = Vec4::new(0.5, -1.0, 2.0, 0.0);
+        let x = Vec4::new(0.4, -1.0, 3.0, -0.0);
+
+        assert_eq!(Vec4::step(&edge, &x), Vec4::new(0.0, 1.0, 1.0, 1.0));
+        assert_eq!(
+            Vec4::smoothstep(&edge, &edge, &x),
+            Vec4::new(0.0, 1.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn to_cartesian_test() {
+        use crate::vector::Vec3;
+
+        assert_eq!(
+            Vec4::new(2.0, -4.0, 6.0, 2.0).to_cartesian(),
+            Some(Vec3::new(1.0, -2.0, 3.0))
+        );
+        assert_eq!(Vec4::new(2.0, -4.0, 6.0, 0.0).to_cartesian(), None);
+        assert_eq!(
+            Vec4::new(2.0, -4.0, 6.0, 0.0).xyz(),
+            Vec3::new(2.0, -4.0, 6.0)
+        );
+        assert_eq!(
+            Vec4::new(4, 8, 12, 4).to_cartesian(),
+            Some(Vec3::new(1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn euclid_test() {
+        let v = Vec4::new(-1, 5, -8, 9);
+        let rhs = Vec4::new(4, 4, 8, -4);
+
+        assert_eq!(v.div_euclid(4), Vec4::new(-1, 1, -2, 2));
+        assert_eq!(v.rem_euclid(4), Vec4::new(3, 1, 0, 1));
+        assert_eq!(v.div_euclid_vec(rhs), Vec4::new(-1, 1, -1, -2));
+        assert_eq!(v.rem_euclid_vec(rhs), Vec4::new(3, 1, 0, 1));
+    }
 }